@@ -0,0 +1,298 @@
+//! Structural comparison of [`Wkt`] geometries.
+
+use std::fmt;
+use std::str::FromStr;
+
+use num_traits::Float;
+
+use crate::error::RoundTripError;
+use crate::to_wkt::{write_wkt_with_config, WriteConfig};
+use crate::types::{Coord, LineString, Point, Polygon};
+use crate::{ParseLimits, Wkt, WktNum};
+
+/// Compare two ordinates with a tolerance relative to their magnitude.
+fn approx_eq_ordinate<T: WktNum + Float>(a: T, b: T) -> bool {
+    if a == b {
+        return true;
+    }
+    let scale = T::one().max(a.abs()).max(b.abs());
+    (a - b).abs() <= T::epsilon() * scale
+}
+
+/// Compare two optional ordinates with `eq`; a missing ordinate only equals another missing one.
+fn optional_ordinates_eq_by<T, F>(a: Option<T>, b: Option<T>, eq: F) -> bool
+where
+    T: WktNum,
+    F: Fn(T, T) -> bool,
+{
+    match (a, b) {
+        (Some(a), Some(b)) => eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+pub(crate) fn approx_eq_coord<T: WktNum + Float>(a: &Coord<T>, b: &Coord<T>) -> bool {
+    approx_eq_ordinate(a.x, b.x)
+        && approx_eq_ordinate(a.y, b.y)
+        && optional_ordinates_eq_by(a.z, b.z, approx_eq_ordinate)
+        && optional_ordinates_eq_by(a.m, b.m, approx_eq_ordinate)
+}
+
+fn points_eq_by<T, F>(a: &Point<T>, b: &Point<T>, eq: &F) -> bool
+where
+    T: WktNum,
+    F: Fn(&Coord<T>, &Coord<T>) -> bool,
+{
+    match (&a.0, &b.0) {
+        (Some(a), Some(b)) => eq(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn coords_eq_by<T, F>(a: &[Coord<T>], b: &[Coord<T>], eq: &F) -> bool
+where
+    T: WktNum,
+    F: Fn(&Coord<T>, &Coord<T>) -> bool,
+{
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| eq(a, b))
+}
+
+fn line_strings_eq_by<T, F>(a: &[LineString<T>], b: &[LineString<T>], eq: &F) -> bool
+where
+    T: WktNum,
+    F: Fn(&Coord<T>, &Coord<T>) -> bool,
+{
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| coords_eq_by(&a.0, &b.0, eq))
+}
+
+fn polygons_eq_by<T, F>(a: &[Polygon<T>], b: &[Polygon<T>], eq: &F) -> bool
+where
+    T: WktNum,
+    F: Fn(&Coord<T>, &Coord<T>) -> bool,
+{
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(a, b)| line_strings_eq_by(&a.0, &b.0, eq))
+}
+
+impl<T: WktNum> Wkt<T> {
+    /// Returns `true` if both geometries have the same type and nesting, and `eq` holds for every
+    /// pair of corresponding coordinates.
+    pub(crate) fn structurally_eq_by<F>(&self, other: &Self, eq: &F) -> bool
+    where
+        F: Fn(&Coord<T>, &Coord<T>) -> bool,
+    {
+        match (self, other) {
+            (Wkt::Point(a), Wkt::Point(b)) => points_eq_by(a, b, eq),
+            (Wkt::LineString(a), Wkt::LineString(b)) => coords_eq_by(&a.0, &b.0, eq),
+            (Wkt::LinearRing(a), Wkt::LinearRing(b)) => coords_eq_by(&a.0, &b.0, eq),
+            (Wkt::Polygon(a), Wkt::Polygon(b)) => line_strings_eq_by(&a.0, &b.0, eq),
+            (Wkt::MultiPoint(a), Wkt::MultiPoint(b)) => {
+                a.0.len() == b.0.len() && a.0.iter().zip(&b.0).all(|(a, b)| points_eq_by(a, b, eq))
+            }
+            (Wkt::MultiLineString(a), Wkt::MultiLineString(b)) => {
+                line_strings_eq_by(&a.0, &b.0, eq)
+            }
+            (Wkt::MultiPolygon(a), Wkt::MultiPolygon(b)) => polygons_eq_by(&a.0, &b.0, eq),
+            (Wkt::GeometryCollection(a), Wkt::GeometryCollection(b)) => {
+                a.0.len() == b.0.len()
+                    && a.0
+                        .iter()
+                        .zip(&b.0)
+                        .all(|(a, b)| a.structurally_eq_by(b, eq))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if both geometries have the same structure and every pair of corresponding
+    /// coordinates is within `xy_eps` in x and y, `z_eps` in z, and `m_eps` in m.
+    ///
+    /// Separate tolerances are useful when the ordinates have different units, e.g. a measure
+    /// holding timestamps alongside projected x and y.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let a: Wkt<f64> = Wkt::from_str("LINESTRING Z(0 0 100,10 0 200)").unwrap();
+    /// let b: Wkt<f64> = Wkt::from_str("LINESTRING Z(0.001 0 100.5,10 0 199.5)").unwrap();
+    /// assert!(a.approx_eq_components(&b, 0.01, 1.0, 0.0));
+    /// assert!(!a.approx_eq_components(&b, 0.01, 0.1, 0.0));
+    /// ```
+    pub fn approx_eq_components(&self, other: &Self, xy_eps: T, z_eps: T, m_eps: T) -> bool
+    where
+        T: Float,
+    {
+        self.structurally_eq_by(other, &|a: &Coord<T>, b: &Coord<T>| {
+            (a.x - b.x).abs() <= xy_eps
+                && (a.y - b.y).abs() <= xy_eps
+                && optional_ordinates_eq_by(a.z, b.z, |a, b| (a - b).abs() <= z_eps)
+                && optional_ordinates_eq_by(a.m, b.m, |a, b| (a - b).abs() <= m_eps)
+        })
+    }
+}
+
+impl<T> Wkt<T>
+where
+    T: WktNum + Float + FromStr + fmt::Display,
+{
+    /// Write this geometry as WKT, parse it back, and check that the result matches the original.
+    ///
+    /// Coordinates are compared with a small tolerance relative to their magnitude. This is useful
+    /// for asserting that a geometry will survive serialization before it is stored.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(1 2 3, 4 5 6)").unwrap();
+    /// assert!(wkt.round_trip_check().is_ok());
+    /// ```
+    pub fn round_trip_check(&self) -> Result<(), RoundTripError> {
+        let mut written = String::new();
+        write_wkt_with_config(&mut written, self, &WriteConfig::default())?;
+        // Only a linear ring is written as `LINEARRING`, so read one back as a linear ring
+        let limits = ParseLimits {
+            keep_linear_rings: true,
+            ..Default::default()
+        };
+        let reparsed =
+            Wkt::from_str_with_limits(&written, &limits).map_err(RoundTripError::Parse)?;
+
+        if self.structurally_eq_by(&reparsed, &approx_eq_coord) {
+            Ok(())
+        } else {
+            let mut reparsed_wkt = String::new();
+            write_wkt_with_config(&mut reparsed_wkt, &reparsed, &WriteConfig::default())?;
+            Err(RoundTripError::Mismatch {
+                written,
+                reparsed: reparsed_wkt,
+            })
+        }
+    }
+}
+
+impl<T> Wkt<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    /// Parse `wkt_str` and return `true` if it describes the same geometry as `self`, comparing
+    /// ordinates with a tolerance relative to their magnitude.
+    ///
+    /// Returns `false` if `wkt_str` doesn't parse.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POINT Z(0.3 1 2)").unwrap();
+    /// assert!(wkt.matches_wkt_str("point z (0.30000000000000004 1 2)"));
+    /// assert!(!wkt.matches_wkt_str_exact("point z (0.30000000000000004 1 2)"));
+    /// ```
+    pub fn matches_wkt_str(&self, wkt_str: &str) -> bool
+    where
+        T: Float,
+    {
+        Wkt::from_str(wkt_str).is_ok_and(|other| self.structurally_eq_by(&other, &approx_eq_coord))
+    }
+
+    /// Like [`matches_wkt_str`](Self::matches_wkt_str), but every ordinate must be exactly equal.
+    pub fn matches_wkt_str_exact(&self, wkt_str: &str) -> bool {
+        Wkt::from_str(wkt_str).is_ok_and(|other| *self == other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::RoundTripError;
+    use crate::types::{Coord, Point};
+    use crate::{ParseLimits, Wkt};
+    use std::str::FromStr;
+
+    #[test]
+    fn round_trip_polygon() {
+        let wkt: Wkt<f64> =
+            Wkt::from_str("POLYGON Z((0 0 0, 10.5 0 1, 10.5 10.25 2, 0 0 0))").unwrap();
+        wkt.round_trip_check().unwrap();
+    }
+
+    #[test]
+    fn round_trip_geometry_collection() {
+        let wkt: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING Z(4 5 6,7 8 9))")
+                .unwrap();
+        wkt.round_trip_check().unwrap();
+
+        let limits = ParseLimits {
+            keep_linear_rings: true,
+            ..Default::default()
+        };
+        let wkt: Wkt<f64> =
+            Wkt::from_str_with_limits("GEOMETRYCOLLECTION(LINEARRING(0 0,1 0,0 0))", &limits)
+                .unwrap();
+        wkt.round_trip_check().unwrap();
+    }
+
+    #[test]
+    fn round_trip_measured() {
+        let wkt: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION M(POINT M(1 2 3),LINESTRING M(4 5 6,7 8 9))")
+                .unwrap();
+        wkt.round_trip_check().unwrap();
+
+        let wkt: Wkt<f64> =
+            Wkt::from_str("MULTIPOLYGON ZM(((0 0 0 1,1 0 0 2,1 1 0 3,0 0 0 1)))").unwrap();
+        wkt.round_trip_check().unwrap();
+    }
+
+    #[test]
+    fn polygon_matches_wkt_str() {
+        let wkt: Wkt<f64> = Wkt::from_str("POLYGON Z((0 0 0,10 0 0,10 10 0,0 0 0))").unwrap();
+        let spaced = " POLYGON Z ( ( 0 0 0 , 10 0 0 ,\n10 10 0, 0 0 0 ) ) ";
+        assert!(wkt.matches_wkt_str(spaced));
+        assert!(wkt.matches_wkt_str_exact(spaced));
+        assert!(!wkt.matches_wkt_str("POLYGON Z((0 0 0,10 0 0,10 10 1,0 0 0))"));
+        assert!(!wkt.matches_wkt_str("POLYGON Z((0 0 0,10 0 0"));
+    }
+
+    #[test]
+    fn approx_eq_components_separate_tolerances() {
+        let a: Wkt<f64> = Wkt::from_str("POINT Z(1 2 1000)").unwrap();
+        let b: Wkt<f64> = Wkt::from_str("POINT Z(1.0001 2 1002)").unwrap();
+        assert!(a.approx_eq_components(&b, 0.001, 5.0, 0.0));
+        assert!(!a.approx_eq_components(&b, 0.00001, 5.0, 0.0));
+        assert!(!a.approx_eq_components(&b, 0.001, 1.0, 0.0));
+
+        let line: Wkt<f64> = Wkt::from_str("LINESTRING Z(1 2 1000,3 4 5)").unwrap();
+        assert!(!a.approx_eq_components(&line, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn approx_eq_components_measured() {
+        let a: Wkt<f64> = Wkt::from_str("POINT ZM(1 2 3 1000)").unwrap();
+        let b: Wkt<f64> = Wkt::from_str("POINT ZM(1 2 3 1060)").unwrap();
+        assert!(a.approx_eq_components(&b, 0.0, 0.0, 60.0));
+        assert!(!a.approx_eq_components(&b, 0.0, 0.0, 30.0));
+
+        let unmeasured: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+        assert!(!a.approx_eq_components(&unmeasured, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn round_trip_non_finite_fails() {
+        let wkt = Wkt::Point(Point::new(Coord {
+            x: f64::NAN,
+            y: 2.0,
+            z: Some(3.0),
+            m: None,
+        }));
+        assert!(matches!(
+            wkt.round_trip_check(),
+            Err(RoundTripError::Parse(_))
+        ));
+    }
+}
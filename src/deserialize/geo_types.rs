@@ -1,13 +1,16 @@
-use crate::{Wkt, WktNum};
+use crate::types::{Dimension, Point};
+use crate::{ToWkt, Wkt, WktNum};
+use geo_types::CoordNum;
 use serde::de::{Deserialize, Deserializer, Error};
-use std::{default::Default, str::FromStr};
+use serde::Serializer;
+use std::{default::Default, fmt, str::FromStr};
 
 #[deprecated(since = "0.10.2", note = "instead use `wkt::deserialize_wkt`")]
 /// Deserializes from WKT format into a [`geo_types::Geometry`].
 pub fn deserialize_geometry<'de, D, T>(deserializer: D) -> Result<geo_types::Geometry<T>, D::Error>
 where
     D: Deserializer<'de>,
-    T: FromStr + Default + WktNum,
+    T: FromStr + Default + WktNum + CoordNum,
 {
     Wkt::deserialize(deserializer).and_then(|g: Wkt<T>| g.try_into().map_err(D::Error::custom))
 }
@@ -43,7 +46,7 @@ pub fn deserialize_point<'de, D, T>(
 ) -> Result<Option<geo_types::Point<T>>, D::Error>
 where
     D: Deserializer<'de>,
-    T: FromStr + Default + WktNum,
+    T: FromStr + Default + WktNum + CoordNum,
 {
     Wkt::deserialize(deserializer).and_then(|wkt: Wkt<T>| {
         geo_types::Geometry::try_from(wkt)
@@ -60,3 +63,142 @@ where
             })
     })
 }
+
+/// Serializes a [`geo_types::Geometry`] as a WKT string, for use with
+/// `#[serde(serialize_with = ...)]` alongside [`deserialize_wkt`](crate::deserialize_wkt).
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{point, Geometry};
+/// use wkt::deserialize::geo_types::serialize_geometry;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct MyType {
+///     #[serde(
+///         serialize_with = "serialize_geometry",
+///         deserialize_with = "wkt::deserialize_wkt"
+///     )]
+///     pub geometry: Geometry<f64>,
+/// }
+///
+/// let my_type = MyType {
+///     geometry: Geometry::Point(point!(x: 1., y: 2., z: 3.)),
+/// };
+/// let json = serde_json::to_string(&my_type).unwrap();
+/// assert_eq!(json, r#"{"geometry":"POINT Z(1 2 3)"}"#);
+/// let my_type: MyType = serde_json::from_str(&json).unwrap();
+/// assert!(matches!(my_type.geometry, Geometry::Point(_)));
+/// ```
+pub fn serialize_geometry<S, T>(
+    geometry: &geo_types::Geometry<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: WktNum + CoordNum + fmt::Display,
+{
+    serializer.serialize_str(&geometry.wkt_string())
+}
+
+/// Serializes an `Option<geo_types::Point>` as a WKT string, writing `None` as an empty point.
+///
+/// This is the counterpart of [`deserialize_point`].
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{point, Point};
+/// use wkt::deserialize::geo_types::{deserialize_point, serialize_point};
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct MyType {
+///     #[serde(serialize_with = "serialize_point", deserialize_with = "deserialize_point")]
+///     pub geometry: Option<Point<f64>>,
+/// }
+///
+/// let my_type = MyType { geometry: None };
+/// let json = serde_json::to_string(&my_type).unwrap();
+/// let my_type: MyType = serde_json::from_str(&json).unwrap();
+/// assert!(my_type.geometry.is_none());
+///
+/// let my_type = MyType { geometry: Some(point!(x: 3.14, y: 42., z: 0.)) };
+/// let json = serde_json::to_string(&my_type).unwrap();
+/// assert_eq!(json, r#"{"geometry":"POINT Z(3.14 42 0)"}"#);
+/// ```
+pub fn serialize_point<S, T>(
+    point: &Option<geo_types::Point<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: WktNum + CoordNum + fmt::Display,
+{
+    match point {
+        Some(point) => serializer.serialize_str(&point.wkt_string()),
+        None => serializer.collect_str(&Point::<T>::empty(Dimension::XY)),
+    }
+}
+
+/// Serializes a [`geo_types::LineString`] as a WKT string.
+pub fn serialize_line_string<S, T>(
+    line_string: &geo_types::LineString<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: WktNum + CoordNum + fmt::Display,
+{
+    serializer.serialize_str(&line_string.wkt_string())
+}
+
+/// Serializes a [`geo_types::Polygon`] as a WKT string.
+pub fn serialize_polygon<S, T>(
+    polygon: &geo_types::Polygon<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: WktNum + CoordNum + fmt::Display,
+{
+    serializer.serialize_str(&polygon.wkt_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{serialize_line_string, serialize_polygon};
+    use geo_types::{line_string, polygon, LineString, Polygon};
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Parcel {
+        #[serde(
+            serialize_with = "serialize_line_string",
+            deserialize_with = "crate::deserialize_wkt"
+        )]
+        boundary: LineString<f64>,
+        #[serde(
+            serialize_with = "serialize_polygon",
+            deserialize_with = "crate::deserialize_wkt"
+        )]
+        footprint: Polygon<f64>,
+    }
+
+    #[test]
+    fn round_trip_line_string_and_polygon() {
+        let parcel = Parcel {
+            boundary: line_string![(x: 0., y: 0., z: 1.), (x: 5., y: 0., z: 1.)],
+            footprint: polygon![
+                (x: 0., y: 0., z: 0.),
+                (x: 4., y: 0., z: 0.),
+                (x: 4., y: 4., z: 0.),
+                (x: 0., y: 0., z: 0.),
+            ],
+        };
+        let json = serde_json::to_string(&parcel).unwrap();
+        assert_eq!(
+            json,
+            r#"{"boundary":"LINESTRING Z(0 0 1,5 0 1)","footprint":"POLYGON Z((0 0 0,4 0 0,4 4 0,0 0 0))"}"#
+        );
+        assert_eq!(serde_json::from_str::<Parcel>(&json).unwrap(), parcel);
+    }
+}
@@ -2,9 +2,10 @@
 //!
 //! You can deserialise to [`geo_types`] or any other implementor of [`TryFromWkt`], using
 //! [`deserialize_wkt`]. Or you can store this crates internal primitives [`wkt`]
-//! or [`Wkt`] in your struct fields.
+//! or [`Wkt`] in your struct fields, which serialize back to WKT strings too.
 
 use crate::{TryFromWkt, Wkt, WktNum};
+use ::geo_types::CoordNum;
 use serde::de::{Deserializer, Error, Visitor};
 use std::{
     default::Default,
@@ -142,6 +143,38 @@ where
     }
 }
 
+/// Serializes as a WKT string, as written by [`Display`](fmt::Display).
+///
+/// ```
+/// // This example relies on enabling this crates `serde` feature
+/// use std::str::FromStr;
+/// use wkt::Wkt;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Record {
+///     geom: Wkt<f64>,
+/// }
+///
+/// let record = Record {
+///     geom: Wkt::from_str("POINT Z(1 2 3)").unwrap(),
+/// };
+/// let json = serde_json::to_string(&record).unwrap();
+/// assert_eq!(json, r#"{"geom":"POINT Z(1 2 3)"}"#);
+/// ```
+impl<T> serde::Serialize for Wkt<T>
+where
+    T: WktNum + CoordNum + fmt::Display,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+// Only exercised by the tests, which newer compilers flag as never constructed
+#[allow(dead_code)]
 struct GeometryVisitor<T> {
     _marker: PhantomData<T>,
 }
@@ -194,12 +227,15 @@ mod tests {
                 .unwrap();
             assert!(matches!(
                 wkt,
-                Wkt::Point(Point(Some(Coord {
-                    x: _, // floating-point types cannot be used in patterns
-                    y: _, // floating-point types cannot be used in patterns
-                    z: None,
-                    m: None,
-                })))
+                Wkt::Point(Point(
+                    Some(Coord {
+                        x: _, // floating-point types cannot be used in patterns
+                        y: _, // floating-point types cannot be used in patterns
+                        z: None,
+                        m: None,
+                    }),
+                    _
+                ))
             ));
         }
 
@@ -209,8 +245,34 @@ mod tests {
             let wkt = deserializer.deserialize_any(WktVisitor::<f64>::default());
             assert_eq!(
                 wkt.unwrap_err(),
-                Error::custom("Unable to parse input number as the desired output type")
+                Error::custom(
+                    "Unable to parse input number '20.1A' at offset 10 as the desired output type"
+                )
+            );
+        }
+    }
+
+    mod serialize {
+        use super::*;
+
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Record {
+            name: String,
+            geom: Wkt<f64>,
+        }
+
+        #[test]
+        fn round_trip_struct_field() {
+            let record = Record {
+                name: "depot".to_string(),
+                geom: Wkt::from_str("LINESTRING ZM(1 2 3 4,5 6 7 8)").unwrap(),
+            };
+            let json = serde_json::to_string(&record).unwrap();
+            assert_eq!(
+                json,
+                r#"{"name":"depot","geom":"LINESTRING ZM(1 2 3 4,5 6 7 8)"}"#
             );
+            assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
         }
     }
 
@@ -225,12 +287,15 @@ mod tests {
                 .unwrap();
             assert!(matches!(
                 geometry,
-                Wkt::Point(Point(Some(Coord {
-                    x: _, // floating-point types cannot be used in patterns
-                    y: _, // floating-point types cannot be used in patterns
-                    z: None,
-                    m: None,
-                })))
+                Wkt::Point(Point(
+                    Some(Coord {
+                        x: _, // floating-point types cannot be used in patterns
+                        y: _, // floating-point types cannot be used in patterns
+                        z: None,
+                        m: None,
+                    }),
+                    _
+                ))
             ));
         }
 
@@ -240,7 +305,9 @@ mod tests {
             let geometry = deserializer.deserialize_any(GeometryVisitor::<f64>::default());
             assert_eq!(
                 geometry.unwrap_err(),
-                Error::custom("Expected a number for the Y coordinate")
+                Error::custom(
+                    "Expected a number for the Y coordinate: found 'PI3.14' at offset 10"
+                )
             );
         }
     }
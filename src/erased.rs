@@ -0,0 +1,246 @@
+//! A dyn-compatible view of geometries, for keeping geometries from different sources behind one
+//! trait object.
+//!
+//! [`GeometryTrait`] has an associated type for every kind of geometry, so it can't be made into
+//! a trait object. [`DynGeometry`] exposes the parts of it which don't need those types.
+
+use std::fmt;
+
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+use crate::types::{Coord, GeometryType};
+use crate::{Wkt, WktNum};
+
+/// The type, dimensions and coordinates of a geometry, without the associated types of
+/// [`GeometryTrait`].
+///
+/// This is implemented for every [`GeometryTrait`] implementation, so e.g. `geo_types` and `Wkt`
+/// geometries can be stored together as `Box<dyn DynGeometry<f64>>` or [`ErasedGeometry`].
+pub trait DynGeometry<T: WktNum> {
+    /// The type of this geometry. Rects and triangles are polygons and lines are line strings, as
+    /// when writing WKT.
+    fn geometry_type(&self) -> GeometryType;
+
+    /// The coordinate dimensions of this geometry.
+    fn dimensions(&self) -> Dimensions;
+
+    /// All coordinates of this geometry, in the order they would be written as WKT. Empty points
+    /// have none, and a rect has its minimum and maximum corners.
+    fn coords(&self) -> Box<dyn Iterator<Item = Coord<T>> + '_>;
+}
+
+fn to_coord<T: WktNum>(coord: &impl CoordTrait<T = T>) -> Coord<T> {
+    let (z, m) = match coord.dim() {
+        Dimensions::Xyz | Dimensions::Unknown(3) => (coord.nth(2), None),
+        Dimensions::Xym => (None, coord.nth(2)),
+        Dimensions::Xyzm | Dimensions::Unknown(4) => (coord.nth(2), coord.nth(3)),
+        Dimensions::Xy | Dimensions::Unknown(_) => (None, None),
+    };
+    Coord {
+        x: coord.x(),
+        y: coord.y(),
+        z,
+        m,
+    }
+}
+
+fn push_polygon_coords<T: WktNum>(polygon: &impl PolygonTrait<T = T>, coords: &mut Vec<Coord<T>>) {
+    for ring in polygon.exterior().into_iter().chain(polygon.interiors()) {
+        coords.extend(LineStringTrait::coords(&ring).map(|coord| to_coord(&coord)));
+    }
+}
+
+fn push_coords<T: WktNum>(geometry: &impl GeometryTrait<T = T>, coords: &mut Vec<Coord<T>>) {
+    match geometry.as_type() {
+        geo_traits::GeometryType::Point(point) => {
+            coords.extend(point.coord().map(|c| to_coord(&c)))
+        }
+        geo_traits::GeometryType::LineString(line_string) => {
+            coords.extend(LineStringTrait::coords(line_string).map(|coord| to_coord(&coord)))
+        }
+        geo_traits::GeometryType::Polygon(polygon) => push_polygon_coords(polygon, coords),
+        geo_traits::GeometryType::MultiPoint(multi_point) => coords.extend(
+            multi_point
+                .points()
+                .filter_map(|point| point.coord().map(|coord| to_coord(&coord))),
+        ),
+        geo_traits::GeometryType::MultiLineString(mls) => {
+            for line_string in mls.line_strings() {
+                coords.extend(LineStringTrait::coords(&line_string).map(|coord| to_coord(&coord)));
+            }
+        }
+        geo_traits::GeometryType::MultiPolygon(multi_polygon) => {
+            for polygon in multi_polygon.polygons() {
+                push_polygon_coords(&polygon, coords);
+            }
+        }
+        geo_traits::GeometryType::GeometryCollection(gc) => {
+            for geometry in gc.geometries() {
+                push_coords(&geometry, coords);
+            }
+        }
+        geo_traits::GeometryType::Rect(rect) => {
+            coords.extend([to_coord(&rect.min()), to_coord(&rect.max())])
+        }
+        geo_traits::GeometryType::Triangle(triangle) => {
+            coords.extend(TriangleTrait::coords(triangle).iter().map(to_coord))
+        }
+        geo_traits::GeometryType::Line(line) => {
+            coords.extend(LineTrait::coords(line).iter().map(to_coord))
+        }
+    }
+}
+
+impl<G> DynGeometry<G::T> for G
+where
+    G: GeometryTrait,
+    G::T: WktNum,
+{
+    fn geometry_type(&self) -> GeometryType {
+        match self.as_type() {
+            geo_traits::GeometryType::Point(_) => GeometryType::Point,
+            geo_traits::GeometryType::LineString(_) | geo_traits::GeometryType::Line(_) => {
+                GeometryType::LineString
+            }
+            geo_traits::GeometryType::Polygon(_)
+            | geo_traits::GeometryType::Rect(_)
+            | geo_traits::GeometryType::Triangle(_) => GeometryType::Polygon,
+            geo_traits::GeometryType::MultiPoint(_) => GeometryType::MultiPoint,
+            geo_traits::GeometryType::MultiLineString(_) => GeometryType::MultiLineString,
+            geo_traits::GeometryType::MultiPolygon(_) => GeometryType::MultiPolygon,
+            geo_traits::GeometryType::GeometryCollection(_) => GeometryType::GeometryCollection,
+        }
+    }
+
+    fn dimensions(&self) -> Dimensions {
+        self.dim()
+    }
+
+    fn coords(&self) -> Box<dyn Iterator<Item = Coord<G::T>> + '_> {
+        let mut coords = Vec::new();
+        push_coords(self, &mut coords);
+        Box::new(coords.into_iter())
+    }
+}
+
+/// A geometry from any source behind a [`DynGeometry`] trait object, as returned by
+/// [`Wkt::erase`].
+pub struct ErasedGeometry<'a, T: WktNum>(Box<dyn DynGeometry<T> + 'a>);
+
+impl<'a, T: WktNum> ErasedGeometry<'a, T> {
+    /// Erase the type of `geometry`.
+    pub fn new(geometry: impl DynGeometry<T> + 'a) -> Self {
+        ErasedGeometry(Box::new(geometry))
+    }
+}
+
+impl<T: WktNum> DynGeometry<T> for ErasedGeometry<'_, T> {
+    fn geometry_type(&self) -> GeometryType {
+        self.0.geometry_type()
+    }
+
+    fn dimensions(&self) -> Dimensions {
+        self.0.dimensions()
+    }
+
+    fn coords(&self) -> Box<dyn Iterator<Item = Coord<T>> + '_> {
+        self.0.coords()
+    }
+}
+
+impl<T: WktNum> fmt::Debug for ErasedGeometry<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErasedGeometry")
+            .field("geometry_type", &self.geometry_type())
+            .field("dimensions", &self.dimensions())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: WktNum> Wkt<T> {
+    /// Borrow this geometry as an [`ErasedGeometry`], so it can be kept alongside geometries of
+    /// other types.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use geo_traits::Dimensions;
+    /// use wkt::types::GeometryType;
+    /// use wkt::{DynGeometry, Wkt};
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(1 2 3,4 5 6)").unwrap();
+    /// let erased = wkt.erase();
+    /// assert_eq!(erased.geometry_type(), GeometryType::LineString);
+    /// assert_eq!(erased.dimensions(), Dimensions::Xyz);
+    /// assert_eq!(erased.coords().count(), 2);
+    /// ```
+    pub fn erase(&self) -> ErasedGeometry<'_, T> {
+        ErasedGeometry::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynGeometry, ErasedGeometry};
+    use crate::types::{Coord, GeometryType};
+    use crate::Wkt;
+    use geo_traits::Dimensions;
+    use std::str::FromStr;
+
+    #[test]
+    fn erase_wkt_and_read_it_back() {
+        let wkt: Wkt<f64> =
+            Wkt::from_str("POLYGON Z((0 0 1,1 0 1,1 1 1,0 0 1),(0.1 0.1 1,0.2 0.1 1,0.1 0.1 1))")
+                .unwrap();
+        let erased = wkt.erase();
+        assert_eq!(erased.geometry_type(), GeometryType::Polygon);
+        assert_eq!(erased.dimensions(), Dimensions::Xyz);
+        let coords: Vec<Coord<f64>> = erased.coords().collect();
+        assert_eq!(coords.len(), 7);
+        assert_eq!(
+            coords[1],
+            Coord {
+                x: 1.,
+                y: 0.,
+                z: Some(1.),
+                m: None
+            }
+        );
+    }
+
+    #[test]
+    fn mixed_sources_behind_one_trait_object() {
+        let collection: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION M(POINT M(1 2 3),POINT M EMPTY)").unwrap();
+        let point: Wkt<f64> = Wkt::from_str("POINT(5 6)").unwrap();
+        let geometries = [
+            collection.erase(),
+            ErasedGeometry::new(&point),
+            ErasedGeometry::new(point.clone()),
+        ];
+
+        let types: Vec<GeometryType> = geometries.iter().map(|g| g.geometry_type()).collect();
+        assert_eq!(
+            types,
+            [
+                GeometryType::GeometryCollection,
+                GeometryType::Point,
+                GeometryType::Point
+            ]
+        );
+        let first: Vec<Coord<f64>> = geometries[0].coords().collect();
+        assert_eq!(
+            first,
+            [Coord {
+                x: 1.,
+                y: 2.,
+                z: None,
+                m: Some(3.)
+            }]
+        );
+        assert_eq!(geometries[2].coords().count(), 1);
+    }
+}
@@ -2,6 +2,9 @@ use std::fmt;
 
 use thiserror::Error;
 
+use crate::types::{Dimension, GeometryType};
+use crate::ValidationIssue;
+
 /// Generic errors for WKT writing and reading
 #[derive(Error, Debug)]
 pub enum Error {
@@ -12,6 +15,219 @@ pub enum Error {
     /// Wrapper around `[std::fmt::Error]`
     #[error(transparent)]
     FmtError(#[from] std::fmt::Error),
+    /// Wrapper around `[std::io::Error]`, from writing WKB
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    /// A sequence had more items than the `u32` count WKB stores for it can hold.
+    #[error("Can't write {0} items as WKB, which holds at most u32::MAX in a sequence")]
+    TooManyWkbItems(usize),
+    /// A coordinate had no ordinate at this index, which the dimension being written needs.
+    #[error("Coordinate has no ordinate at index {0} for the dimension being written")]
+    MissingOrdinate(usize),
+}
+
+/// Errors returned when parsing WKT text, e.g. by [`Wkt::from_str`](crate::Wkt).
+///
+/// Every variant records the byte offset into the input where the problem was found, and the
+/// `Display` text is a readable description of the problem that includes it.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum ParseWktError {
+    /// A token appeared where the grammar doesn't allow it.
+    #[error("{expected}: found '{found}' at offset {offset}")]
+    UnexpectedToken {
+        /// A description of what was expected
+        expected: &'static str,
+        /// The start of the offending token's text
+        found: String,
+        /// The byte offset of the offending token
+        offset: usize,
+    },
+    /// A number couldn't be parsed as the requested coordinate type.
+    #[error(
+        "Unable to parse input number '{found}' at offset {offset} as the desired output type"
+    )]
+    InvalidNumber {
+        /// The start of the number's text
+        found: String,
+        /// The byte offset of the number
+        offset: usize,
+    },
+    /// A number was infinite or NaN, which isn't allowed unless
+    /// [`ParseLimits::allow_nonfinite`](crate::ParseLimits::allow_nonfinite) is set.
+    #[error("Non-finite number '{found}' at offset {offset}")]
+    NonFiniteNumber {
+        /// The start of the number's text
+        found: String,
+        /// The byte offset of the number
+        offset: usize,
+    },
+    /// A number was too large for the requested coordinate type, e.g. `1e40` for `f32`, which
+    /// would otherwise be read as infinity.
+    #[error("Number '{found}' at offset {offset} is out of range for the desired output type")]
+    NumberOutOfRange {
+        /// The start of the number's text
+        found: String,
+        /// The byte offset of the number
+        offset: usize,
+    },
+    /// The input ended before the geometry was complete.
+    #[error("{expected} at offset {offset}")]
+    UnexpectedEndOfInput {
+        /// A description of what was expected
+        expected: &'static str,
+        /// The length of the input
+        offset: usize,
+    },
+    /// The input ended with parentheses still open.
+    #[error("Unbalanced parentheses (expected {missing} more ')') at offset {offset}")]
+    UnbalancedParentheses {
+        /// The number of `)` needed to close every open parenthesis
+        missing: usize,
+        /// The length of the input
+        offset: usize,
+    },
+    /// The geometry keyword isn't a WKT geometry type.
+    #[error("Invalid type encountered: {name} at offset {offset}")]
+    UnknownGeometryType {
+        /// The keyword as written
+        name: String,
+        /// The byte offset of the keyword
+        offset: usize,
+    },
+    /// A coordinate had fewer or more ordinates than the dimension of its geometry, e.g. the
+    /// second coordinate of `LINESTRING Z(1 2 3,4 5)`.
+    #[error(
+        "Coordinate at index {at_coord_index} doesn't match the {expected:?} dimension of its \
+         geometry at offset {offset}"
+    )]
+    InconsistentDimension {
+        /// The dimension of the geometry
+        expected: Dimension,
+        /// The index of the coordinate within its line string or ring, or of the point within
+        /// its `MULTIPOINT`
+        at_coord_index: usize,
+        /// The byte offset of the token found instead of the next ordinate, or of the extra
+        /// ordinate
+        offset: usize,
+    },
+    /// More tokens followed a complete geometry. Use [`Wkt::parse_prefix`](crate::Wkt) to parse a
+    /// geometry from the start of a longer input.
+    #[error("Unexpected text after the geometry: found '{found}' at offset {offset}")]
+    TrailingTokens {
+        /// The start of the first token after the geometry
+        found: String,
+        /// The byte offset of the first token after the geometry
+        offset: usize,
+    },
+    /// `GEOMETRYCOLLECTION`s are nested more deeply than the parser allows.
+    #[error("GEOMETRYCOLLECTION nested more than {limit} deep at offset {offset}")]
+    NestingTooDeep {
+        /// The deepest nesting allowed, as in [`ParseLimits`](crate::ParseLimits)
+        limit: usize,
+        /// The byte offset of the collection which went past the limit
+        offset: usize,
+    },
+}
+
+impl ParseWktError {
+    /// The byte offset into the input where the error was found.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let input = "LINESTRING Z(1 2 3,4 x 6)";
+    /// let err = Wkt::<f64>::from_str(input).unwrap_err();
+    /// assert_eq!(&input[err.offset()..], "x 6)");
+    /// ```
+    pub fn offset(&self) -> usize {
+        match self {
+            ParseWktError::UnexpectedToken { offset, .. }
+            | ParseWktError::InvalidNumber { offset, .. }
+            | ParseWktError::NonFiniteNumber { offset, .. }
+            | ParseWktError::NumberOutOfRange { offset, .. }
+            | ParseWktError::UnexpectedEndOfInput { offset, .. }
+            | ParseWktError::UnbalancedParentheses { offset, .. }
+            | ParseWktError::UnknownGeometryType { offset, .. }
+            | ParseWktError::InconsistentDimension { offset, .. }
+            | ParseWktError::TrailingTokens { offset, .. }
+            | ParseWktError::NestingTooDeep { offset, .. } => *offset,
+        }
+    }
+}
+
+/// Errors returned when reading well-known binary, e.g. by [`Wkt::from_wkb`](crate::Wkt::from_wkb).
+///
+/// Offsets are in bytes from the start of the input.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum WkbError {
+    #[error("WKB input ended early; it is only {0} bytes long")]
+    UnexpectedEnd(usize),
+    #[error("Invalid WKB byte order marker {marker} at offset {offset}")]
+    InvalidByteOrder { marker: u8, offset: usize },
+    #[error("Unknown WKB geometry type code {code} at offset {offset}")]
+    UnknownGeometryType { code: u32, offset: usize },
+    #[error("Expected a WKB {expected:?} member at offset {offset}, found a {found:?}")]
+    UnexpectedMemberType {
+        expected: GeometryType,
+        found: GeometryType,
+        offset: usize,
+    },
+    #[error("Unable to represent the WKB ordinate at offset {0} as the desired output type")]
+    InvalidNumber(usize),
+    #[error("WKB geometry collections are nested more than {0} deep")]
+    NestingTooDeep(usize),
+    #[error("{0} bytes of unexpected data after the WKB geometry")]
+    TrailingBytes(usize),
+}
+
+/// Errors returned by [`Wkt::from_coord_buffer`](crate::Wkt::from_coord_buffer) when the buffers
+/// don't describe a geometry.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum CoordBufferError {
+    #[error("{len} ordinates can't be split into whole {dim:?} coordinates")]
+    RaggedCoords { len: usize, dim: Dimension },
+    #[error("A POINT needs exactly one coordinate, found {0}")]
+    PointCoordCount(usize),
+    #[error("Offsets must start at 0, never decrease, and end at the number of items they index")]
+    InvalidOffsets,
+    #[error("A {0:?} has no flat coordinate buffer representation")]
+    UnsupportedType(GeometryType),
+}
+
+/// Errors returned by [`Wkt::from_str_validated`](crate::Wkt::from_str_validated)
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum ValidatedParseError {
+    #[error(transparent)]
+    Parse(#[from] ParseWktError),
+    #[error("WKT failed validation: {0:?}")]
+    Invalid(Vec<ValidationIssue>),
+}
+
+/// Errors returned by [`WktReader`](crate::read::WktReader) for a line of input, and by
+/// [`Wkt::from_reader`](crate::Wkt::from_reader).
+#[derive(Error, Debug)]
+pub enum ReadError {
+    /// Wrapper around `[std::io::Error]`, from reading the input
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Invalid WKT on line {line}")]
+    InvalidWkt {
+        /// The line number, counting from 1
+        line: usize,
+        source: ParseWktError,
+    },
+}
+
+/// Errors returned by [`Wkt::round_trip_check`](crate::Wkt::round_trip_check)
+#[derive(Error, Debug)]
+pub enum RoundTripError {
+    #[error("Failed to write geometry as WKT: {0}")]
+    Write(#[from] Error),
+    #[error("Failed to parse the written WKT: {0}")]
+    Parse(ParseWktError),
+    #[error("Geometry changed after a round trip: wrote {written}, read back {reparsed}")]
+    Mismatch { written: String, reparsed: String },
 }
 
 impl From<Error> for fmt::Error {
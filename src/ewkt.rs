@@ -0,0 +1,281 @@
+//! Support for the spatial reference identifier (SRID) carried by extended WKT.
+
+use std::fmt;
+use std::str::FromStr;
+
+use geo_types::CoordNum;
+
+use crate::error::{Error, ParseWktError};
+use crate::to_wkt::{write_wkt_as_ewkt, EwktConfig};
+use crate::{ParseLimits, Wkt, WktNum};
+
+/// A [`Wkt`] geometry tagged with an optional spatial reference identifier (SRID).
+///
+/// The SRID is metadata only: the [`Display`](fmt::Display) impl writes the plain WKT of
+/// [`geometry`](Self::geometry) and ignores the SRID. Use [`ewkt_string`](Self::ewkt_string) to
+/// include it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WktWithSrid<T: WktNum> {
+    pub geometry: Wkt<T>,
+    pub srid: Option<u32>,
+}
+
+impl<T: WktNum> WktWithSrid<T> {
+    /// The SRID of this geometry, if one was set.
+    pub fn srid(&self) -> Option<u32> {
+        self.srid
+    }
+
+    /// Set the SRID of this geometry.
+    pub fn with_srid(self, srid: u32) -> Self {
+        Self {
+            srid: Some(srid),
+            ..self
+        }
+    }
+
+    /// Discard the SRID and return the geometry.
+    pub fn into_geometry(self) -> Wkt<T> {
+        self.geometry
+    }
+}
+
+impl<T: WktNum> Wkt<T> {
+    /// Tag this geometry with a spatial reference identifier.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+    /// let tagged = wkt.with_srid(4326);
+    /// assert_eq!(tagged.srid(), Some(4326));
+    /// assert_eq!(tagged.to_string(), "POINT Z(1 2 3)");
+    /// ```
+    pub fn with_srid(self, srid: u32) -> WktWithSrid<T> {
+        WktWithSrid {
+            geometry: self,
+            srid: Some(srid),
+        }
+    }
+}
+
+/// Split an optional `SRID=<srid>;` declaration off the front of `ewkt_str`, returning the SRID
+/// and the byte offset where the geometry starts.
+fn split_srid_prefix(ewkt_str: &str) -> Result<(Option<u32>, usize), ParseWktError> {
+    let start = ewkt_str.len() - ewkt_str.trim_start().len();
+    let rest = &ewkt_str[start..];
+    if !rest
+        .get(..5)
+        .is_some_and(|keyword| keyword.eq_ignore_ascii_case("SRID="))
+    {
+        return Ok((None, 0));
+    }
+
+    let srid_start = start + 5;
+    let Some(len) = ewkt_str[srid_start..].find(';') else {
+        return Err(ParseWktError::UnexpectedEndOfInput {
+            expected: "Expected ';' after the SRID",
+            offset: ewkt_str.len(),
+        });
+    };
+    let srid_text = &ewkt_str[srid_start..srid_start + len];
+    let srid = srid_text
+        .trim()
+        .parse()
+        .map_err(|_| ParseWktError::InvalidNumber {
+            found: srid_text.chars().take(32).collect(),
+            offset: srid_start,
+        })?;
+    Ok((Some(srid), srid_start + len + 1))
+}
+
+impl<T> Wkt<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    /// Parse extended WKT as written by PostGIS, which may declare the SRID of the geometry with
+    /// a leading `SRID=<srid>;`. The SRID is `None` if there is no declaration.
+    ///
+    /// Plain [`Wkt::from_str`] rejects an SRID declaration.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let (srid, wkt) = Wkt::<f64>::from_ewkt_str("SRID=4326;POINT Z(1 2 3)").unwrap();
+    /// assert_eq!(srid, Some(4326));
+    /// assert_eq!(wkt, Wkt::from_str("POINT Z(1 2 3)").unwrap());
+    /// ```
+    pub fn from_ewkt_str(ewkt_str: &str) -> Result<(Option<u32>, Wkt<T>), ParseWktError> {
+        let (srid, start) = split_srid_prefix(ewkt_str)?;
+        Ok((
+            srid,
+            Wkt::from_bytes_at(ewkt_str.as_bytes(), start, &ParseLimits::default())?,
+        ))
+    }
+}
+
+impl<T: WktNum> From<Wkt<T>> for WktWithSrid<T> {
+    fn from(geometry: Wkt<T>) -> Self {
+        WktWithSrid {
+            geometry,
+            srid: None,
+        }
+    }
+}
+
+impl<T> WktWithSrid<T>
+where
+    T: WktNum + CoordNum + fmt::Display,
+{
+    /// Write this geometry as extended WKT, declaring the SRID if there is one, so that
+    /// [`Wkt::from_ewkt_str`] reads it back. Fails if the geometry's dimensions can't be written
+    /// as WKT.
+    ///
+    /// This is [`write_ewkt`](crate::to_wkt::write_ewkt) with the SRID, except that a
+    /// [`Wkt::LinearRing`] keeps its `LINEARRING` keyword.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+    /// assert_eq!(wkt.with_srid(4326).ewkt_string().unwrap(), "SRID=4326;POINT Z(1 2 3)");
+    /// ```
+    pub fn ewkt_string(&self) -> Result<String, Error> {
+        let config = EwktConfig {
+            srid: self.srid,
+            ..Default::default()
+        };
+        let mut ewkt = String::new();
+        write_wkt_as_ewkt(&mut ewkt, &self.geometry, &config)?;
+        Ok(ewkt)
+    }
+}
+
+impl<T> fmt::Display for WktWithSrid<T>
+where
+    T: WktNum + CoordNum + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(&self.geometry, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WktWithSrid;
+    use crate::error::ParseWktError;
+    use crate::to_wkt::write_ewkt;
+    use crate::{ParseLimits, Wkt};
+    use std::str::FromStr;
+
+    #[test]
+    fn set_and_read_srid() {
+        let wkt: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+
+        let untagged = WktWithSrid::from(wkt.clone());
+        assert_eq!(untagged.srid(), None);
+
+        let tagged = wkt.clone().with_srid(4326);
+        assert_eq!(tagged.srid(), Some(4326));
+        assert_eq!(tagged.clone().with_srid(3857).srid(), Some(3857));
+        assert_eq!(tagged.into_geometry(), wkt);
+    }
+
+    #[test]
+    fn display_ignores_srid() {
+        let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(1 2 3,4 5 6)").unwrap();
+        assert_eq!(wkt.with_srid(4326).to_string(), "LINESTRING Z(1 2 3,4 5 6)");
+    }
+
+    #[test]
+    fn parse_srid_declaration() {
+        let (srid, wkt) = Wkt::<f64>::from_ewkt_str("SRID=4326;POINT Z(1 2 3)").unwrap();
+        assert_eq!(srid, Some(4326));
+        assert_eq!(wkt, Wkt::from_str("POINT Z(1 2 3)").unwrap());
+
+        let (srid, wkt) = Wkt::<f64>::from_ewkt_str(" srid=3857; LINESTRING(1 2,3 4)").unwrap();
+        assert_eq!(srid, Some(3857));
+        assert_eq!(wkt, Wkt::from_str("LINESTRING(1 2,3 4)").unwrap());
+
+        let (srid, _) = Wkt::<f64>::from_ewkt_str("POINT(1 2)").unwrap();
+        assert_eq!(srid, None);
+
+        // Plain WKT parsing doesn't accept the declaration
+        assert!(Wkt::<f64>::from_str("SRID=4326;POINT Z(1 2 3)").is_err());
+    }
+
+    #[test]
+    fn invalid_srid_declaration() {
+        assert_eq!(
+            Wkt::<f64>::from_ewkt_str("SRID=abc;POINT(1 2)").unwrap_err(),
+            ParseWktError::InvalidNumber {
+                found: "abc".to_string(),
+                offset: 5
+            }
+        );
+        assert_eq!(
+            Wkt::<f64>::from_ewkt_str("SRID=4326 POINT(1 2)").unwrap_err(),
+            ParseWktError::UnexpectedEndOfInput {
+                expected: "Expected ';' after the SRID",
+                offset: 20
+            }
+        );
+
+        // Offsets in the geometry count from the start of the whole input
+        assert_eq!(
+            Wkt::<f64>::from_ewkt_str("SRID=4326;POINT(1 x)")
+                .unwrap_err()
+                .offset(),
+            18
+        );
+    }
+
+    #[test]
+    fn ewkt_round_trip() {
+        let wkt: Wkt<f64> = Wkt::from_str("POLYGON Z((0 0 1,1 0 1,1 1 1,0 0 1))").unwrap();
+        let ewkt = wkt.clone().with_srid(4326).ewkt_string().unwrap();
+        assert_eq!(ewkt, "SRID=4326;POLYGON Z((0 0 1,1 0 1,1 1 1,0 0 1))");
+        assert_eq!(
+            Wkt::from_ewkt_str(&ewkt).unwrap(),
+            (Some(4326), wkt.clone())
+        );
+
+        let untagged = WktWithSrid::from(wkt.clone()).ewkt_string().unwrap();
+        assert_eq!(untagged, wkt.to_string());
+        assert_eq!(Wkt::from_ewkt_str(&untagged).unwrap(), (None, wkt));
+
+        let limits = ParseLimits {
+            keep_linear_rings: true,
+            ..Default::default()
+        };
+        let ring = Wkt::<f64>::from_str_with_limits("LINEARRING(0 0,1 0,0 0)", &limits).unwrap();
+        assert_eq!(
+            ring.with_srid(4326).ewkt_string().unwrap(),
+            "SRID=4326;LINEARRING(0 0,1 0,0 0)"
+        );
+    }
+
+    #[test]
+    fn ewkt_string_matches_write_ewkt() {
+        for wkt_str in [
+            "POINT EMPTY",
+            "LINESTRING M(1 2 3,4 5 6)",
+            "POLYGON ZM((0 0 1 2,1 0 1 2,1 1 1 2,0 0 1 2))",
+            "GEOMETRYCOLLECTION(POINT(1 2),MULTIPOINT(3 4,5 6))",
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str(wkt_str).unwrap();
+            for srid in [None, Some(4326)] {
+                let mut written = String::new();
+                write_ewkt(&mut written, &wkt, srid).unwrap();
+                let tagged = WktWithSrid {
+                    geometry: wkt.clone(),
+                    srid,
+                };
+                assert_eq!(tagged.ewkt_string().unwrap(), written);
+            }
+        }
+    }
+}
@@ -0,0 +1,415 @@
+//! A flat, columnar representation of a [`Wkt`] geometry for passing across an FFI boundary.
+//!
+//! Coordinates are interleaved into a single buffer, and nesting is described by offset buffers
+//! in the style of [GeoArrow](https://geoarrow.org/format.html).
+
+use std::ops::Range;
+
+use num_traits::Float;
+
+use crate::error::CoordBufferError;
+use crate::types::{
+    Coord, Dimension, GeometryType, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+use crate::{Wkt, WktNum};
+
+/// Offsets describing how the flat coordinate buffer of an [`FfiGeometry`] is nested.
+///
+/// Each buffer holds one more entry than the number of items it describes, so item `i` spans
+/// `buffer[i]..buffer[i + 1]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Offsets {
+    /// The range of [`rings`](Self::rings) making up each polygon of a `MULTIPOLYGON`.
+    ///
+    /// Empty for every other geometry type.
+    pub polygons: Vec<usize>,
+    /// The range of coordinates making up each ring of a `POLYGON` or `MULTIPOLYGON`, or each
+    /// line of a `MULTILINESTRING`.
+    ///
+    /// Empty for `POINT`, `LINESTRING` and `MULTIPOINT`, whose coordinates need no nesting.
+    pub rings: Vec<usize>,
+}
+
+/// A geometry flattened into plain buffers, as returned by [`Wkt::to_ffi_parts`].
+///
+/// The buffers can be handed to C as a pointer and length each, e.g. with
+/// [`Vec::as_ptr`] and [`Vec::len`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FfiGeometry<T: WktNum> {
+    /// The ISO WKB code of the geometry type, from 1 for `POINT` to 6 for `MULTIPOLYGON`.
+    pub type_code: u32,
+    /// The dimension of each coordinate in [`coords`](Self::coords).
+    pub dim: Dimension,
+    /// Interleaved coordinate ordinates, two to four per coordinate depending on
+    /// [`dim`](Self::dim). An empty point is stored as NaN ordinates, as is a missing z or m.
+    pub coords: Vec<T>,
+    /// How [`coords`](Self::coords) is divided into rings, lines and polygons.
+    pub offsets: Offsets,
+}
+
+impl<T: WktNum + Float> FfiGeometry<T> {
+    fn new(type_code: u32, dim: Dimension) -> Self {
+        let offsets = Offsets {
+            polygons: if type_code == 6 { vec![0] } else { vec![] },
+            rings: if matches!(type_code, 3 | 5 | 6) {
+                vec![0]
+            } else {
+                vec![]
+            },
+        };
+        FfiGeometry {
+            type_code,
+            dim,
+            coords: Vec::new(),
+            offsets,
+        }
+    }
+
+    fn push_coord(&mut self, coord: Option<&Coord<T>>) {
+        let (x, y, z, m) = match coord {
+            Some(coord) => (coord.x, coord.y, coord.z, coord.m),
+            None => (T::nan(), T::nan(), None, None),
+        };
+        self.coords.extend([x, y]);
+        if matches!(self.dim, Dimension::XYZ | Dimension::XYZM) {
+            self.coords.push(z.unwrap_or_else(T::nan));
+        }
+        if matches!(self.dim, Dimension::XYM | Dimension::XYZM) {
+            self.coords.push(m.unwrap_or_else(T::nan));
+        }
+    }
+
+    fn push_ring(&mut self, ring: &LineString<T>) {
+        for coord in &ring.0 {
+            self.push_coord(Some(coord));
+        }
+        self.offsets
+            .rings
+            .push(self.coords.len() / coord_size(self.dim));
+    }
+
+    fn push_polygon(&mut self, polygon: &Polygon<T>) {
+        for ring in &polygon.0 {
+            self.push_ring(ring);
+        }
+    }
+}
+
+/// The number of ordinates of each coordinate of `dim` in a flat buffer.
+fn coord_size(dim: Dimension) -> usize {
+    match dim {
+        Dimension::XY => 2,
+        Dimension::XYZ | Dimension::XYM => 3,
+        Dimension::XYZM => 4,
+    }
+}
+
+/// Read one coordinate of `dim` from its ordinates, treating a NaN z or m as missing.
+fn read_coord<T: WktNum + Float>(ordinates: &[T], dim: Dimension) -> Coord<T> {
+    let present = |value: T| (!value.is_nan()).then_some(value);
+    let (z, m) = match dim {
+        Dimension::XY => (None, None),
+        Dimension::XYZ => (present(ordinates[2]), None),
+        Dimension::XYM => (None, present(ordinates[2])),
+        Dimension::XYZM => (present(ordinates[2]), present(ordinates[3])),
+    };
+    Coord {
+        x: ordinates[0],
+        y: ordinates[1],
+        z,
+        m,
+    }
+}
+
+/// A point whose x and y are both NaN is empty, as written by [`Wkt::to_ffi_parts`].
+fn read_point<T: WktNum + Float>(coord: &Coord<T>, dim: Dimension) -> Point<T> {
+    if coord.x.is_nan() && coord.y.is_nan() {
+        Point::empty(dim)
+    } else {
+        Point::new(coord.clone())
+    }
+}
+
+/// Split an offset buffer into the ranges of the `len` items it indexes.
+fn offset_ranges(offsets: &[usize], len: usize) -> Result<Vec<Range<usize>>, CoordBufferError> {
+    let valid = offsets.first() == Some(&0)
+        && offsets.last() == Some(&len)
+        && offsets.windows(2).all(|pair| pair[0] <= pair[1]);
+    if !valid {
+        return Err(CoordBufferError::InvalidOffsets);
+    }
+    Ok(offsets.windows(2).map(|pair| pair[0]..pair[1]).collect())
+}
+
+fn split_rings<T: WktNum>(
+    coords: &[Coord<T>],
+    offsets: &[usize],
+    dim: Dimension,
+) -> Result<Vec<LineString<T>>, CoordBufferError> {
+    Ok(offset_ranges(offsets, coords.len())?
+        .into_iter()
+        .map(|range| LineString(coords[range].to_vec(), dim))
+        .collect())
+}
+
+impl<T: WktNum + Float> Wkt<T> {
+    /// Flatten this geometry into an [`FfiGeometry`] of plain buffers.
+    ///
+    /// Returns `None` for a `GEOMETRYCOLLECTION`, which has no flat representation.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("MULTILINESTRING Z((1 2 3,4 5 6),(7 8 9,1 2 3))").unwrap();
+    /// let parts = wkt.to_ffi_parts().unwrap();
+    /// assert_eq!(parts.type_code, 5);
+    /// assert_eq!(parts.coords.len(), 12);
+    /// assert_eq!(parts.offsets.rings, vec![0, 2, 4]);
+    /// ```
+    pub fn to_ffi_parts(&self) -> Option<FfiGeometry<T>> {
+        let dim = self.dimension();
+        let parts = match self {
+            Wkt::Point(point) => {
+                let mut parts = FfiGeometry::new(1, dim);
+                parts.push_coord(point.0.as_ref());
+                parts
+            }
+            Wkt::LineString(line_string) | Wkt::LinearRing(line_string) => {
+                let mut parts = FfiGeometry::new(2, dim);
+                for coord in &line_string.0 {
+                    parts.push_coord(Some(coord));
+                }
+                parts
+            }
+            Wkt::Polygon(polygon) => {
+                let mut parts = FfiGeometry::new(3, dim);
+                parts.push_polygon(polygon);
+                parts
+            }
+            Wkt::MultiPoint(multi_point) => {
+                let mut parts = FfiGeometry::new(4, dim);
+                for point in &multi_point.0 {
+                    parts.push_coord(point.0.as_ref());
+                }
+                parts
+            }
+            Wkt::MultiLineString(multi_line_string) => {
+                let mut parts = FfiGeometry::new(5, dim);
+                for line_string in &multi_line_string.0 {
+                    parts.push_ring(line_string);
+                }
+                parts
+            }
+            Wkt::MultiPolygon(multi_polygon) => {
+                let mut parts = FfiGeometry::new(6, dim);
+                for polygon in &multi_polygon.0 {
+                    parts.push_polygon(polygon);
+                    let num_rings = parts.offsets.rings.len() - 1;
+                    parts.offsets.polygons.push(num_rings);
+                }
+                parts
+            }
+            Wkt::GeometryCollection(_) => return None,
+        };
+        Some(parts)
+    }
+
+    /// Rebuild a geometry from a flat coordinate buffer and the offsets describing its nesting,
+    /// the inverse of [`to_ffi_parts`](Self::to_ffi_parts).
+    ///
+    /// `coords` holds the interleaved ordinates of each coordinate in `dim`. A NaN z or m is read
+    /// as missing, and a point with NaN x and y as empty. Only the offset buffers which
+    /// `geometry_type` uses are read, and a `GEOMETRYCOLLECTION` can't be rebuilt.
+    ///
+    /// ```
+    /// use wkt::ffi::Offsets;
+    /// use wkt::types::{Dimension, GeometryType};
+    /// use wkt::Wkt;
+    ///
+    /// let offsets = Offsets {
+    ///     polygons: vec![],
+    ///     rings: vec![0, 2, 4],
+    /// };
+    /// let coords = [1., 2., 3., 4., 5., 6., 7., 8.];
+    /// let kind = GeometryType::MultiLineString;
+    /// let wkt = Wkt::from_coord_buffer(kind, Dimension::XY, &coords, &offsets).unwrap();
+    /// assert_eq!(wkt.to_string(), "MULTILINESTRING((1 2,3 4),(5 6,7 8))");
+    /// ```
+    pub fn from_coord_buffer(
+        geometry_type: GeometryType,
+        dim: Dimension,
+        coords: &[T],
+        offsets: &Offsets,
+    ) -> Result<Wkt<T>, CoordBufferError> {
+        let size = coord_size(dim);
+        if coords.len() % size != 0 {
+            return Err(CoordBufferError::RaggedCoords {
+                len: coords.len(),
+                dim,
+            });
+        }
+        let coords: Vec<Coord<T>> = coords
+            .chunks_exact(size)
+            .map(|ordinates| read_coord(ordinates, dim))
+            .collect();
+
+        Ok(match geometry_type {
+            GeometryType::Point => match coords.as_slice() {
+                [coord] => Wkt::Point(read_point(coord, dim)),
+                coords => return Err(CoordBufferError::PointCoordCount(coords.len())),
+            },
+            GeometryType::LineString => Wkt::LineString(LineString(coords, dim)),
+            GeometryType::Polygon => {
+                Wkt::Polygon(Polygon(split_rings(&coords, &offsets.rings, dim)?, dim))
+            }
+            GeometryType::MultiPoint => Wkt::MultiPoint(MultiPoint(
+                coords.iter().map(|coord| read_point(coord, dim)).collect(),
+                dim,
+            )),
+            GeometryType::MultiLineString => Wkt::MultiLineString(MultiLineString(
+                split_rings(&coords, &offsets.rings, dim)?,
+                dim,
+            )),
+            GeometryType::MultiPolygon => {
+                let mut rings = split_rings(&coords, &offsets.rings, dim)?;
+                let polygons = offset_ranges(&offsets.polygons, rings.len())?
+                    .into_iter()
+                    .rev()
+                    .map(|range| Polygon(rings.split_off(range.start), dim))
+                    .collect::<Vec<_>>();
+                Wkt::MultiPolygon(MultiPolygon(polygons.into_iter().rev().collect(), dim))
+            }
+            GeometryType::GeometryCollection => {
+                return Err(CoordBufferError::UnsupportedType(geometry_type))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Offsets;
+    use crate::error::CoordBufferError;
+    use crate::types::{Dimension, GeometryType};
+    use crate::Wkt;
+    use std::str::FromStr;
+
+    #[test]
+    fn polygon_ffi_parts() {
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "POLYGON Z((0 0 0,10 0 0,10 10 0,0 10 0,0 0 0),(4 4 0,6 4 0,5 6 0,4 4 0))",
+        )
+        .unwrap();
+        let parts = wkt.to_ffi_parts().unwrap();
+        assert_eq!(parts.type_code, 3);
+        assert_eq!(parts.dim, Dimension::XYZ);
+        assert_eq!(parts.coords.len(), 9 * 3);
+        assert_eq!(&parts.coords[3..6], &[10., 0., 0.]);
+        assert_eq!(parts.offsets.rings, vec![0, 5, 9]);
+        assert!(parts.offsets.polygons.is_empty());
+    }
+
+    #[test]
+    fn multi_polygon_ffi_parts() {
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "MULTIPOLYGON Z(((0 0 0,1 0 0,1 1 0,0 0 0)),((5 5 5,6 5 5,6 6 5,5 5 5),(1 1 1,2 1 1,1 2 1,1 1 1)))",
+        )
+        .unwrap();
+        let parts = wkt.to_ffi_parts().unwrap();
+        assert_eq!(parts.type_code, 6);
+        assert_eq!(parts.coords.len(), 12 * 3);
+        assert_eq!(parts.offsets.polygons, vec![0, 1, 3]);
+        assert_eq!(parts.offsets.rings, vec![0, 4, 8, 12]);
+    }
+
+    #[test]
+    fn measured_ffi_parts() {
+        let wkt: Wkt<f64> = Wkt::from_str("LINESTRING M(1 2 3,4 5 6)").unwrap();
+        let parts = wkt.to_ffi_parts().unwrap();
+        assert_eq!(parts.dim, Dimension::XYM);
+        assert_eq!(parts.coords, vec![1., 2., 3., 4., 5., 6.]);
+    }
+
+    #[test]
+    fn geometry_collection_has_no_ffi_parts() {
+        let wkt: Wkt<f64> = Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(1 2 3))").unwrap();
+        assert!(wkt.to_ffi_parts().is_none());
+    }
+
+    #[test]
+    fn rebuild_polygon_from_coord_buffer() {
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "POLYGON Z((0 0 0,10 0 0,10 10 0,0 10 0,0 0 0),(4 4 0,6 4 0,5 6 0,4 4 0))",
+        )
+        .unwrap();
+        let parts = wkt.to_ffi_parts().unwrap();
+        let rebuilt = Wkt::from_coord_buffer(
+            GeometryType::Polygon,
+            parts.dim,
+            &parts.coords,
+            &parts.offsets,
+        );
+        assert_eq!(rebuilt, Ok(wkt));
+    }
+
+    #[test]
+    fn rebuild_from_coord_buffer_round_trips() {
+        for (geometry_type, wkt) in [
+            (GeometryType::Point, "POINT(1 2)"),
+            (GeometryType::Point, "POINT EMPTY"),
+            (GeometryType::LineString, "LINESTRING Z(1 2 3,4 5 6)"),
+            (GeometryType::LineString, "LINESTRING ZM(1 2 3 4,5 6 7 8)"),
+            (GeometryType::MultiPoint, "MULTIPOINT M((1 2 3),(4 5 6))"),
+            (GeometryType::MultiPoint, "MULTIPOINT Z((1 2 3),(4 5 6))"),
+            (
+                GeometryType::MultiLineString,
+                "MULTILINESTRING((1 2,3 4),(5 6,7 8))",
+            ),
+            (
+                GeometryType::MultiPolygon,
+                "MULTIPOLYGON Z(((0 0 0,1 0 0,1 1 0,0 0 0)),\
+                 ((5 5 5,6 5 5,6 6 5,5 5 5),(1 1 1,2 1 1,1 2 1,1 1 1)))",
+            ),
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str(wkt).unwrap();
+            let parts = wkt.to_ffi_parts().unwrap();
+            let rebuilt =
+                Wkt::from_coord_buffer(geometry_type, parts.dim, &parts.coords, &parts.offsets);
+            assert_eq!(rebuilt, Ok(wkt));
+        }
+    }
+
+    #[test]
+    fn invalid_coord_buffers() {
+        let offsets = Offsets {
+            polygons: vec![],
+            rings: vec![0, 2],
+        };
+        let build = |geometry_type, coords: &[f64], offsets: &Offsets| {
+            Wkt::from_coord_buffer(geometry_type, Dimension::XY, coords, offsets)
+        };
+        assert_eq!(
+            build(GeometryType::Polygon, &[1., 2., 3.], &offsets),
+            Err(CoordBufferError::RaggedCoords {
+                len: 3,
+                dim: Dimension::XY
+            })
+        );
+        assert_eq!(
+            build(GeometryType::Polygon, &[1., 2., 3., 4., 5., 6.], &offsets),
+            Err(CoordBufferError::InvalidOffsets)
+        );
+        assert_eq!(
+            build(GeometryType::Point, &[1., 2., 3., 4.], &offsets),
+            Err(CoordBufferError::PointCoordCount(2))
+        );
+        assert_eq!(
+            build(GeometryType::GeometryCollection, &[], &Offsets::default()),
+            Err(CoordBufferError::UnsupportedType(
+                GeometryType::GeometryCollection
+            ))
+        );
+    }
+}
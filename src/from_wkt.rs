@@ -1,7 +1,17 @@
+use std::io::Read;
+use std::str::FromStr;
+
+use crate::geo_types_from_wkt::Error;
+use crate::types::Dimension;
+use crate::{Wkt, WktNum};
+
 /// Create geometries from WKT.
 ///
 /// A default implementation exists for [geo-types](../geo-types), or you can implement this trait
 /// for your own types.
+///
+/// `(T, T)` and `[T; 2]` can also be read from a 2D `POINT`, and `(T, T, T)` and `[T; 3]` from a
+/// `POINT Z`, for when a single coordinate is all that's needed.
 pub trait TryFromWkt<T>: Sized {
     type Error;
 
@@ -26,4 +36,172 @@ pub trait TryFromWkt<T>: Sized {
     /// assert_eq!(point.y(), 20.0);
     /// ```
     fn try_from_wkt_reader(wkt_reader: impl std::io::Read) -> Result<Self, Self::Error>;
+
+    /// Like [`try_from_wkt_reader`](Self::try_from_wkt_reader), but fails without reading further
+    /// once the input exceeds `max_bytes`, rather than buffering unbounded input from untrusted
+    /// sources.
+    ///
+    /// The default implementation buffers at most `max_bytes` and passes them on to
+    /// `try_from_wkt_reader`, returning [`Error::InputTooLarge`] for longer input.
+    ///
+    /// # Examples
+    /// ```
+    /// // This example requires the geo-types feature (on by default).
+    /// use wkt::TryFromWkt;
+    /// use geo_types::Point;
+    ///
+    /// let fake_file = "POINT Z(10 20 30)".as_bytes().to_vec();
+    /// let point: Point<f64> = Point::try_from_wkt_reader_limited(&*fake_file, 1024).unwrap();
+    /// assert_eq!(point.y(), 20.0);
+    /// assert!(Point::<f64>::try_from_wkt_reader_limited(&*fake_file, 8).is_err());
+    /// ```
+    fn try_from_wkt_reader_limited(
+        wkt_reader: impl std::io::Read,
+        max_bytes: usize,
+    ) -> Result<Self, Self::Error>
+    where
+        Self::Error: From<Error>,
+    {
+        let bytes = read_wkt_bytes(wkt_reader, Some(max_bytes))?;
+        Self::try_from_wkt_reader(&*bytes)
+    }
+}
+
+/// Read all of `wkt_reader`, failing once it exceeds `max_bytes` if there's a limit.
+pub(crate) fn read_wkt_bytes(
+    wkt_reader: impl Read,
+    max_bytes: Option<usize>,
+) -> Result<Vec<u8>, Error> {
+    let mut bytes = vec![];
+    match max_bytes {
+        Some(max_bytes) => {
+            // Read one byte past the limit, to tell input of exactly `max_bytes` from more
+            let limit = (max_bytes as u64).saturating_add(1);
+            wkt_reader
+                .take(limit)
+                .read_to_end(&mut bytes)
+                .map_err(|e| Error::External(Box::new(e)))?;
+            if bytes.len() > max_bytes {
+                return Err(Error::InputTooLarge(max_bytes));
+            }
+        }
+        None => {
+            let mut wkt_reader = wkt_reader;
+            wkt_reader
+                .read_to_end(&mut bytes)
+                .map_err(|e| Error::External(Box::new(e)))?;
+        }
+    }
+    Ok(bytes)
+}
+
+/// Read all of `wkt_reader` as UTF-8, failing once it exceeds `max_bytes` if there's a limit.
+pub(crate) fn read_wkt_string(
+    wkt_reader: impl Read,
+    max_bytes: Option<usize>,
+) -> Result<String, Error> {
+    let bytes = read_wkt_bytes(wkt_reader, max_bytes)?;
+    String::from_utf8(bytes).map_err(|e| Error::External(Box::new(e)))
+}
+
+/// Macro for implementing `TryFromWkt` for plain coordinate tuples and arrays, which can be read
+/// from a non-empty `POINT` of exactly the matching dimension.
+macro_rules! try_from_wkt_coord_impl {
+    ($($type:ty, $dim:expr, |$coord:ident| $convert:expr);* $(;)?) => {
+        $(
+            impl<T: WktNum + FromStr> TryFromWkt<T> for $type {
+                type Error = Error;
+
+                fn try_from_wkt_str(wkt_str: &str) -> Result<Self, Self::Error> {
+                    let point = match Wkt::from_str(wkt_str).map_err(Error::InvalidWKT)? {
+                        Wkt::Point(point) => point,
+                        other => {
+                            return Err(Error::MismatchedGeometry {
+                                expected: "Point",
+                                found: other.geometry_type_name(),
+                            })
+                        }
+                    };
+                    if point.1 != $dim {
+                        return Err(Error::MismatchedDimension {
+                            expected: $dim,
+                            found: point.1,
+                        });
+                    }
+                    let $coord = point.0.ok_or(Error::PointConversionError)?;
+                    Ok($convert)
+                }
+
+                fn try_from_wkt_reader(wkt_reader: impl Read) -> Result<Self, Self::Error> {
+                    Self::try_from_wkt_str(&read_wkt_string(wkt_reader, None)?)
+                }
+            }
+        )*
+    };
+}
+
+// The point's dimension is checked before conversion, and an XYZ point always has a z
+try_from_wkt_coord_impl! {
+    (T, T), Dimension::XY, |c| (c.x, c.y);
+    (T, T, T), Dimension::XYZ, |c| (c.x, c.y, c.z.expect("an XYZ point has a z"));
+    [T; 2], Dimension::XY, |c| [c.x, c.y];
+    [T; 3], Dimension::XYZ, |c| [c.x, c.y, c.z.expect("an XYZ point has a z")];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TryFromWkt;
+    use crate::geo_types_from_wkt::Error;
+    use crate::types::Dimension;
+
+    #[test]
+    fn tuples_and_arrays_from_points() {
+        assert_eq!(
+            <(f64, f64)>::try_from_wkt_str("POINT(1 2)").unwrap(),
+            (1., 2.)
+        );
+        assert_eq!(
+            <(f64, f64, f64)>::try_from_wkt_str("POINT Z(1 2 3)").unwrap(),
+            (1., 2., 3.)
+        );
+        assert_eq!(<[i32; 2]>::try_from_wkt_str("POINT(1 2)").unwrap(), [1, 2]);
+        assert_eq!(
+            <[f64; 3]>::try_from_wkt_reader("POINT Z(1 2 3)".as_bytes()).unwrap(),
+            [1., 2., 3.]
+        );
+    }
+
+    #[test]
+    fn tuples_and_arrays_errors() {
+        assert!(matches!(
+            <(f64, f64)>::try_from_wkt_str("POINT Z(1 2 3)"),
+            Err(Error::MismatchedDimension {
+                expected: Dimension::XY,
+                found: Dimension::XYZ
+            })
+        ));
+        assert!(matches!(
+            <[f64; 3]>::try_from_wkt_str("POINT M(1 2 3)"),
+            Err(Error::MismatchedDimension { .. })
+        ));
+        assert!(matches!(
+            <(f64, f64)>::try_from_wkt_str("LINESTRING(1 2,3 4)"),
+            Err(Error::MismatchedGeometry {
+                found: "LineString",
+                ..
+            })
+        ));
+        assert!(matches!(
+            <[f64; 2]>::try_from_wkt_str("POINT EMPTY"),
+            Err(Error::PointConversionError)
+        ));
+        assert!(matches!(
+            <(f64, f64)>::try_from_wkt_str("POINT(1"),
+            Err(Error::InvalidWKT(_))
+        ));
+        assert!(matches!(
+            <(f64, f64)>::try_from_wkt_reader_limited("POINT(1 2)".as_bytes(), 4),
+            Err(Error::InputTooLarge(4))
+        ));
+    }
 }
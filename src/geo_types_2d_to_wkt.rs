@@ -0,0 +1,213 @@
+//! Conversions from mainline 2D [`geo-types`](https://docs.rs/geo-types) primitives to WKT,
+//! enabled by the `geo-types-2d` feature.
+//!
+//! Mainline coordinates have no `z`, so these write 2D WKT, e.g. `POINT(1 2)`, where the
+//! conversions from the 3D fork in [`geo_types_to_wkt`](crate::geo_types_to_wkt) write
+//! `POINT Z(1 2 3)`. Both sets of impls are available together.
+
+use geo_types_2d::CoordNum as CoordNum2d;
+
+use crate::types::{
+    Coord, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+use crate::{ToWkt, Wkt};
+
+macro_rules! impl_to_wkt_2d {
+    ($($type:ident => $convert:ident),* $(,)?) => {
+        $(
+            impl<T> ToWkt<T> for geo_types_2d::$type<T>
+            where
+                T: CoordNum2d + geo_types::CoordNum + std::fmt::Display + Default,
+            {
+                fn to_wkt(&self) -> Wkt<T> {
+                    $convert(self).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_wkt_2d! {
+    Geometry => g_geom_to_w_geom,
+    Point => g_point_to_w_point,
+    Line => g_line_to_w_linestring,
+    LineString => g_linestring_to_w_linestring,
+    Polygon => g_polygon_to_w_polygon,
+    MultiPoint => g_mpoint_to_w_mpoint,
+    MultiLineString => g_mline_to_w_mline,
+    MultiPolygon => g_mpolygon_to_w_mpolygon,
+    GeometryCollection => g_geocol_to_w_geocol,
+    Rect => g_rect_to_w_polygon,
+    Triangle => g_triangle_to_w_polygon,
+}
+
+fn g_point_to_w_coord<T: CoordNum2d + Default>(g_point: &geo_types_2d::Coord<T>) -> Coord<T> {
+    Coord {
+        x: g_point.x,
+        y: g_point.y,
+        z: None,
+        m: None,
+    }
+}
+
+fn g_point_to_w_point<T: CoordNum2d + Default>(g_point: &geo_types_2d::Point<T>) -> Point<T> {
+    Point::new(g_point_to_w_coord(&g_point.0))
+}
+
+fn g_points_to_w_linestring<T: CoordNum2d + Default>(
+    g_coords: &[geo_types_2d::Coord<T>],
+) -> LineString<T> {
+    LineString::new(g_coords.iter().map(g_point_to_w_coord).collect())
+}
+
+fn g_line_to_w_linestring<T: CoordNum2d + Default>(
+    g_line: &geo_types_2d::Line<T>,
+) -> LineString<T> {
+    g_points_to_w_linestring(&[g_line.start, g_line.end])
+}
+
+fn g_linestring_to_w_linestring<T: CoordNum2d + Default>(
+    g_linestring: &geo_types_2d::LineString<T>,
+) -> LineString<T> {
+    g_points_to_w_linestring(&g_linestring.0)
+}
+
+fn g_polygon_to_w_polygon<T: CoordNum2d + Default>(
+    g_polygon: &geo_types_2d::Polygon<T>,
+) -> Polygon<T> {
+    let mut poly_lines = vec![];
+
+    // Outer
+    let outer_points = &g_polygon.exterior().0;
+    if !outer_points.is_empty() {
+        poly_lines.push(g_points_to_w_linestring(outer_points));
+    }
+
+    // Inner
+    poly_lines.extend(
+        g_polygon
+            .interiors()
+            .iter()
+            .map(g_linestring_to_w_linestring),
+    );
+
+    Polygon::new(poly_lines)
+}
+
+fn g_rect_to_w_polygon<T: CoordNum2d + Default>(g_rect: &geo_types_2d::Rect<T>) -> Polygon<T> {
+    g_polygon_to_w_polygon(&g_rect.to_polygon())
+}
+
+fn g_triangle_to_w_polygon<T: CoordNum2d + Default>(
+    g_triangle: &geo_types_2d::Triangle<T>,
+) -> Polygon<T> {
+    g_polygon_to_w_polygon(&g_triangle.to_polygon())
+}
+
+fn g_mpoint_to_w_mpoint<T: CoordNum2d + Default>(
+    g_mpoint: &geo_types_2d::MultiPoint<T>,
+) -> MultiPoint<T> {
+    MultiPoint::new(g_mpoint.0.iter().map(g_point_to_w_point).collect())
+}
+
+fn g_mline_to_w_mline<T: CoordNum2d + Default>(
+    g_mline: &geo_types_2d::MultiLineString<T>,
+) -> MultiLineString<T> {
+    MultiLineString::new(g_mline.0.iter().map(g_linestring_to_w_linestring).collect())
+}
+
+fn g_mpolygon_to_w_mpolygon<T: CoordNum2d + Default>(
+    g_mpolygon: &geo_types_2d::MultiPolygon<T>,
+) -> MultiPolygon<T> {
+    MultiPolygon::new(g_mpolygon.0.iter().map(g_polygon_to_w_polygon).collect())
+}
+
+fn g_geocol_to_w_geocol<T: CoordNum2d + Default>(
+    g_geocol: &geo_types_2d::GeometryCollection<T>,
+) -> GeometryCollection<T> {
+    GeometryCollection::new(g_geocol.0.iter().map(g_geom_to_w_geom).collect())
+}
+
+fn g_geom_to_w_geom<T: CoordNum2d + Default>(g_geom: &geo_types_2d::Geometry<T>) -> Wkt<T> {
+    match g_geom {
+        geo_types_2d::Geometry::Point(g) => g_point_to_w_point(g).into(),
+        geo_types_2d::Geometry::Line(g) => g_line_to_w_linestring(g).into(),
+        geo_types_2d::Geometry::LineString(g) => g_linestring_to_w_linestring(g).into(),
+        geo_types_2d::Geometry::Polygon(g) => g_polygon_to_w_polygon(g).into(),
+        geo_types_2d::Geometry::MultiPoint(g) => g_mpoint_to_w_mpoint(g).into(),
+        geo_types_2d::Geometry::MultiLineString(g) => g_mline_to_w_mline(g).into(),
+        geo_types_2d::Geometry::MultiPolygon(g) => g_mpolygon_to_w_mpolygon(g).into(),
+        geo_types_2d::Geometry::GeometryCollection(g) => g_geocol_to_w_geocol(g).into(),
+        geo_types_2d::Geometry::Rect(g) => g_rect_to_w_polygon(g).into(),
+        geo_types_2d::Geometry::Triangle(g) => g_triangle_to_w_polygon(g).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ToWkt;
+
+    #[test]
+    fn float_geom() {
+        let point = geo_types_2d::Point::new(1f32, 2f32);
+        assert_eq!("POINT(1 2)", &point.wkt_string());
+
+        let point = geo_types_2d::Point::new(1.1, 2.9);
+        assert_eq!("POINT(1.1 2.9)", &point.wkt_string());
+    }
+
+    #[test]
+    fn geometries_2d() {
+        use geo_types_2d::{coord, line_string, point, polygon};
+
+        let line_string = line_string![(x: 1., y: 2.), (x: 3., y: 4.)];
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 4.), (x: 0., y: 0.)];
+        let cases: [(geo_types_2d::Geometry<f64>, &str); 7] = [
+            (
+                geo_types_2d::Line::new(coord!(x: 1., y: 2.), coord!(x: 3., y: 4.)).into(),
+                "LINESTRING(1 2,3 4)",
+            ),
+            (line_string.clone().into(), "LINESTRING(1 2,3 4)"),
+            (polygon.clone().into(), "POLYGON((0 0,4 0,2 4,0 0))"),
+            (
+                geo_types_2d::MultiPoint::new(vec![point!(x: 1., y: 2.)]).into(),
+                "MULTIPOINT((1 2))",
+            ),
+            (
+                geo_types_2d::Rect::new(coord!(x: 0., y: 0.), coord!(x: 1., y: 2.)).into(),
+                "POLYGON((1 0,1 2,0 2,0 0,1 0))",
+            ),
+            (
+                geo_types_2d::Geometry::GeometryCollection(
+                    geo_types_2d::GeometryCollection::new_from(vec![
+                        line_string.into(),
+                        geo_types_2d::MultiPolygon::new(vec![polygon]).into(),
+                    ]),
+                ),
+                "GEOMETRYCOLLECTION(LINESTRING(1 2,3 4),MULTIPOLYGON(((0 0,4 0,2 4,0 0))))",
+            ),
+            (
+                geo_types_2d::Triangle::new(
+                    coord!(x: 0., y: 0.),
+                    coord!(x: 4., y: 0.),
+                    coord!(x: 2., y: 4.),
+                )
+                .into(),
+                "POLYGON((0 0,4 0,2 4,0 0))",
+            ),
+        ];
+        for (geometry, expected) in cases {
+            assert_eq!(geometry.wkt_string(), expected);
+        }
+    }
+
+    #[test]
+    fn same_geometry_from_2d_and_3d() {
+        let flat = geo_types_2d::Point::new(1., 2.);
+        let with_z = geo_types::Point::new(1., 2., 3.);
+        assert_eq!(flat.wkt_string(), "POINT(1 2)");
+        assert_eq!(with_z.wkt_string(), "POINT Z(1 2 3)");
+        assert_eq!(with_z.to_wkt().to_wkt_string_2d(), flat.wkt_string());
+    }
+}
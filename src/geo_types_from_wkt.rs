@@ -15,6 +15,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::error::ParseWktError;
+use crate::from_wkt::read_wkt_string;
 use crate::types::*;
 use crate::{TryFromWkt, Wkt};
 
@@ -36,12 +38,48 @@ pub enum Error {
         expected: &'static str,
         found: &'static str,
     },
+    #[error("Mismatched dimension (expected {expected:?}, found {found:?})")]
+    MismatchedDimension {
+        expected: Dimension,
+        found: Dimension,
+    },
     #[error("Wrong number of Geometries: {0}")]
     WrongNumberOfGeometries(usize),
-    #[error("Invalid WKT: {0}")]
-    InvalidWKT(&'static str),
-    #[error("External error: {0}")]
-    External(Box<dyn std::error::Error>),
+    #[error("A {type_name} needs {needed} coordinates, but only {found} were found")]
+    InsufficientCoordinates {
+        type_name: &'static str,
+        needed: usize,
+        found: usize,
+    },
+    /// The input isn't valid WKT; the [`ParseWktError`] is the
+    /// [`source`](std::error::Error::source) of this error.
+    #[error("Invalid WKT")]
+    InvalidWKT(#[from] ParseWktError),
+    #[error("The WKT input exceeded the limit of {0} bytes")]
+    InputTooLarge(usize),
+    /// An error from reading the input or from `geo_types`, available as the
+    /// [`source`](std::error::Error::source) of this error.
+    #[error("External error")]
+    External(#[source] Box<dyn std::error::Error + Send + Sync>),
+}
+
+/// Convert `wkt` through [`geo_types::Geometry`], mapping the error from `geo_types`.
+fn try_from_geometry<T, G>(wkt: Wkt<T>) -> Result<G, Error>
+where
+    T: CoordNum + Default,
+    G: TryFrom<geo_types::Geometry<T>, Error = geo_types::Error>,
+{
+    let geometry = geo_types::Geometry::try_from(wkt)?;
+    G::try_from(geometry).map_err(|e| {
+        match e {
+            geo_types::Error::MismatchedGeometry { expected, found } => {
+                Error::MismatchedGeometry { expected, found }
+            }
+            // currently only one error type in geo-types error enum, but that seems likely to change
+            #[allow(unreachable_patterns)]
+            other => Error::External(Box::new(other)),
+        }
+    })
 }
 
 macro_rules! try_from_wkt_impl {
@@ -52,17 +90,7 @@ macro_rules! try_from_wkt_impl {
                 type Error = Error;
 
                 fn try_from(wkt: Wkt<T>) -> Result<Self, Self::Error> {
-                    let geometry = geo_types::Geometry::try_from(wkt)?;
-                    Self::try_from(geometry).map_err(|e| {
-                        match e {
-                            geo_types::Error::MismatchedGeometry { expected, found } => {
-                                Error::MismatchedGeometry { expected, found }
-                            }
-                            // currently only one error type in geo-types error enum, but that seems likely to change
-                            #[allow(unreachable_patterns)]
-                            other => Error::External(Box::new(other)),
-                        }
-                    })
+                    try_from_geometry(wkt)
                 }
             }
         )+
@@ -71,18 +99,104 @@ macro_rules! try_from_wkt_impl {
 
 try_from_wkt_impl!(
     Point,
-    Line,
     LineString,
     Polygon,
     MultiPoint,
     MultiLineString,
-    MultiPolygon,
-    // See impl below.
-    // GeometryCollection,
-    Rect,
-    Triangle
+    MultiPolygon // See impls below.
+                 // GeometryCollection,
+                 // Line,
+                 // Rect,
+                 // Triangle
 );
 
+/// The coordinates of the exterior ring of `polygon`, without the closing coordinate.
+fn exterior_vertices<T: CoordNum + Default>(polygon: &Polygon<T>) -> &[Coord<T>] {
+    let Some(exterior) = polygon.0.first() else {
+        return &[];
+    };
+    match exterior.0.as_slice() {
+        [first, .., last] if first == last => &exterior.0[..exterior.0.len() - 1],
+        coords => coords,
+    }
+}
+
+/// Fallibly convert a WKT `LINESTRING` with exactly two coordinates into a [`geo_types::Line`]
+impl<T: CoordNum + Default> TryFrom<Wkt<T>> for geo_types::Line<T> {
+    type Error = Error;
+
+    fn try_from(wkt: Wkt<T>) -> Result<Self, Self::Error> {
+        let Wkt::LineString(line_string) = wkt else {
+            return try_from_geometry(wkt);
+        };
+        match line_string.0.as_slice() {
+            [start, end] => Ok(Self::new(
+                geo_types::Coord::from(start.clone()),
+                geo_types::Coord::from(end.clone()),
+            )),
+            coords if coords.len() < 2 => Err(Error::InsufficientCoordinates {
+                type_name: type_name::<Self>(),
+                needed: 2,
+                found: coords.len(),
+            }),
+            _ => Err(Error::MismatchedGeometry {
+                expected: type_name::<Self>(),
+                found: type_name::<geo_types::LineString<T>>(),
+            }),
+        }
+    }
+}
+
+/// Fallibly convert a WKT `POLYGON` with a three-vertex exterior and no interiors into a
+/// [`geo_types::Triangle`]
+impl<T: CoordNum + Default> TryFrom<Wkt<T>> for geo_types::Triangle<T> {
+    type Error = Error;
+
+    fn try_from(wkt: Wkt<T>) -> Result<Self, Self::Error> {
+        let Wkt::Polygon(polygon) = wkt else {
+            return try_from_geometry(wkt);
+        };
+        match exterior_vertices(&polygon) {
+            [a, b, c] if polygon.0.len() == 1 => Ok(Self::new(
+                geo_types::Coord::from(a.clone()),
+                geo_types::Coord::from(b.clone()),
+                geo_types::Coord::from(c.clone()),
+            )),
+            vertices if vertices.len() < 3 => Err(Error::InsufficientCoordinates {
+                type_name: type_name::<Self>(),
+                needed: 3,
+                found: vertices.len(),
+            }),
+            _ => Err(Error::MismatchedGeometry {
+                expected: type_name::<Self>(),
+                found: type_name::<geo_types::Polygon<T>>(),
+            }),
+        }
+    }
+}
+
+/// Fallibly convert this WKT primitive into a [`geo_types::Rect`]
+///
+/// A `POLYGON` is never converted into a `Rect`, but one with fewer than four exterior vertices
+/// reports [`Error::InsufficientCoordinates`] rather than a geometry mismatch.
+impl<T: CoordNum + Default> TryFrom<Wkt<T>> for geo_types::Rect<T> {
+    type Error = Error;
+
+    fn try_from(wkt: Wkt<T>) -> Result<Self, Self::Error> {
+        if let Wkt::Polygon(polygon) = &wkt {
+            let found = exterior_vertices(polygon).len();
+            if found < 4 {
+                return Err(Error::InsufficientCoordinates {
+                    type_name: type_name::<Self>(),
+                    needed: 4,
+                    found,
+                });
+            }
+        }
+        try_from_geometry(wkt)
+    }
+}
+
 /// Fallibly convert this WKT primitive into this [`geo_types`] primitive
 impl<T: CoordNum + Default> TryFrom<Wkt<T>> for geo_types::GeometryCollection<T> {
     type Error = Error;
@@ -101,7 +215,7 @@ impl<T: CoordNum + Default> TryFrom<Wkt<T>> for geo_types::GeometryCollection<T>
                 expected: type_name::<Self>(),
                 found: type_name::<geo_types::Point<T>>(),
             }),
-            Wkt::LineString(_) => Err(Error::MismatchedGeometry {
+            Wkt::LineString(_) | Wkt::LinearRing(_) => Err(Error::MismatchedGeometry {
                 expected: type_name::<Self>(),
                 found: type_name::<geo_types::LineString<T>>(),
             }),
@@ -127,8 +241,10 @@ impl<T: CoordNum + Default> TryFrom<Wkt<T>> for geo_types::GeometryCollection<T>
 
 impl<T: CoordNum + Default> From<Coord<T>> for geo_types::Coord<T> {
     /// Convert from a WKT Coordinate to a [`geo_types::Coordinate`]
+    ///
+    /// A missing z becomes zero, and any m is dropped.
     fn from(coord: Coord<T>) -> geo_types::Coord<T> {
-        coord! { x: coord.x, y: coord.y, z: coord.z }
+        coord! { x: coord.x, y: coord.y, z: coord.z.unwrap_or_default() }
     }
 }
 
@@ -138,7 +254,7 @@ impl<T: CoordNum + Default> TryFrom<Point<T>> for geo_types::Point<T> {
     /// Fallibly convert from a WKT `POINT` to a [`geo_types::Point`]
     fn try_from(point: Point<T>) -> Result<Self, Self::Error> {
         match point.0 {
-            Some(coord) => Ok(Self::new(coord.x, coord.y, coord.z)),
+            Some(coord) => Ok(Self::new(coord.x, coord.y, coord.z.unwrap_or_default())),
             None => Err(Error::PointConversionError),
         }
     }
@@ -294,7 +410,7 @@ where
                     geo_types::MultiPoint(vec![]).into()
                 }
             }
-            Wkt::LineString(g) => geo_types::Geometry::LineString(g.into()),
+            Wkt::LineString(g) | Wkt::LinearRing(g) => geo_types::Geometry::LineString(g.into()),
             Wkt::Polygon(g) => geo_types::Geometry::Polygon(g.into()),
             Wkt::MultiLineString(g) => geo_types::Geometry::MultiLineString(g.into()),
             Wkt::MultiPoint(g) => geo_types::Geometry::MultiPoint(g.try_into()?),
@@ -304,6 +420,31 @@ where
     }
 }
 
+impl<T> Wkt<T>
+where
+    T: CoordNum + Default + std::fmt::Display,
+{
+    /// Convert to a [`geo_types::Geometry`] and back, to see this geometry as `geo_types` would
+    /// store it.
+    ///
+    /// `geo_types` coordinates always have a z and never an m, so a missing z becomes zero and
+    /// any measures are dropped. An empty point becomes an empty `MULTIPOINT`, and conversion
+    /// fails where [`TryFrom`] would, e.g. for an empty point inside a `MULTIPOINT`.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POINT ZM(1 2 3 4)").unwrap();
+    /// assert_eq!(wkt.via_geo_types().unwrap().to_string(), "POINT Z(1 2 3)");
+    /// ```
+    pub fn via_geo_types(self) -> Result<Wkt<T>, Error> {
+        use crate::ToWkt;
+
+        Ok(geo_types::Geometry::try_from(self)?.to_wkt())
+    }
+}
+
 /// Macro for implementing `TryFromWkt` for all the geo-types.
 /// Alternatively, we could try to have a kind of blanket implementation on `TryFrom<Wkt<T>>`,
 /// but:
@@ -320,11 +461,8 @@ macro_rules! try_from_wkt_impl {
                     Self::try_from(wkt)
                 }
 
-                fn try_from_wkt_reader(mut wkt_reader: impl Read) -> Result<Self, Self::Error> {
-                    let mut bytes = vec![];
-                    wkt_reader.read_to_end(&mut bytes).map_err(|e| Error::External(Box::new(e)))?;
-                    let wkt_str = String::from_utf8(bytes).map_err(|e| Error::External(Box::new(e)))?;
-                    Self::try_from_wkt_str(&wkt_str)
+                fn try_from_wkt_reader(wkt_reader: impl Read) -> Result<Self, Self::Error> {
+                    Self::try_from_wkt_str(&read_wkt_string(wkt_reader, None)?)
                 }
             }
        )*
@@ -351,11 +489,12 @@ mod tests {
 
     #[test]
     fn convert_single_item_wkt() {
-        let wkt = Wkt::from(Point(Some(Coord {
+        let wkt = Wkt::from(Point::new(Coord {
             x: 1.0,
             y: 2.0,
-            z: 3.0,
-        })));
+            z: Some(3.0),
+            m: None,
+        }));
 
         let converted = geo_types::Geometry::try_from(wkt).unwrap();
         let g_point: geo_types::Point<f64> = geo_types::Point::new(1.0, 2.0, 3.0);
@@ -365,18 +504,19 @@ mod tests {
 
     #[test]
     fn convert_empty_point() {
-        let point = Point(None);
+        let point = Point::empty(Dimension::XY);
         let res: Result<geo_types::Point<f64>, Error> = point.try_into();
         assert!(res.is_err());
     }
 
     #[test]
     fn convert_point() {
-        let point = Wkt::from(Point(Some(Coord {
+        let point = Wkt::from(Point::new(Coord {
             x: 10.,
             y: 20.,
-            z: 30.,
-        })));
+            z: Some(30.),
+            m: None,
+        }));
 
         let g_point: geo_types::Point<f64> = (10., 20., 30.).into();
         assert_eq!(
@@ -387,7 +527,7 @@ mod tests {
 
     #[test]
     fn convert_empty_linestring() {
-        let w_linestring = Wkt::from(LineString(vec![]));
+        let w_linestring = Wkt::from(LineString::new(vec![]));
         let g_linestring: geo_types::LineString<f64> = geo_types::LineString(vec![]);
         assert_eq!(
             geo_types::Geometry::LineString(g_linestring),
@@ -395,18 +535,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn convert_linestring_to_line() {
+        let w_linestring: Wkt<f64> = LineString::new(vec![
+            Coord {
+                x: 10.,
+                y: 20.,
+                z: Some(30.),
+                m: None,
+            },
+            Coord {
+                x: 40.,
+                y: 50.,
+                z: Some(60.),
+                m: None,
+            },
+        ])
+        .into();
+        let g_line: geo_types::Line<f64> = w_linestring.try_into().unwrap();
+        assert_eq!(
+            g_line,
+            geo_types::Line::new(
+                coord! { x: 10., y: 20., z: 30. },
+                coord! { x: 40., y: 50., z: 60. }
+            )
+        );
+    }
+
+    #[test]
+    fn convert_empty_linestring_to_line() {
+        let w_linestring: Wkt<f64> = LineString::new(vec![]).into();
+        let res: Result<geo_types::Line<f64>, Error> = w_linestring.try_into();
+        assert!(matches!(
+            res,
+            Err(Error::InsufficientCoordinates {
+                needed: 2,
+                found: 0,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn convert_short_linestring_to_line() {
+        let w_linestring: Wkt<f64> = LineString::new(vec![Coord {
+            x: 10.,
+            y: 20.,
+            z: Some(30.),
+            m: None,
+        }])
+        .into();
+        let res: Result<geo_types::Line<f64>, Error> = w_linestring.try_into();
+        assert!(matches!(
+            res,
+            Err(Error::InsufficientCoordinates {
+                needed: 2,
+                found: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn convert_empty_polygon_to_triangle() {
+        let w_polygon: Wkt<f64> = Polygon::new(vec![]).into();
+        let res: Result<geo_types::Triangle<f64>, Error> = w_polygon.try_into();
+        assert!(matches!(
+            res,
+            Err(Error::InsufficientCoordinates {
+                needed: 3,
+                found: 0,
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn convert_linestring() {
-        let w_linestring: Wkt<f64> = LineString(vec![
+        let w_linestring: Wkt<f64> = LineString::new(vec![
             Coord {
                 x: 10.,
                 y: 20.,
-                z: 30.,
+                z: Some(30.),
+                m: None,
             },
             Coord {
                 x: 40.,
                 y: 50.,
-                z: 60.,
+                z: Some(60.),
+                m: None,
             },
         ])
         .into();
@@ -419,7 +636,7 @@ mod tests {
 
     #[test]
     fn convert_empty_polygon() {
-        let w_polygon: Wkt<f64> = Polygon(vec![]).into();
+        let w_polygon: Wkt<f64> = Polygon::new(vec![]).into();
         let g_polygon: geo_types::Polygon<f64> =
             geo_types::Polygon::new(geo_types::LineString(vec![]), vec![]);
         assert_eq!(
@@ -430,49 +647,57 @@ mod tests {
 
     #[test]
     fn convert_polygon() {
-        let w_polygon: Wkt<f64> = Polygon(vec![
-            LineString(vec![
+        let w_polygon: Wkt<f64> = Polygon::new(vec![
+            LineString::new(vec![
                 Coord {
                     x: 0.,
                     y: 0.,
-                    z: 0.,
+                    z: Some(0.),
+                    m: None,
                 },
                 Coord {
                     x: 20.,
                     y: 40.,
-                    z: 60.,
+                    z: Some(60.),
+                    m: None,
                 },
                 Coord {
                     x: 40.,
                     y: 0.,
-                    z: -40.,
+                    z: Some(-40.),
+                    m: None,
                 },
                 Coord {
                     x: 0.,
                     y: 0.,
-                    z: 0.,
+                    z: Some(0.),
+                    m: None,
                 },
             ]),
-            LineString(vec![
+            LineString::new(vec![
                 Coord {
                     x: 5.,
                     y: 5.,
-                    z: 5.,
+                    z: Some(5.),
+                    m: None,
                 },
                 Coord {
                     x: 20.,
                     y: 30.,
-                    z: 40.,
+                    z: Some(40.),
+                    m: None,
                 },
                 Coord {
                     x: 30.,
                     y: 5.,
-                    z: -30.,
+                    z: Some(-30.),
+                    m: None,
                 },
                 Coord {
                     x: 5.,
                     y: 5.,
-                    z: 5.,
+                    z: Some(5.),
+                    m: None,
                 },
             ]),
         ])
@@ -489,7 +714,7 @@ mod tests {
 
     #[test]
     fn convert_empty_multilinestring() {
-        let w_multilinestring: Wkt<f64> = MultiLineString(vec![]).into();
+        let w_multilinestring: Wkt<f64> = MultiLineString::new(vec![]).into();
         let g_multilinestring: geo_types::MultiLineString<f64> = geo_types::MultiLineString(vec![]);
         assert_eq!(
             geo_types::Geometry::MultiLineString(g_multilinestring),
@@ -499,29 +724,33 @@ mod tests {
 
     #[test]
     fn convert_multilinestring() {
-        let w_multilinestring: Wkt<f64> = MultiLineString(vec![
-            LineString(vec![
+        let w_multilinestring: Wkt<f64> = MultiLineString::new(vec![
+            LineString::new(vec![
                 Coord {
                     x: 10.,
                     y: 20.,
-                    z: 30.
+                    z: Some(30.),
+                    m: None,
                 },
                 Coord {
                     x: 40.,
                     y: 50.,
-                    z: 60.,
+                    z: Some(60.),
+                    m: None,
                 },
             ]),
-            LineString(vec![
+            LineString::new(vec![
                 Coord {
                     x: 70.,
                     y: 80.,
-                    z: 90.,
+                    z: Some(90.),
+                    m: None,
                 },
                 Coord {
                     x: 100.,
                     y: 110.,
-                    z: 120.,
+                    z: Some(120.),
+                    m: None,
                 },
             ]),
         ])
@@ -538,7 +767,7 @@ mod tests {
 
     #[test]
     fn convert_empty_multipoint() {
-        let w_multipoint: Wkt<f64> = MultiPoint(vec![]).into();
+        let w_multipoint: Wkt<f64> = MultiPoint::new(vec![]).into();
         let g_multipoint: geo_types::MultiPoint<f64> = geo_types::MultiPoint(vec![]);
         assert_eq!(
             geo_types::Geometry::MultiPoint(g_multipoint),
@@ -548,17 +777,19 @@ mod tests {
 
     #[test]
     fn convert_multipoint() {
-        let w_multipoint: Wkt<f64> = MultiPoint(vec![
-            Point(Some(Coord {
+        let w_multipoint: Wkt<f64> = MultiPoint::new(vec![
+            Point::new(Coord {
                 x: 10.,
                 y: 20.,
-                z: 25.,
-            })),
-            Point(Some(Coord {
+                z: Some(25.),
+                m: None,
+            }),
+            Point::new(Coord {
                 x: 30.,
                 y: 40.,
-                z: 45.,
-            })),
+                z: Some(45.),
+                m: None,
+            }),
         ])
         .into();
         let g_multipoint: geo_types::MultiPoint<f64> = vec![(10., 20., 25.), (30., 40., 45.)].into();
@@ -570,7 +801,7 @@ mod tests {
 
     #[test]
     fn convert_empty_multipolygon() {
-        let w_multipolygon: Wkt<f64> = MultiPolygon(vec![]).into();
+        let w_multipolygon: Wkt<f64> = MultiPolygon::new(vec![]).into();
         let g_multipolygon: geo_types::MultiPolygon<f64> = geo_types::MultiPolygon(vec![]);
         assert_eq!(
             geo_types::Geometry::MultiPolygon(g_multipolygon),
@@ -580,73 +811,85 @@ mod tests {
 
     #[test]
     fn convert_multipolygon() {
-        let w_multipolygon: Wkt<f64> = MultiPolygon(vec![
-            Polygon(vec![
-                LineString(vec![
+        let w_multipolygon: Wkt<f64> = MultiPolygon::new(vec![
+            Polygon::new(vec![
+                LineString::new(vec![
                     Coord {
                         x: 0.,
                         y: 0.,
-                        z: 0.,
+                        z: Some(0.),
+                        m: None,
                     },
                     Coord {
                         x: 20.,
                         y: 40.,
-                        z: -20.,
+                        z: Some(-20.),
+                        m: None,
                     },
                     Coord {
                         x: 40.,
                         y: 0.,
-                        z: -40.,
+                        z: Some(-40.),
+                        m: None,
                     },
                     Coord {
                         x: 0.,
                         y: 0.,
-                        z: 0.,
+                        z: Some(0.),
+                        m: None,
                     },
                 ]),
-                LineString(vec![
+                LineString::new(vec![
                     Coord {
                         x: 5.,
                         y: 5.,
-                        z: 5.,
+                        z: Some(5.),
+                        m: None,
                     },
                     Coord {
                         x: 20.,
                         y: 30.,
-                        z: -20.,
+                        z: Some(-20.),
+                        m: None,
                     },
                     Coord {
                         x: 30.,
                         y: 5.,
-                        z: -30.,
+                        z: Some(-30.),
+                        m: None,
                     },
                     Coord {
                         x: 5.,
                         y: 5.,
-                        z: 5.,
+                        z: Some(5.),
+                        m: None,
                     },
                 ]),
             ]),
-            Polygon(vec![LineString(vec![
+            Polygon::new(vec![LineString::new(vec![
                 Coord {
                     x: 40.,
                     y: 40.,
-                    z: 40.,
+                    z: Some(40.),
+                    m: None,
                 },
                 Coord {
                     x: 20.,
                     y: 45.,
-                    z: -20.,
+                    z: Some(-20.),
+                    m: None,
                 },
                 Coord {
                     x: 45.,
                     y: 30.,
-                    z: -45.,
+                    z: Some(-45.),
+                    m: None,
                 },
                 Coord {
                     x: 40.,
                     y: 40.,
-                    z: 40.,
+                    z: Some(40.),
+                    m: None,
                 },
             ])]),
         ])
@@ -670,7 +913,7 @@ mod tests {
 
     #[test]
     fn convert_empty_geometrycollection() {
-        let w_geometrycollection: Wkt<f64> = GeometryCollection(vec![]).into();
+        let w_geometrycollection: Wkt<f64> = GeometryCollection::new(vec![]).into();
         let g_geometrycollection: geo_types::GeometryCollection<f64> =
             geo_types::GeometryCollection(vec![]);
         assert_eq!(
@@ -681,142 +924,163 @@ mod tests {
 
     #[test]
     fn convert_geometrycollection() {
-        let w_point = Point(Some(Coord {
+        let w_point = Point::new(Coord {
             x: 10.,
             y: 20.,
-            z: 30.,
-        }))
+            z: Some(30.),
+            m: None,
+        })
         .into();
 
-        let w_linestring = LineString(vec![
+        let w_linestring = LineString::new(vec![
             Coord {
                 x: 10.,
                 y: 20.,
-                z: 30.,
+                z: Some(30.),
+                m: None,
             },
             Coord {
                 x: 40.,
                 y: 50.,
-                z: 60.,
+                z: Some(60.),
+                m: None,
             },
         ])
         .into();
 
-        let w_polygon = Polygon(vec![LineString(vec![
+        let w_polygon = Polygon::new(vec![LineString::new(vec![
             Coord {
                 x: 0.,
                 y: 0.,
-                z: 0.,
+                z: Some(0.),
+                m: None,
             },
             Coord {
                 x: 20.,
                 y: 40.,
-                z: 60.
+                z: Some(60.),
+                m: None,
             },
             Coord {
                 x: 40.,
                 y: 0.,
-                z: -40.,
+                z: Some(-40.),
+                m: None,
             },
             Coord {
                 x: 0.,
                 y: 0.,
-                z: 0.,
+                z: Some(0.),
+                m: None,
             },
         ])])
         .into();
 
-        let w_multilinestring = MultiLineString(vec![
-            LineString(vec![
+        let w_multilinestring = MultiLineString::new(vec![
+            LineString::new(vec![
                 Coord {
                     x: 10.,
                     y: 20.,
-                    z: 30.,
+                    z: Some(30.),
+                    m: None,
                 },
                 Coord {
                     x: 40.,
                     y: 50.,
-                    z: 60.,
+                    z: Some(60.),
+                    m: None,
                 },
             ]),
-            LineString(vec![
+            LineString::new(vec![
                 Coord {
                     x: 70.,
                     y: 80.,
-                    z: 90.,
+                    z: Some(90.),
+                    m: None,
                 },
                 Coord {
                     x: 100.,
                     y: 110.,
-                    z: 120.,
+                    z: Some(120.),
+                    m: None,
                 },
             ]),
         ])
         .into();
 
-        let w_multipoint = MultiPoint(vec![
-            Point(Some(Coord {
+        let w_multipoint = MultiPoint::new(vec![
+            Point::new(Coord {
                 x: 10.,
                 y: 20.,
-                z: 30.,
-            })),
-            Point(Some(Coord {
+                z: Some(30.),
+                m: None,
+            }),
+            Point::new(Coord {
                 x: 40.,
                 y: 50.,
-                z: 60.,
-            })),
+                z: Some(60.),
+                m: None,
+            }),
         ])
         .into();
 
-        let w_multipolygon = MultiPolygon(vec![
-            Polygon(vec![LineString(vec![
+        let w_multipolygon = MultiPolygon::new(vec![
+            Polygon::new(vec![LineString::new(vec![
                 Coord {
                     x: 0.,
                     y: 0.,
-                    z: 0.,
+                    z: Some(0.),
+                    m: None,
                 },
                 Coord {
                     x: 20.,
                     y: 40.,
-                    z: 60.,
+                    z: Some(60.),
+                    m: None,
                 },
                 Coord {
                     x: 40.,
                     y: 0.,
-                    z: -40.,
+                    z: Some(-40.),
+                    m: None,
                 },
                 Coord {
                     x: 0.,
                     y: 0.,
-                    z: 0.,
+                    z: Some(0.),
+                    m: None,
                 },
             ])]),
-            Polygon(vec![LineString(vec![
+            Polygon::new(vec![LineString::new(vec![
                 Coord {
                     x: 40.,
                     y: 40.,
-                    z: 40.,
+                    z: Some(40.),
+                    m: None,
                 },
                 Coord {
                     x: 20.,
                     y: 45.,
-                    z: -20.,
+                    z: Some(-20.),
+                    m: None,
                 },
                 Coord {
                     x: 45.,
                     y: 30.,
-                    z: -45.,
+                    z: Some(-45.),
+                    m: None,
                 },
                 Coord {
                     x: 40.,
                     y: 40.,
-                    z: 40.,
+                    z: Some(40.),
+                    m: None,
                 },
             ])]),
         ])
         .into();
 
-        let w_geometrycollection: Wkt<f64> = GeometryCollection(vec![
+        let w_geometrycollection: Wkt<f64> = GeometryCollection::new(vec![
             w_point,
             w_multipoint,
             w_linestring,
@@ -880,7 +1144,9 @@ mod tests {
         let err = geo_types::GeometryCollection::<f64>::try_from_wkt_str("GeomColl(POINT Z(1 2 3))")
             .unwrap_err();
         match err {
-            Error::InvalidWKT(err_text) => assert_eq!(err_text, "Invalid type encountered"),
+            Error::InvalidWKT(ParseWktError::UnknownGeometryType { name, .. }) => {
+                assert_eq!(name, "GeomColl")
+            }
             e => panic!("Not the error we expected. Found: {}", e),
         }
     }
@@ -904,11 +1170,26 @@ mod tests {
         let a_point_too_many = geo_types::Point::<f64>::try_from_wkt_str("PINT Z(1 2 3)");
         let err = a_point_too_many.unwrap_err();
         match err {
-            Error::InvalidWKT(err_text) => assert_eq!(err_text, "Invalid type encountered"),
+            Error::InvalidWKT(ParseWktError::UnknownGeometryType { name, .. }) => {
+                assert_eq!(name, "PINT")
+            }
             e => panic!("Not the error we expected. Found: {}", e),
         }
     }
 
+    #[test]
+    fn from_reader_over_limit() {
+        // An endless reader, which would exhaust memory if read to the end
+        let endless = std::io::repeat(b'1');
+        let err = geo_types::Point::<f64>::try_from_wkt_reader_limited(endless, 1024).unwrap_err();
+        assert!(matches!(err, Error::InputTooLarge(1024)));
+
+        let exact = "POINT Z(1 2 3)".as_bytes();
+        let point =
+            geo_types::Point::<f64>::try_from_wkt_reader_limited(exact, exact.len()).unwrap();
+        assert_eq!(point, geo_types::Point::new(1., 2., 3.));
+    }
+
     #[test]
     fn from_other_geom_wkt_str() {
         let not_actually_a_line_string =
@@ -933,4 +1214,200 @@ mod tests {
         let wkt_string = point.wkt_string();
         assert_eq!("POINT Z(1 2 3)", &wkt_string);
     }
+
+    #[test]
+    fn error_sources() {
+        use std::error::Error as _;
+
+        // So that it converts into `anyhow::Error` and the like
+        fn assert_send_sync<E: Send + Sync + 'static>() {}
+        assert_send_sync::<Error>();
+
+        let err = geo_types::Point::<f64>::try_from_wkt_reader(&b"POINT(1 \xff)"[..]).unwrap_err();
+        let source = err.source().expect("invalid UTF-8 should be the source");
+        let utf8_error = source.downcast_ref::<std::string::FromUtf8Error>().unwrap();
+        assert_eq!(utf8_error.utf8_error().valid_up_to(), 8);
+
+        let err = geo_types::Point::<f64>::try_from_wkt_str("POINT(1").unwrap_err();
+        let source = err.source().expect("the parse error should be the source");
+        assert!(matches!(
+            source.downcast_ref::<ParseWktError>(),
+            Some(ParseWktError::UnexpectedEndOfInput { .. })
+        ));
+    }
+
+    #[test]
+    fn f32_overflow() {
+        let err = geo_types::Point::<f32>::try_from_wkt_str("POINT(1e40 0)").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidWKT(ParseWktError::NumberOutOfRange { offset: 6, .. })
+        ));
+        assert_eq!(err.to_string(), "Invalid WKT");
+        assert!(geo_types::Point::<f64>::try_from_wkt_str("POINT(1e40 0)").is_ok());
+    }
+
+    #[test]
+    fn integer_coord_types() {
+        use crate::to_wkt::ToWkt;
+        let point: geo_types::Point<i64> =
+            geo_types::Point::try_from_wkt_str("POINT(1 2)").unwrap();
+        assert_eq!(point, geo_types::Point::new(1, 2, 0));
+        assert_eq!(point.wkt_string(), "POINT Z(1 2 0)");
+
+        let line_string: geo_types::LineString<i32> =
+            geo_types::LineString::try_from_wkt_str("LINESTRING Z(-1 2 3,+4 5 6)").unwrap();
+        assert_eq!(line_string.0[1], coord! { x: 4, y: 5, z: 6 });
+
+        for fractional in [
+            "POINT(1.5 2)",
+            "POINT(1.0 2)",
+            "POINT(1e2 2)",
+            "POINT(1 .5)",
+        ] {
+            assert!(
+                matches!(
+                    geo_types::Point::<i64>::try_from_wkt_str(fractional),
+                    Err(Error::InvalidWKT(ParseWktError::InvalidNumber { .. }))
+                ),
+                "{fractional}"
+            );
+        }
+    }
+
+    #[test]
+    fn point_via_geo_types() {
+        let wkt: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+        assert_eq!(wkt.clone().via_geo_types().unwrap(), wkt);
+
+        let wkt: Wkt<f64> = Wkt::from_str("POINT(1 2)").unwrap();
+        assert_eq!(wkt.via_geo_types().unwrap().to_string(), "POINT Z(1 2 0)");
+    }
+
+    #[test]
+    fn linear_ring_to_line_string() {
+        use crate::to_wkt::ToWkt;
+
+        let limits = crate::ParseLimits {
+            keep_linear_rings: true,
+            ..Default::default()
+        };
+        let wkt: Wkt<f64> =
+            Wkt::from_str_with_limits("LINEARRING Z(0 0 1,1 0 1,1 1 1,0 0 1)", &limits).unwrap();
+        assert!(matches!(wkt, Wkt::LinearRing(_)));
+        let line_string = geo_types::LineString::try_from(wkt).unwrap();
+        assert!(line_string.is_closed());
+        assert_eq!(line_string.0.len(), 4);
+        assert_eq!(
+            line_string.wkt_string(),
+            "LINESTRING Z(0 0 1,1 0 1,1 1 1,0 0 1)"
+        );
+    }
+
+    /// Z must survive conversion to `geo_types` for every kind of geometry.
+    mod preserves_z {
+        use super::*;
+
+        fn c(x: f64, y: f64, z: f64) -> geo_types::Coord<f64> {
+            coord! { x: x, y: y, z: z }
+        }
+
+        fn convert(wkt: &str) -> geo_types::Geometry<f64> {
+            geo_types::Geometry::try_from(Wkt::from_str(wkt).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn point() {
+            assert_eq!(
+                convert("POINT Z(1 2 3)"),
+                geo_types::Geometry::Point(geo_types::Point(c(1., 2., 3.)))
+            );
+        }
+
+        #[test]
+        fn line_string() {
+            assert_eq!(
+                convert("LINESTRING Z(1 2 3,4 5 6)"),
+                geo_types::Geometry::LineString(geo_types::LineString(vec![
+                    c(1., 2., 3.),
+                    c(4., 5., 6.)
+                ]))
+            );
+        }
+
+        #[test]
+        fn polygon() {
+            assert_eq!(
+                convert("POLYGON Z((0 0 1,4 0 2,4 4 3,0 0 1),(1 1 5,2 1 6,2 2 7,1 1 5))"),
+                geo_types::Geometry::Polygon(geo_types::Polygon::new(
+                    geo_types::LineString(vec![
+                        c(0., 0., 1.),
+                        c(4., 0., 2.),
+                        c(4., 4., 3.),
+                        c(0., 0., 1.)
+                    ]),
+                    vec![geo_types::LineString(vec![
+                        c(1., 1., 5.),
+                        c(2., 1., 6.),
+                        c(2., 2., 7.),
+                        c(1., 1., 5.)
+                    ])],
+                ))
+            );
+        }
+
+        #[test]
+        fn multi_point() {
+            assert_eq!(
+                convert("MULTIPOINT Z((1 2 3),(4 5 6))"),
+                geo_types::Geometry::MultiPoint(geo_types::MultiPoint(vec![
+                    geo_types::Point(c(1., 2., 3.)),
+                    geo_types::Point(c(4., 5., 6.))
+                ]))
+            );
+        }
+
+        #[test]
+        fn multi_line_string() {
+            assert_eq!(
+                convert("MULTILINESTRING Z((1 2 3,4 5 6),(7 8 9,10 11 12))"),
+                geo_types::Geometry::MultiLineString(geo_types::MultiLineString(vec![
+                    geo_types::LineString(vec![c(1., 2., 3.), c(4., 5., 6.)]),
+                    geo_types::LineString(vec![c(7., 8., 9.), c(10., 11., 12.)])
+                ]))
+            );
+        }
+
+        #[test]
+        fn multi_polygon() {
+            assert_eq!(
+                convert("MULTIPOLYGON Z(((0 0 1,1 0 2,1 1 3,0 0 1)))"),
+                geo_types::Geometry::MultiPolygon(geo_types::MultiPolygon(vec![
+                    geo_types::Polygon::new(
+                        geo_types::LineString(vec![
+                            c(0., 0., 1.),
+                            c(1., 0., 2.),
+                            c(1., 1., 3.),
+                            c(0., 0., 1.)
+                        ]),
+                        vec![],
+                    )
+                ]))
+            );
+        }
+
+        #[test]
+        fn geometry_collection() {
+            assert_eq!(
+                convert("GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING Z(4 5 6,7 8 9))"),
+                geo_types::Geometry::GeometryCollection(geo_types::GeometryCollection(vec![
+                    geo_types::Geometry::Point(geo_types::Point(c(1., 2., 3.))),
+                    geo_types::Geometry::LineString(geo_types::LineString(vec![
+                        c(4., 5., 6.),
+                        c(7., 8., 9.)
+                    ])),
+                ]))
+            );
+        }
+    }
 }
@@ -1,5 +1,12 @@
+//! Conversions from [`geo_types`] primitives to WKT.
+//!
+//! These are written against the 3D `geo-3d-types` fork and read `z` from every coordinate. The
+//! `geo-types-2d` feature adds conversions from mainline 2D `geo-types` as well, which write 2D
+//! WKT.
+
 use geo_types::CoordNum;
 
+use crate::to_wkt::rect_ring;
 use crate::types::{
     Coord, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
     Polygon,
@@ -187,14 +194,23 @@ where
     }
 }
 
+/// A `Rect` is converted to the same polygon as [`write_rect`](crate::to_wkt::write_rect) writes:
+/// the ring of its corners counter-clockwise from the minimum, at its z if it has no depth, and
+/// otherwise the ring of its bottom face, then its top face, then back to the first corner.
+///
 /// # Examples
 /// ```
 /// use geo_types::{coord, Rect};
 /// use wkt::ToWkt;
 ///
-/// let rect: Rect<f64> = Rect::new(coord!(x: 4., y: 4., z: 4.), coord!(x: 8., y: 8., z: 8.));
+/// let flat: Rect<f64> = Rect::new(coord!(x: 4., y: 4., z: 2.), coord!(x: 8., y: 8., z: 2.));
+/// assert_eq!(flat.wkt_string(), "POLYGON Z((4 4 2,8 4 2,8 8 2,4 8 2,4 4 2))");
 ///
-/// assert_eq!(rect.wkt_string(), "POLYGON Z((4 4 8,4 8 8,8 8 8,8 4 8,8 4 4,4 4 4,4 8 4,8 8 4,4 4 8))");
+/// let rect: Rect<f64> = Rect::new(coord!(x: 4., y: 4., z: 4.), coord!(x: 8., y: 8., z: 8.));
+/// assert_eq!(
+///     rect.wkt_string(),
+///     "POLYGON Z((4 4 4,8 4 4,8 8 4,4 8 4,4 4 4,4 4 8,8 4 8,8 8 8,4 8 8,4 4 8,4 4 4))"
+/// );
 /// ```
 impl<T> ToWkt<T> for geo_types::Rect<T>
 where
@@ -230,7 +246,8 @@ where
     Coord {
         x: g_point.x,
         y: g_point.y,
-        z: g_point.z,
+        z: Some(g_point.z),
+        m: None,
     }
 }
 
@@ -239,7 +256,7 @@ where
     T: CoordNum + Default,
 {
     let coord = g_point_to_w_coord(&g_point.0);
-    Point(Some(coord))
+    Point::new(coord)
 }
 
 fn g_points_to_w_coords<T>(g_points: &[geo_types::Coord<T>]) -> Vec<Coord<T>>
@@ -257,7 +274,7 @@ where
         .iter()
         .map(|p| &p.0)
         .map(g_point_to_w_coord)
-        .map(|c| Point(Some(c)))
+        .map(Point::new)
         .collect()
 }
 
@@ -281,7 +298,7 @@ where
     T: CoordNum + Default,
 {
     let w_coords = g_points_to_w_coords(g_coords);
-    LineString(w_coords)
+    LineString::new(w_coords)
 }
 
 fn g_lines_to_w_lines<T>(g_lines: &[geo_types::LineString<T>]) -> Vec<LineString<T>>
@@ -308,8 +325,9 @@ fn g_rect_to_w_polygon<T>(g_rect: &geo_types::Rect<T>) -> Polygon<T>
 where
     T: CoordNum + Default,
 {
-    let polygon = g_rect.to_polygon();
-    g_polygon_to_w_polygon(&polygon)
+    let ring =
+        rect_ring(g_rect, geo_traits::Dimensions::Xyz).expect("geo-types rects always have a z");
+    Polygon::new(vec![LineString::new(ring)])
 }
 
 fn g_polygon_to_w_polygon<T>(g_polygon: &geo_types::Polygon<T>) -> Polygon<T>
@@ -330,7 +348,7 @@ where
     let inner = g_lines_to_w_lines(inner_lines);
     poly_lines.extend(inner);
 
-    Polygon(poly_lines)
+    Polygon::new(poly_lines)
 }
 
 fn g_mpoint_to_w_mpoint<T>(g_mpoint: &geo_types::MultiPoint<T>) -> MultiPoint<T>
@@ -339,7 +357,7 @@ where
 {
     let geo_types::MultiPoint(g_points) = g_mpoint;
     let w_points = g_points_to_w_points(g_points);
-    MultiPoint(w_points)
+    MultiPoint::new(w_points)
 }
 
 fn g_mline_to_w_mline<T>(g_mline: &geo_types::MultiLineString<T>) -> MultiLineString<T>
@@ -348,7 +366,7 @@ where
 {
     let geo_types::MultiLineString(g_lines) = g_mline;
     let w_lines = g_lines_to_w_lines(g_lines);
-    MultiLineString(w_lines)
+    MultiLineString::new(w_lines)
 }
 
 fn g_polygons_to_w_polygons<T>(g_polygons: &[geo_types::Polygon<T>]) -> Vec<Polygon<T>>
@@ -368,7 +386,7 @@ where
 {
     let geo_types::MultiPolygon(g_polygons) = g_mpolygon;
     let w_polygons = g_polygons_to_w_polygons(g_polygons);
-    MultiPolygon(w_polygons)
+    MultiPolygon::new(w_polygons)
 }
 
 fn g_geocol_to_w_geocol<T>(g_geocol: &geo_types::GeometryCollection<T>) -> GeometryCollection<T>
@@ -381,7 +399,7 @@ where
         let w_geom = g_geom_to_w_geom(g_geom);
         w_geoms.push(w_geom);
     }
-    GeometryCollection(w_geoms)
+    GeometryCollection::new(w_geoms)
 }
 
 fn g_geom_to_w_geom<T: CoordNum + Default>(g_geom: &geo_types::Geometry<T>) -> Wkt<T> {
@@ -424,4 +442,91 @@ mod tests {
         let point = geo_types::Point::new(1.1, 2.9, 3.8);
         assert_eq!("POINT Z(1.1 2.9 3.8)", &point.wkt_string());
     }
+
+    #[test]
+    fn rect_round_trip() {
+        use std::str::FromStr;
+
+        use crate::to_wkt::write_rect;
+        use crate::Wkt;
+
+        let cases = [
+            (
+                ((-1.5, 0., 2.), (3., 0.25, 2.)),
+                "POLYGON Z((-1.5 0 2,3 0 2,3 0.25 2,-1.5 0.25 2,-1.5 0 2))",
+            ),
+            (
+                ((0., 0., 0.), (0., 0., 0.)),
+                "POLYGON Z((0 0 0,0 0 0,0 0 0,0 0 0,0 0 0))",
+            ),
+            (
+                ((-10., -20., -3.), (-1., -2., -3.)),
+                "POLYGON Z((-10 -20 -3,-1 -20 -3,-1 -2 -3,-10 -2 -3,-10 -20 -3))",
+            ),
+        ];
+        for (((x0, y0, z0), (x1, y1, z1)), expected) in cases {
+            let rect = geo_types::Rect::new(
+                geo_types::coord!(x: x0, y: y0, z: z0),
+                geo_types::coord!(x: x1, y: y1, z: z1),
+            );
+            let mut written = String::new();
+            write_rect(&mut written, &rect).unwrap();
+            assert_eq!(written, expected);
+
+            let Wkt::Polygon(polygon) = Wkt::<f64>::from_str(&written).unwrap() else {
+                unreachable!()
+            };
+            let ring = &polygon.0[0].0;
+            assert_eq!(ring.len(), 5);
+            assert_eq!(ring.first(), ring.last());
+        }
+
+        // `wkt_string` writes the same ring as `write_rect`
+        for ((x0, y0, z0), (x1, y1, z1)) in [
+            ((-1.5, 0., 2.), (3., 0.25, 2.)),
+            ((4., 4., 4.), (8., 8., 8.)),
+            ((-10., -20., -3.), (-1., -2., 5.)),
+        ] {
+            let rect = geo_types::Rect::new(
+                geo_types::coord!(x: x0, y: y0, z: z0),
+                geo_types::coord!(x: x1, y: y1, z: z1),
+            );
+            let mut written = String::new();
+            write_rect(&mut written, &rect).unwrap();
+            assert_eq!(rect.wkt_string(), written);
+
+            let parsed: geo_types::Polygon<f64> = Wkt::from_str(&rect.wkt_string())
+                .unwrap()
+                .try_into()
+                .unwrap();
+            let face = |z| {
+                vec![
+                    (x0, y0, z),
+                    (x1, y0, z),
+                    (x1, y1, z),
+                    (x0, y1, z),
+                    (x0, y0, z),
+                ]
+            };
+            let mut ring = face(z0);
+            if z1 != z0 {
+                ring.extend(face(z1));
+                ring.push((x0, y0, z0));
+            }
+            assert_eq!(parsed, geo_types::Polygon::new(ring.into(), vec![]));
+        }
+
+        let deep = geo_types::Rect::new(
+            geo_types::coord!(x: 4., y: 4., z: 4.),
+            geo_types::coord!(x: 8., y: 8., z: 8.),
+        );
+        let mut written = String::new();
+        write_rect(&mut written, &deep).unwrap();
+        let Wkt::Polygon(polygon) = Wkt::<f64>::from_str(&written).unwrap() else {
+            unreachable!()
+        };
+        let ring = &polygon.0[0].0;
+        assert_eq!(ring.len(), 11);
+        assert_eq!(ring.first(), ring.last());
+    }
 }
@@ -1,4 +1,7 @@
+use crate::error::ParseWktError;
+use crate::tokenizer::{PeekableTokens, Token, Tokens};
 use crate::types::{Dimension, GeometryType};
+use crate::{check_after_dimension, dimension_of_word, infer_geom_dimension};
 
 const POINT: &str = "POINT";
 const LINESTRING: &str = "LINESTRING";
@@ -82,11 +85,82 @@ pub fn infer_type(input: &str) -> Result<(GeometryType, Option<Dimension>), Stri
         } else if input.starts_with(GEOMETRYCOLLECTION) {
             Ok((GeometryType::GeometryCollection, None))
         } else {
-            return Err(format!("Unsupported WKT prefix {input}"));
+            Err(format!("Unsupported WKT prefix {input}"))
         }
     }
 }
 
+/// Read the geometry type and dimension from the start of a WKT string, without parsing the
+/// rest of it.
+///
+/// Only the type keyword, any dimension tag and, if there is no tag, the first coordinate are
+/// read, so this is cheap even for very large geometries. Use it to decide how to handle a
+/// geometry before paying for [`Wkt::from_str`](std::str::FromStr::from_str). Unlike
+/// [`infer_type`], the dimension of an untagged geometry comes from the number of ordinates in
+/// its first coordinate, as it does when parsing in full. Untagged `EMPTY` geometries and
+/// `GEOMETRYCOLLECTION`s are XY.
+///
+/// ```
+/// use wkt::infer_type_and_dim;
+/// use wkt::types::{Dimension, GeometryType};
+///
+/// assert_eq!(
+///     infer_type_and_dim("MULTIPOLYGON ZM(((0 0 0 0,1 0 0 0,1 1 0 0,0 0 0 0)))").unwrap(),
+///     (GeometryType::MultiPolygon, Dimension::XYZM)
+/// );
+/// assert_eq!(
+///     infer_type_and_dim("LINESTRING(1 2 3,4 5 6)").unwrap(),
+///     (GeometryType::LineString, Dimension::XYZ)
+/// );
+/// assert!(infer_type_and_dim("CIRCLE(1 2)").is_err());
+/// ```
+pub fn infer_type_and_dim(wkt_str: &str) -> Result<(GeometryType, Dimension), ParseWktError> {
+    let mut tokens = PeekableTokens::<f64>::new(Tokens::from_str(wkt_str));
+    let word = match tokens.next().transpose()? {
+        Some(Token::Word(word)) => word,
+        Some(_) => return Err(tokens.unexpected_token_error("Invalid WKT format")),
+        None => return Err(tokens.unexpected_end_error("Invalid WKT format")),
+    };
+    let Some((geom_type, dim)) = type_of_word(&word) else {
+        return Err(ParseWktError::UnknownGeometryType {
+            name: word,
+            offset: tokens.token_start(),
+        });
+    };
+
+    let dim = match dim {
+        Some(dim) => {
+            check_after_dimension(&mut tokens)?;
+            dim
+        }
+        None => infer_geom_dimension(&mut tokens)?,
+    };
+    Ok((geom_type, dim))
+}
+
+/// Split a type keyword such as `POINT` or `POINTZ` into its type and any dimension joined to it.
+fn type_of_word(word: &str) -> Option<(GeometryType, Option<Dimension>)> {
+    let upper = word.to_ascii_uppercase();
+    let (geom_type, dim_str) = [
+        (GeometryType::Point, POINT),
+        (GeometryType::LineString, LINESTRING),
+        (GeometryType::LineString, "LINEARRING"),
+        (GeometryType::Polygon, POLYGON),
+        (GeometryType::MultiPoint, MULTIPOINT),
+        (GeometryType::MultiLineString, MULTILINESTRING),
+        (GeometryType::MultiPolygon, MULTIPOLYGON),
+        (GeometryType::GeometryCollection, GEOMETRYCOLLECTION),
+    ]
+    .into_iter()
+    .find_map(|(geom_type, keyword)| Some((geom_type, upper.strip_prefix(keyword)?)))?;
+
+    if dim_str.is_empty() {
+        Some((geom_type, None))
+    } else {
+        Some((geom_type, Some(dimension_of_word(dim_str)?)))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -143,4 +217,56 @@ mod test {
             (GeometryType::MultiPolygon, None)
         );
     }
+
+    #[test]
+    fn type_and_dim() {
+        assert_eq!(
+            infer_type_and_dim("POINT(1 2)").unwrap(),
+            (GeometryType::Point, Dimension::XY)
+        );
+        assert_eq!(
+            infer_type_and_dim("  multipoint m((1 2 3))").unwrap(),
+            (GeometryType::MultiPoint, Dimension::XYM)
+        );
+        assert_eq!(
+            infer_type_and_dim("POLYGONZ((0 0 0,1 0 0,1 1 0,0 0 0))").unwrap(),
+            (GeometryType::Polygon, Dimension::XYZ)
+        );
+        assert_eq!(
+            infer_type_and_dim("LINESTRING(1 2 3 4,5 6 7 8)").unwrap(),
+            (GeometryType::LineString, Dimension::XYZM)
+        );
+        assert_eq!(
+            infer_type_and_dim("POINT Z EMPTY").unwrap(),
+            (GeometryType::Point, Dimension::XYZ)
+        );
+        assert_eq!(
+            infer_type_and_dim("GEOMETRYCOLLECTION(POINT Z(1 2 3))").unwrap(),
+            (GeometryType::GeometryCollection, Dimension::XY)
+        );
+    }
+
+    #[test]
+    fn type_and_dim_only_reads_the_start() {
+        // The body is malformed, but isn't read
+        assert_eq!(
+            infer_type_and_dim("MULTIPOLYGON ZM(((0 0 0 0,1 0 0 0,oops").unwrap(),
+            (GeometryType::MultiPolygon, Dimension::XYZM)
+        );
+    }
+
+    #[test]
+    fn type_and_dim_errors() {
+        assert!(matches!(
+            infer_type_and_dim("CIRCLE(1 2)"),
+            Err(ParseWktError::UnknownGeometryType { offset: 0, .. })
+        ));
+        assert!(matches!(
+            infer_type_and_dim("POINTQ(1 2)"),
+            Err(ParseWktError::UnknownGeometryType { .. })
+        ));
+        assert!(infer_type_and_dim("POINT Z M(1 2 3)").is_err());
+        assert!(infer_type_and_dim("(1 2)").is_err());
+        assert!(infer_type_and_dim("").is_err());
+    }
 }
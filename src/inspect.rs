@@ -0,0 +1,837 @@
+//! Read-only queries over the structure and coordinates of a [`Wkt`] geometry.
+
+use std::collections::HashMap;
+
+use geo_traits::{Dimensions, GeometryTrait};
+use num_traits::Float;
+
+use crate::types::{
+    Coord, Dimension, GeometryCollection, GeometryType, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+use crate::{Wkt, WktNum};
+
+/// The smallest box enclosing both `a` and `b`, each given as its minimum and maximum corners.
+///
+/// The result only has a z (or m) if both boxes do.
+fn union_bounds<T: WktNum + Float>(
+    (min, max): (Coord<T>, Coord<T>),
+    (other_min, other_max): (&Coord<T>, &Coord<T>),
+) -> (Coord<T>, Coord<T>) {
+    (
+        Coord {
+            x: min.x.min(other_min.x),
+            y: min.y.min(other_min.y),
+            z: min.z.zip(other_min.z).map(|(a, b)| a.min(b)),
+            m: min.m.zip(other_min.m).map(|(a, b)| a.min(b)),
+        },
+        Coord {
+            x: max.x.max(other_max.x),
+            y: max.y.max(other_max.y),
+            z: max.z.zip(other_max.z).map(|(a, b)| a.max(b)),
+            m: max.m.zip(other_max.m).map(|(a, b)| a.max(b)),
+        },
+    )
+}
+
+/// The minimum and maximum corners of the box enclosing every geometry in `geoms`, or `None` if
+/// they are all empty. This is the extent of a layer, as used to size a spatial index.
+///
+/// Empty geometries are skipped. As with [`Wkt::bounding_rect`], the corners only have a z (or
+/// m) if every coordinate has one.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wkt::types::Coord;
+/// use wkt::{total_bounds, Wkt};
+///
+/// let geoms: Vec<Wkt<f64>> = vec![
+///     Wkt::from_str("POINT Z(1 2 3)").unwrap(),
+///     Wkt::from_str("POINT Z EMPTY").unwrap(),
+///     Wkt::from_str("LINESTRING Z(-1 5 0,2 4 1)").unwrap(),
+/// ];
+/// assert_eq!(
+///     total_bounds(&geoms),
+///     Some((
+///         Coord { x: -1., y: 2., z: Some(0.), m: None },
+///         Coord { x: 2., y: 5., z: Some(3.), m: None },
+///     ))
+/// );
+/// ```
+pub fn total_bounds<T: WktNum + Float>(geoms: &[Wkt<T>]) -> Option<(Coord<T>, Coord<T>)> {
+    geoms
+        .iter()
+        .filter_map(Wkt::bounding_rect)
+        .reduce(|bounds, (min, max)| union_bounds(bounds, (&min, &max)))
+}
+
+/// Whether `iter` yields more than `limit` items, consuming at most `limit + 1` of them.
+fn more_than(mut iter: impl Iterator, limit: usize) -> bool {
+    iter.nth(limit).is_some()
+}
+
+impl<T: WktNum> Wkt<T> {
+    /// The type of this geometry.
+    pub fn geometry_type(&self) -> GeometryType {
+        match self {
+            Wkt::Point(_) => GeometryType::Point,
+            Wkt::LineString(_) | Wkt::LinearRing(_) => GeometryType::LineString,
+            Wkt::Polygon(_) => GeometryType::Polygon,
+            Wkt::MultiPoint(_) => GeometryType::MultiPoint,
+            Wkt::MultiLineString(_) => GeometryType::MultiLineString,
+            Wkt::MultiPolygon(_) => GeometryType::MultiPolygon,
+            Wkt::GeometryCollection(_) => GeometryType::GeometryCollection,
+        }
+    }
+
+    /// The OGC name of the type of this geometry, e.g. `"LineString"`, for logging or branching
+    /// on without a `match`.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("MULTIPOLYGON Z EMPTY").unwrap();
+    /// assert_eq!(wkt.geometry_type_name(), "MultiPolygon");
+    /// ```
+    pub fn geometry_type_name(&self) -> &'static str {
+        match self.geometry_type() {
+            GeometryType::Point => "Point",
+            GeometryType::LineString => "LineString",
+            GeometryType::Polygon => "Polygon",
+            GeometryType::MultiPoint => "MultiPoint",
+            GeometryType::MultiLineString => "MultiLineString",
+            GeometryType::MultiPolygon => "MultiPolygon",
+            GeometryType::GeometryCollection => "GeometryCollection",
+        }
+    }
+
+    /// Returns `true` if this geometry has no coordinates and no members, as when it was written
+    /// with `EMPTY`.
+    ///
+    /// A collection whose members are all empty is not itself empty.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// assert!(Wkt::<f64>::from_str("POINT Z EMPTY").unwrap().is_empty());
+    /// assert!(!Wkt::<f64>::from_str("GEOMETRYCOLLECTION(POINT EMPTY)").unwrap().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Wkt::Point(point) => point.0.is_none(),
+            Wkt::LineString(line_string) | Wkt::LinearRing(line_string) => line_string.0.is_empty(),
+            Wkt::Polygon(polygon) => polygon.0.is_empty(),
+            Wkt::MultiPoint(multi_point) => multi_point.0.is_empty(),
+            Wkt::MultiLineString(multi_line_string) => multi_line_string.0.is_empty(),
+            Wkt::MultiPolygon(multi_polygon) => multi_polygon.0.is_empty(),
+            Wkt::GeometryCollection(collection) => collection.0.is_empty(),
+        }
+    }
+
+    /// The coordinate dimensions of this geometry.
+    ///
+    /// This is the same as [`GeometryTrait::dim`], but doesn't need the trait to be in scope.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use geo_traits::Dimensions;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+    /// assert_eq!(wkt.dimensions(), Dimensions::Xyz);
+    /// ```
+    pub fn dimensions(&self) -> Dimensions {
+        GeometryTrait::dim(self)
+    }
+
+    /// The coordinate dimension of this geometry as this crate's [`Dimension`], e.g. to learn
+    /// whether the input was tagged `Z` or `M` without importing `geo_traits`.
+    ///
+    /// An empty geometry keeps the dimension it was written with.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::types::Dimension;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POINT M EMPTY").unwrap();
+    /// assert_eq!(wkt.dimension(), Dimension::XYM);
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("MULTIPOLYGON ZM EMPTY").unwrap();
+    /// assert_eq!(wkt.dimension(), Dimension::XYZM);
+    /// ```
+    pub fn dimension(&self) -> Dimension {
+        // Geometries parsed from WKT never have an unknown dimension
+        Dimension::of(self.dimensions())
+    }
+
+    /// If this is a non-empty `GEOMETRYCOLLECTION` whose members all have the same type, return
+    /// that type.
+    ///
+    /// Returns `None` for mixed or empty collections, and for any other geometry.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::types::GeometryType;
+    /// use wkt::Wkt;
+    ///
+    /// let layer: Wkt<f64> =
+    ///     Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(1 2 3),POINT Z(4 5 6))").unwrap();
+    /// assert_eq!(layer.is_homogeneous_collection(), Some(GeometryType::Point));
+    /// ```
+    pub fn is_homogeneous_collection(&self) -> Option<GeometryType> {
+        let Wkt::GeometryCollection(collection) = self else {
+            return None;
+        };
+        let mut members = collection.0.iter().map(Wkt::geometry_type);
+        let first = members.next()?;
+        members.all(|kind| kind == first).then_some(first)
+    }
+
+    /// All coordinates of this geometry, borrowed in the order they appear in WKT: points,
+    /// line strings, each ring of each polygon, and the members of collections, recursively.
+    /// Empty points have no coordinates.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> =
+    ///     Wkt::from_str("GEOMETRYCOLLECTION(POINT(5 1),LINESTRING(-2 0,3 4))").unwrap();
+    /// let (min_x, max_x) = wkt
+    ///     .coords()
+    ///     .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), c| (min.min(c.x), max.max(c.x)));
+    /// assert_eq!((min_x, max_x), (-2., 5.));
+    /// ```
+    pub fn coords(&self) -> impl Iterator<Item = &Coord<T>> {
+        self.boxed_coords()
+    }
+
+    /// [`coords`](Self::coords), boxed so that collections can recurse.
+    fn boxed_coords(&self) -> Box<dyn Iterator<Item = &Coord<T>> + '_> {
+        match self {
+            Wkt::Point(point) => Box::new(point.0.iter()),
+            Wkt::LineString(line_string) | Wkt::LinearRing(line_string) => {
+                Box::new(line_string.0.iter())
+            }
+            Wkt::Polygon(polygon) => Box::new(polygon.0.iter().flat_map(|ring| ring.0.iter())),
+            Wkt::MultiPoint(multi_point) => {
+                Box::new(multi_point.0.iter().filter_map(|point| point.0.as_ref()))
+            }
+            Wkt::MultiLineString(multi_line_string) => Box::new(
+                multi_line_string
+                    .0
+                    .iter()
+                    .flat_map(|line_string| line_string.0.iter()),
+            ),
+            Wkt::MultiPolygon(multi_polygon) => Box::new(
+                multi_polygon
+                    .0
+                    .iter()
+                    .flat_map(|polygon| polygon.0.iter())
+                    .flat_map(|ring| ring.0.iter()),
+            ),
+            Wkt::GeometryCollection(collection) => Box::new(
+                collection
+                    .0
+                    .iter()
+                    .flat_map(|geometry| geometry.boxed_coords()),
+            ),
+        }
+    }
+
+    /// Returns `true` if this geometry has more than `limit` coordinates.
+    ///
+    /// Counting stops as soon as the limit is passed, so this is cheap even for huge geometries,
+    /// e.g. when deciding whether to simplify before rendering.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING(0 0,1 1,2 2)").unwrap();
+    /// assert!(wkt.coord_count_exceeds(2));
+    /// assert!(!wkt.coord_count_exceeds(3));
+    /// ```
+    pub fn coord_count_exceeds(&self, limit: usize) -> bool {
+        more_than(self.coords(), limit)
+    }
+
+    /// The total number of coordinates of each type of geometry within this one.
+    ///
+    /// The members of a `GEOMETRYCOLLECTION` are counted under their own types, recursively, so
+    /// the result never contains [`GeometryType::GeometryCollection`]. This is useful for
+    /// pre-allocating columns before converting a mixed collection to a columnar format.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::types::GeometryType;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> =
+    ///     Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING Z(4 5 6,7 8 9))").unwrap();
+    /// let totals = wkt.coord_totals_by_kind();
+    /// assert_eq!(totals[&GeometryType::Point], 1);
+    /// assert_eq!(totals[&GeometryType::LineString], 2);
+    /// ```
+    pub fn coord_totals_by_kind(&self) -> HashMap<GeometryType, usize> {
+        let mut totals = HashMap::new();
+        self.add_coord_totals(&mut totals);
+        totals
+    }
+
+    fn add_coord_totals(&self, totals: &mut HashMap<GeometryType, usize>) {
+        if let Wkt::GeometryCollection(collection) = self {
+            for geometry in &collection.0 {
+                geometry.add_coord_totals(totals);
+            }
+        } else {
+            *totals.entry(self.geometry_type()).or_default() += self.coords().count();
+        }
+    }
+
+    /// The total number of rings of the polygons in this geometry, counting exteriors and holes.
+    ///
+    /// Polygons within a `GEOMETRYCOLLECTION` are counted, and geometries other than polygons have
+    /// no rings.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> =
+    ///     Wkt::from_str("POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,2 2,1 1))").unwrap();
+    /// assert_eq!(wkt.ring_count(), 2);
+    /// assert_eq!(wkt.hole_count(), 1);
+    /// ```
+    pub fn ring_count(&self) -> usize {
+        let mut count = 0;
+        self.for_each_polygon(&mut |polygon| count += polygon.0.len());
+        count
+    }
+
+    /// The total number of interior rings (holes) of the polygons in this geometry, counted as
+    /// in [`ring_count`](Self::ring_count).
+    pub fn hole_count(&self) -> usize {
+        let mut count = 0;
+        self.for_each_polygon(&mut |polygon| count += polygon.0.len().saturating_sub(1));
+        count
+    }
+
+    fn for_each_polygon(&self, f: &mut impl FnMut(&Polygon<T>)) {
+        match self {
+            Wkt::Polygon(polygon) => f(polygon),
+            Wkt::MultiPolygon(multi_polygon) => multi_polygon.0.iter().for_each(f),
+            Wkt::GeometryCollection(collection) => {
+                for geometry in &collection.0 {
+                    geometry.for_each_polygon(f);
+                }
+            }
+            Wkt::Point(_)
+            | Wkt::LineString(_)
+            | Wkt::LinearRing(_)
+            | Wkt::MultiPoint(_)
+            | Wkt::MultiLineString(_) => (),
+        }
+    }
+
+    /// The minimum and maximum corners of the axis-aligned box enclosing every coordinate of this
+    /// geometry, or `None` if it is empty.
+    ///
+    /// The corners only have a z (or m) if every coordinate of the geometry has one.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::types::Coord;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(0 5 2,3 -1 4)").unwrap();
+    /// assert_eq!(
+    ///     wkt.bounding_rect(),
+    ///     Some((
+    ///         Coord { x: 0., y: -1., z: Some(2.), m: None },
+    ///         Coord { x: 3., y: 5., z: Some(4.), m: None },
+    ///     ))
+    /// );
+    /// ```
+    pub fn bounding_rect(&self) -> Option<(Coord<T>, Coord<T>)>
+    where
+        T: Float,
+    {
+        let mut coords = self.coords();
+        let first = coords.next()?;
+        Some(coords.fold((first.clone(), first.clone()), |bounds, c| {
+            union_bounds(bounds, (c, c))
+        }))
+    }
+
+    /// The minimum and maximum z of this geometry, or `None` if it is empty or any coordinate has
+    /// no z.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(0 0 120.5,1 1 98,2 2 130)").unwrap();
+    /// assert_eq!(wkt.z_range(), Some((98., 130.)));
+    /// ```
+    pub fn z_range(&self) -> Option<(T, T)>
+    where
+        T: Float,
+    {
+        let (min, max) = self.bounding_rect()?;
+        min.z.zip(max.z)
+    }
+
+    /// The minimum and maximum m of this geometry, or `None` if it is empty or any coordinate has
+    /// no m.
+    pub fn m_range(&self) -> Option<(T, T)>
+    where
+        T: Float,
+    {
+        let (min, max) = self.bounding_rect()?;
+        min.m.zip(max.m)
+    }
+
+    /// A rectangular `POLYGON` spanning the x and y bounds of this geometry, or `None` if it is
+    /// empty, like PostGIS's `ST_Envelope`.
+    ///
+    /// The ring is closed, and starts at the minimum corner. If the geometry has z ordinates, the
+    /// rectangle lies at its minimum z.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(0 5 2,3 -1 4)").unwrap();
+    /// assert_eq!(
+    ///     wkt.envelope().unwrap().to_string(),
+    ///     "POLYGON Z((0 -1 2,0 5 2,3 5 2,3 -1 2,0 -1 2))"
+    /// );
+    /// ```
+    pub fn envelope(&self) -> Option<Wkt<T>>
+    where
+        T: Float,
+    {
+        let (min, max) = self.bounding_rect()?;
+        let corner = |x, y| Coord {
+            x,
+            y,
+            z: min.z,
+            m: None,
+        };
+        let ring = LineString::new(vec![
+            corner(min.x, min.y),
+            corner(min.x, max.y),
+            corner(max.x, max.y),
+            corner(max.x, min.y),
+            corner(min.x, min.y),
+        ]);
+        Some(Wkt::Polygon(Polygon::new(vec![ring])))
+    }
+
+    /// Every vertex of this geometry, at any nesting depth, which falls within the box spanned by
+    /// `min` and `max` (inclusive).
+    ///
+    /// The z range is only checked for vertices with a z, and only if `min` and `max` have one.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::types::Coord;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(0 0 0,5 5 5,10 10 10)").unwrap();
+    /// let min = Coord { x: 1., y: 1., z: Some(1.), m: None };
+    /// let max = Coord { x: 6., y: 6., z: Some(6.), m: None };
+    /// assert_eq!(
+    ///     wkt.vertices_in_bbox(&min, &max),
+    ///     vec![Coord { x: 5., y: 5., z: Some(5.), m: None }]
+    /// );
+    /// ```
+    pub fn vertices_in_bbox(&self, min: &Coord<T>, max: &Coord<T>) -> Vec<Coord<T>>
+    where
+        T: PartialOrd,
+    {
+        self.coords()
+            .filter(|c| {
+                (min.x..=max.x).contains(&c.x)
+                    && (min.y..=max.y).contains(&c.y)
+                    && match (min.z, max.z, c.z) {
+                        (Some(lo), Some(hi), Some(z)) => (lo..=hi).contains(&z),
+                        _ => true,
+                    }
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Returns `true` if every x, y and z ordinate of this geometry is within `tolerance` of a
+    /// multiple of `resolution`, e.g. to check that data has been snapped to a grid.
+    ///
+    /// Measures are not checked. An empty geometry is trivially aligned.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(0.5 1 -2,1.5 3.0000001 0)").unwrap();
+    /// assert!(wkt.is_grid_aligned(0.5, 1e-6));
+    /// assert!(!wkt.is_grid_aligned(1.0, 1e-6));
+    /// ```
+    pub fn is_grid_aligned(&self, resolution: T, tolerance: T) -> bool
+    where
+        T: Float,
+    {
+        let aligned = |ordinate: T| {
+            ((ordinate / resolution).round() * resolution - ordinate).abs() <= tolerance
+        };
+        self.coords()
+            .all(|c| aligned(c.x) && aligned(c.y) && c.z.map_or(true, aligned))
+    }
+}
+
+/// Implements a borrowing `as_*` and a consuming `into_*` accessor for each listed variant.
+macro_rules! variant_accessors {
+    ($($variant:ident($type:ident): $as_fn:ident, $into_fn:ident;)*) => {
+        impl<T: WktNum> Wkt<T> {
+            $(
+                #[doc = concat!(
+                    "This geometry if it's a [`Wkt::", stringify!($variant),
+                    "`], or `None` otherwise."
+                )]
+                pub fn $as_fn(&self) -> Option<&$type<T>> {
+                    match self {
+                        Wkt::$variant(geometry) => Some(geometry),
+                        _ => None,
+                    }
+                }
+
+                #[doc = concat!(
+                    "This geometry if it's a [`Wkt::", stringify!($variant),
+                    "`], or the geometry unchanged as the error otherwise."
+                )]
+                pub fn $into_fn(self) -> Result<$type<T>, Self> {
+                    match self {
+                        Wkt::$variant(geometry) => Ok(geometry),
+                        other => Err(other),
+                    }
+                }
+            )*
+        }
+    };
+}
+
+variant_accessors! {
+    Point(Point): as_point, into_point;
+    LineString(LineString): as_line_string, into_line_string;
+    LinearRing(LineString): as_linear_ring, into_linear_ring;
+    Polygon(Polygon): as_polygon, into_polygon;
+    MultiPoint(MultiPoint): as_multi_point, into_multi_point;
+    MultiLineString(MultiLineString): as_multi_line_string, into_multi_line_string;
+    MultiPolygon(MultiPolygon): as_multi_polygon, into_multi_polygon;
+    GeometryCollection(GeometryCollection): as_geometry_collection, into_geometry_collection;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{more_than, total_bounds};
+    use crate::types::{Coord, Dimension, GeometryType};
+    use crate::{ParseLimits, Wkt};
+    use std::str::FromStr;
+
+    #[test]
+    fn variant_accessors() {
+        let wkt: Wkt<f64> = Wkt::from_str("POINT(1 2)").unwrap();
+        assert_eq!(
+            wkt.as_point().and_then(|p| p.0.as_ref()).map(|c| c.y),
+            Some(2.)
+        );
+        assert!(wkt.as_line_string().is_none());
+        assert!(wkt.as_geometry_collection().is_none());
+        let point = wkt.clone().into_point().unwrap();
+        assert_eq!(Wkt::Point(point), wkt);
+
+        let limits = ParseLimits {
+            keep_linear_rings: true,
+            ..Default::default()
+        };
+        let ring: Wkt<f64> =
+            Wkt::from_str_with_limits("LINEARRING(0 0,1 0,0 1,0 0)", &limits).unwrap();
+        assert!(ring.as_line_string().is_none());
+        assert_eq!(ring.as_linear_ring().map(|r| r.0.len()), Some(4));
+
+        let collection: Wkt<f64> = Wkt::from_str("GEOMETRYCOLLECTION(POINT(1 2))").unwrap();
+        let not_a_polygon = collection.clone().into_polygon().unwrap_err();
+        assert_eq!(not_a_polygon, collection);
+        assert_eq!(collection.into_geometry_collection().unwrap().0.len(), 1);
+    }
+
+    #[test]
+    fn homogeneous_collection() {
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "GEOMETRYCOLLECTION Z(LINESTRING Z(1 2 3,4 5 6),LINESTRING Z(7 8 9,10 11 12))",
+        )
+        .unwrap();
+        assert_eq!(
+            wkt.is_homogeneous_collection(),
+            Some(GeometryType::LineString)
+        );
+    }
+
+    #[test]
+    fn mixed_collection() {
+        let wkt: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING Z(4 5 6,7 8 9))")
+                .unwrap();
+        assert_eq!(wkt.is_homogeneous_collection(), None);
+    }
+
+    #[test]
+    fn type_names_and_emptiness() {
+        let cases = [
+            ("POINT EMPTY", "Point", true),
+            ("POINT Z(1 2 3)", "Point", false),
+            ("LINESTRING EMPTY", "LineString", true),
+            ("POLYGON Z((0 0 0,1 0 0,1 1 0,0 0 0))", "Polygon", false),
+            ("MULTIPOINT EMPTY", "MultiPoint", true),
+            ("MULTILINESTRING((1 2,3 4))", "MultiLineString", false),
+            ("MULTIPOLYGON EMPTY", "MultiPolygon", true),
+            ("GEOMETRYCOLLECTION EMPTY", "GeometryCollection", true),
+            (
+                "GEOMETRYCOLLECTION(POINT EMPTY)",
+                "GeometryCollection",
+                false,
+            ),
+        ];
+        for (wkt, name, empty) in cases {
+            let wkt: Wkt<f64> = Wkt::from_str(wkt).unwrap();
+            assert_eq!(wkt.geometry_type_name(), name);
+            assert_eq!(wkt.is_empty(), empty, "{wkt}");
+        }
+    }
+
+    #[test]
+    fn coords_in_document_order() {
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "GEOMETRYCOLLECTION(MULTIPOINT((1 1)),POLYGON((0 0,4 0,4 4,0 0),(1 1,2 1,1 1)))",
+        )
+        .unwrap();
+        let xs: Vec<f64> = wkt.coords().map(|coord| coord.x).collect();
+        assert_eq!(xs, [1., 0., 4., 4., 0., 1., 2., 1.]);
+
+        // Coordinates are borrowed from the geometry, not copied
+        let Wkt::GeometryCollection(collection) = &wkt else {
+            unreachable!()
+        };
+        let Wkt::Polygon(polygon) = &collection.0[1] else {
+            unreachable!()
+        };
+        assert!(std::ptr::eq(
+            wkt.coords().nth(1).unwrap(),
+            &polygon.0[0].0[0]
+        ));
+    }
+
+    #[test]
+    fn coord_count_exceeds_stops_early() {
+        let coords = (0..100_000)
+            .map(|i| format!("{i} {i}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let wkt: Wkt<f64> = Wkt::from_str(&format!("MULTIPOINT({coords})")).unwrap();
+        assert!(wkt.coord_count_exceeds(10));
+        assert!(wkt.coord_count_exceeds(99_999));
+        assert!(!wkt.coord_count_exceeds(100_000));
+
+        let mut visited = 0;
+        assert!(more_than(wkt.coords().inspect(|_| visited += 1), 10));
+        assert_eq!(visited, 11);
+    }
+
+    #[test]
+    fn dimensions_without_trait_import() {
+        let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(1 2 3,4 5 6)").unwrap();
+        assert_eq!(wkt.dimensions(), geo_traits::Dimensions::Xyz);
+    }
+
+    #[test]
+    fn dimension_of_each_tag() {
+        for (input, dimension) in [
+            ("POINT(1 2)", Dimension::XY),
+            ("POINT Z(1 2 3)", Dimension::XYZ),
+            ("POINT M(1 2 3)", Dimension::XYM),
+            ("POINT ZM(1 2 3 4)", Dimension::XYZM),
+            ("LINESTRING(1 2 3,4 5 6)", Dimension::XYZ),
+            ("POLYGONM((0 0 1,1 0 1,1 1 1,0 0 1))", Dimension::XYM),
+            ("MULTIPOINT ZM((1 2 3 4))", Dimension::XYZM),
+            ("GEOMETRYCOLLECTION Z(POINT Z(1 2 3))", Dimension::XYZ),
+            ("POINT ZM EMPTY", Dimension::XYZM),
+            ("LINESTRING EMPTY", Dimension::XY),
+            ("LINESTRING Z EMPTY", Dimension::XYZ),
+            ("MULTIPOLYGON M EMPTY", Dimension::XYM),
+            ("GEOMETRYCOLLECTION ZM EMPTY", Dimension::XYZM),
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str(input).unwrap();
+            assert_eq!(wkt.dimension(), dimension, "{input}");
+        }
+    }
+
+    #[test]
+    fn coord_totals_by_kind() {
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "GEOMETRYCOLLECTION Z(POINT Z(1 2 3),POLYGON Z((0 0 0,1 0 0,1 1 0,0 0 0)),GEOMETRYCOLLECTION Z(POINT Z(4 5 6),POLYGON Z((0 0 0,2 0 0,2 2 0,0 0 0),(1 1 0,1.5 1 0,1 1.5 0,1 1 0))))",
+        )
+        .unwrap();
+        let totals = wkt.coord_totals_by_kind();
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[&GeometryType::Point], 2);
+        assert_eq!(totals[&GeometryType::Polygon], 12);
+    }
+
+    #[test]
+    fn ring_and_hole_counts() {
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((0 0,10 0,10 10,0 0),(1 1,2 1,2 2,1 1),(5 5,6 5,6 6,5 5)))",
+        )
+        .unwrap();
+        assert_eq!(wkt.ring_count(), 4);
+        assert_eq!(wkt.hole_count(), 2);
+
+        let wkt: Wkt<f64> = Wkt::from_str("LINESTRING(0 0,1 1)").unwrap();
+        assert_eq!(wkt.ring_count(), 0);
+        assert_eq!(wkt.hole_count(), 0);
+    }
+
+    #[test]
+    fn envelope_of_triangle() {
+        let wkt: Wkt<f64> = Wkt::from_str("POLYGON Z((1 1 0,5 2 1,2 4 2,1 1 0))").unwrap();
+        let expected: Wkt<f64> =
+            Wkt::from_str("POLYGON Z((1 1 0,1 4 0,5 4 0,5 1 0,1 1 0))").unwrap();
+        assert_eq!(wkt.envelope(), Some(expected));
+
+        let empty: Wkt<f64> = Wkt::from_str("POLYGON EMPTY").unwrap();
+        assert_eq!(empty.envelope(), None);
+    }
+
+    #[test]
+    fn total_bounds_of_layer() {
+        let geoms: Vec<Wkt<f64>> = vec![
+            Wkt::from_str("POINT Z(10 -3 5)").unwrap(),
+            Wkt::from_str("POLYGON Z((0 0 0,4 0 1,4 4 2,0 0 0))").unwrap(),
+            Wkt::from_str("MULTILINESTRING Z((-2 1 7,3 9 -1))").unwrap(),
+        ];
+        assert_eq!(
+            total_bounds(&geoms),
+            Some((
+                Coord {
+                    x: -2.,
+                    y: -3.,
+                    z: Some(-1.),
+                    m: None,
+                },
+                Coord {
+                    x: 10.,
+                    y: 9.,
+                    z: Some(7.),
+                    m: None,
+                },
+            ))
+        );
+
+        let empty: Vec<Wkt<f64>> = vec![Wkt::from_str("LINESTRING EMPTY").unwrap()];
+        assert_eq!(total_bounds(&empty), None);
+        assert_eq!(total_bounds::<f64>(&[]), None);
+    }
+
+    #[test]
+    fn z_and_m_ranges() {
+        let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(0 0 5,1 1 -2,2 2 3)").unwrap();
+        assert_eq!(wkt.z_range(), Some((-2., 5.)));
+        assert_eq!(wkt.m_range(), None);
+
+        let wkt: Wkt<f64> =
+            Wkt::from_str("MULTIPOINT M((0 0 1700000000),(1 1 1700000600))").unwrap();
+        assert_eq!(wkt.z_range(), None);
+        assert_eq!(wkt.m_range(), Some((1700000000., 1700000600.)));
+
+        let wkt: Wkt<f64> = Wkt::from_str("POINT ZM(1 2 3 4)").unwrap();
+        assert_eq!(wkt.z_range(), Some((3., 3.)));
+        assert_eq!(wkt.m_range(), Some((4., 4.)));
+
+        let empty: Wkt<f64> = Wkt::from_str("LINESTRING Z EMPTY").unwrap();
+        assert_eq!(empty.z_range(), None);
+    }
+
+    #[test]
+    fn grid_aligned_polygon() {
+        let wkt: Wkt<f64> =
+            Wkt::from_str("POLYGON Z((0 0 0,0.25 0 0.5,0.25 0.75 1,0 0 0))").unwrap();
+        assert!(wkt.is_grid_aligned(0.25, 1e-9));
+
+        let off: Wkt<f64> =
+            Wkt::from_str("POLYGON Z((0 0 0,0.25 0 0.5,0.26 0.75 1,0 0 0))").unwrap();
+        assert!(!off.is_grid_aligned(0.25, 1e-9));
+        assert!(off.is_grid_aligned(0.25, 0.02));
+    }
+
+    #[test]
+    fn vertices_in_bbox_polygon() {
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "POLYGON Z((0 0 0,10 0 0,10 10 0,0 10 0,0 0 0),(4 4 0,6 4 0,5 6 0,4 4 0))",
+        )
+        .unwrap();
+        let min = Coord {
+            x: 3.,
+            y: -1.,
+            z: Some(-1.),
+            m: None,
+        };
+        let max = Coord {
+            x: 11.,
+            y: 5.,
+            z: Some(1.),
+            m: None,
+        };
+        assert_eq!(
+            wkt.vertices_in_bbox(&min, &max),
+            vec![
+                Coord {
+                    x: 10.,
+                    y: 0.,
+                    z: Some(0.),
+                    m: None,
+                },
+                Coord {
+                    x: 4.,
+                    y: 4.,
+                    z: Some(0.),
+                    m: None,
+                },
+                Coord {
+                    x: 6.,
+                    y: 4.,
+                    z: Some(0.),
+                    m: None,
+                },
+                Coord {
+                    x: 4.,
+                    y: 4.,
+                    z: Some(0.),
+                    m: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn not_a_collection() {
+        let empty: Wkt<f64> = Wkt::from_str("GEOMETRYCOLLECTION EMPTY").unwrap();
+        assert_eq!(empty.is_homogeneous_collection(), None);
+
+        let point: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+        assert_eq!(point.geometry_type(), GeometryType::Point);
+        assert_eq!(point.is_homogeneous_collection(), None);
+    }
+}
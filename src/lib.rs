@@ -24,8 +24,17 @@
 //!
 //! For advanced usage, see the [`types`](crate::types) module for a list of internally used types.
 //!
-//! This crate has optional `serde` integration for deserializing fields containing WKT. See
-//! [`deserialize`] for an example.
+//! This crate has optional `serde` integration for serializing and deserializing fields containing
+//! WKT. See [`deserialize`] for an example.
+//!
+//! The optional `ordered` feature adds [`types::Ordered`], which gives geometries of `f32` and
+//! `f64` a total order and hash, so they can be sorted or used as `HashMap` keys.
+//!
+//! The optional `geo-types-2d` feature implements [`ToWkt`] for mainline 2D `geo-types` too,
+//! writing 2D WKT, alongside the implementations for the 3D fork.
+//!
+//! The optional `arbitrary` feature implements `arbitrary::Arbitrary` for [`Wkt`] and the
+//! [`types`], generating structurally valid geometries for fuzzing.
 //!
 //! # Examples
 //!
@@ -101,9 +110,10 @@ use geo_traits::{
     GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait,
     MultiPolygonTrait, PointTrait, PolygonTrait,
 };
-use num_traits::Float;
+use geo_types::CoordNum;
 
-use crate::to_wkt::write_geometry;
+use crate::error::{ParseWktError, ReadError};
+use crate::to_wkt::{write_wkt_with_config, WriteConfig};
 use crate::tokenizer::{PeekableTokens, Token, Tokens};
 use crate::types::{
     Dimension, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
@@ -111,25 +121,45 @@ use crate::types::{
 };
 
 pub mod to_wkt;
-mod tokenizer;
+pub mod tokenizer;
 
 /// Error variant for this crate
 pub mod error;
+pub mod read;
 /// `WKT` primitive types and collections
 pub mod types;
+pub mod wkb;
 
+mod compare;
+mod erased;
+mod ewkt;
 mod infer_type;
+mod inspect;
+mod lossless;
+mod transform;
+mod validate;
 
-pub use infer_type::infer_type;
+pub use erased::{DynGeometry, ErasedGeometry};
+pub use ewkt::WktWithSrid;
+pub use infer_type::{infer_type, infer_type_and_dim};
+pub use inspect::total_bounds;
+pub use lossless::WktWithText;
+pub use transform::{NormalizeOptions, RingOrientation};
+pub use validate::{validate_all, ValidationIssue};
 
-pub use crate::to_wkt::ToWkt;
+pub use crate::to_wkt::{ToWkt, ToWktString};
 
 pub mod conversion;
 
+pub mod ffi;
+
 pub mod geo_types_from_wkt;
 
 mod geo_types_to_wkt;
 
+#[cfg(feature = "geo-types-2d")]
+mod geo_types_2d_to_wkt;
+
 #[cfg(feature = "serde")]
 pub mod deserialize;
 #[cfg(feature = "serde")]
@@ -149,14 +179,41 @@ pub use deserialize::geo_types::deserialize_geometry;
 )]
 pub use deserialize::geo_types::deserialize_point;
 
-pub trait WktNum: PartialEq + fmt::Debug + Float + Default {}
-impl<T> WktNum for T where T: PartialEq + fmt::Debug + Float + Default {}
+/// The numeric type of the ordinates of a [`Wkt`] geometry.
+///
+/// Parsing only needs `FromStr` on top of this, so integer, decimal and fixed-point types can be
+/// used as well as floats. Geometric operations such as [`Wkt::normalize`] and
+/// [`Wkt::bounding_rect`] additionally require [`Float`](num_traits::Float), and writing requires
+/// [`CoordNum`].
+///
+/// Each number is parsed with the type's own `FromStr`, so for integer types any number with a
+/// fractional part or exponent, even `1.0` or `1e2`, fails with
+/// [`ParseWktError::InvalidNumber`] rather than being rounded:
+///
+/// ```
+/// use wkt::error::ParseWktError;
+/// use wkt::{TryFromWkt, Wkt};
+///
+/// let point = geo_types::Point::<i64>::try_from_wkt_str("POINT Z(1 -2 3)").unwrap();
+/// assert_eq!(point.x(), 1);
+///
+/// let err = "POINT(1 2.5)".parse::<Wkt<i64>>().unwrap_err();
+/// assert!(matches!(err, ParseWktError::InvalidNumber { offset: 8, .. }));
+/// ```
+pub trait WktNum: Copy + PartialEq + fmt::Debug + Default {}
+impl<T> WktNum for T where T: Copy + PartialEq + fmt::Debug + Default {}
 
 #[derive(Clone, Debug, PartialEq)]
 /// All supported WKT geometry [`types`]
 pub enum Wkt<T: WktNum> {
     Point(Point<T>),
     LineString(LineString<T>),
+    /// A `LINEARRING`, as written by JTS. It is a [`LineString`] to everything but the WKT
+    /// writer, which keeps the `LINEARRING` keyword.
+    ///
+    /// Only parsed when [`ParseLimits::keep_linear_rings`] is set; otherwise a `LINEARRING` is
+    /// read as a [`Wkt::LineString`].
+    LinearRing(LineString<T>),
     Polygon(Polygon<T>),
     MultiPoint(MultiPoint<T>),
     MultiLineString(MultiLineString<T>),
@@ -168,10 +225,19 @@ impl<T> Wkt<T>
 where
     T: WktNum + FromStr,
 {
+    /// A parsed `LINEARRING`, which is only kept distinct from a `LINESTRING` on request.
+    fn linear_ring(ring: LineString<T>, tokens: &PeekableTokens<T>) -> Self {
+        if tokens.keep_linear_rings() {
+            Wkt::LinearRing(ring)
+        } else {
+            Wkt::LineString(ring)
+        }
+    }
+
     fn from_word_and_tokens(
         word: &str,
         tokens: &mut PeekableTokens<T>,
-    ) -> Result<Self, &'static str> {
+    ) -> Result<Self, ParseWktError> {
         // Normally Z/M/ZM is separated by a space from the primary WKT word. E.g. `POINT Z`
         // instead of `POINTZ`. However we wish to support both types (in reading). When written
         // without a space, `POINTZ` is considered a single word, which means we need to include
@@ -181,92 +247,219 @@ where
                 let point_or_err = <Point<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
                 point_or_err.map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("POINTZ") | w.eq_ignore_ascii_case("POINTM") => {
+            w if w.eq_ignore_ascii_case("POINTZ") => {
                 let point_or_err = <Point<T> as FromTokens<T>>::from_tokens_with_header(
                     tokens,
                     Some(Dimension::XYZ),
                 );
                 point_or_err.map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("LINESTRING") || w.eq_ignore_ascii_case("LINEARRING") => {
+            w if w.eq_ignore_ascii_case("POINTM") => {
+                let point_or_err = <Point<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYM),
+                );
+                point_or_err.map(Into::into)
+            }
+            w if w.eq_ignore_ascii_case("POINTZM") => {
+                let point_or_err = <Point<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYZM),
+                );
+                point_or_err.map(Into::into)
+            }
+            w if w.eq_ignore_ascii_case("LINESTRING") => {
                 let ls_or_err = <LineString<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
                 ls_or_err.map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("LINESTRINGZ") | w.eq_ignore_ascii_case("LINESTRINGM") => {
+            w if w.eq_ignore_ascii_case("LINESTRINGZ") => {
                 let ls_or_err = <LineString<T> as FromTokens<T>>::from_tokens_with_header(
                     tokens,
                     Some(Dimension::XYZ),
                 );
                 ls_or_err.map(Into::into)
             }
+            w if w.eq_ignore_ascii_case("LINESTRINGM") => {
+                let ls_or_err = <LineString<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYM),
+                );
+                ls_or_err.map(Into::into)
+            }
+            w if w.eq_ignore_ascii_case("LINESTRINGZM") => {
+                let ls_or_err = <LineString<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYZM),
+                );
+                ls_or_err.map(Into::into)
+            }
+            w if w.eq_ignore_ascii_case("LINEARRING") => {
+                let ls_or_err =
+                    <LineString<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
+                ls_or_err.map(|ls| Wkt::linear_ring(ls, tokens))
+            }
+            w if w.eq_ignore_ascii_case("LINEARRINGZ") => {
+                let ls_or_err = <LineString<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYZ),
+                );
+                ls_or_err.map(|ls| Wkt::linear_ring(ls, tokens))
+            }
+            w if w.eq_ignore_ascii_case("LINEARRINGM") => {
+                let ls_or_err = <LineString<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYM),
+                );
+                ls_or_err.map(|ls| Wkt::linear_ring(ls, tokens))
+            }
+            w if w.eq_ignore_ascii_case("LINEARRINGZM") => {
+                let ls_or_err = <LineString<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYZM),
+                );
+                ls_or_err.map(|ls| Wkt::linear_ring(ls, tokens))
+            }
             w if w.eq_ignore_ascii_case("POLYGON") => {
                 let poly_or_err = <Polygon<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
                 poly_or_err.map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("POLYGONZ") | w.eq_ignore_ascii_case("POLYGONM") => {
+            w if w.eq_ignore_ascii_case("POLYGONZ") => {
                 let poly_or_err = <Polygon<T> as FromTokens<T>>::from_tokens_with_header(
                     tokens,
                     Some(Dimension::XYZ),
                 );
                 poly_or_err.map(Into::into)
             }
+            w if w.eq_ignore_ascii_case("POLYGONM") => {
+                let poly_or_err = <Polygon<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYM),
+                );
+                poly_or_err.map(Into::into)
+            }
+            w if w.eq_ignore_ascii_case("POLYGONZM") => {
+                let poly_or_err = <Polygon<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYZM),
+                );
+                poly_or_err.map(Into::into)
+            }
             w if w.eq_ignore_ascii_case("MULTIPOINT") => {
                 let mp_or_err = <MultiPoint<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
                 mp_or_err.map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("MULTIPOINTZ") | w.eq_ignore_ascii_case("MULTIPOINTM") => {
+            w if w.eq_ignore_ascii_case("MULTIPOINTZ") => {
                 let mp_or_err = <MultiPoint<T> as FromTokens<T>>::from_tokens_with_header(
                     tokens,
                     Some(Dimension::XYZ),
                 );
                 mp_or_err.map(Into::into)
             }
+            w if w.eq_ignore_ascii_case("MULTIPOINTM") => {
+                let mp_or_err = <MultiPoint<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYM),
+                );
+                mp_or_err.map(Into::into)
+            }
+            w if w.eq_ignore_ascii_case("MULTIPOINTZM") => {
+                let mp_or_err = <MultiPoint<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYZM),
+                );
+                mp_or_err.map(Into::into)
+            }
             w if w.eq_ignore_ascii_case("MULTILINESTRING") => {
                 let mls_or_err =
                     <MultiLineString<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
                 mls_or_err.map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("MULTILINESTRINGZ") | w.eq_ignore_ascii_case("MULTILINESTRINGM") => {
+            w if w.eq_ignore_ascii_case("MULTILINESTRINGZ") => {
                 let mls_or_err = <MultiLineString<T> as FromTokens<T>>::from_tokens_with_header(
                     tokens,
                     Some(Dimension::XYZ),
                 );
                 mls_or_err.map(Into::into)
             }
+            w if w.eq_ignore_ascii_case("MULTILINESTRINGM") => {
+                let mls_or_err = <MultiLineString<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYM),
+                );
+                mls_or_err.map(Into::into)
+            }
+            w if w.eq_ignore_ascii_case("MULTILINESTRINGZM") => {
+                let mls_or_err = <MultiLineString<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYZM),
+                );
+                mls_or_err.map(Into::into)
+            }
             w if w.eq_ignore_ascii_case("MULTIPOLYGON") => {
                 let mpoly_or_err = <MultiPolygon<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
                 mpoly_or_err.map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("MULTIPOLYGONZ") | w.eq_ignore_ascii_case("MULTIPOLYGONM") => {
+            w if w.eq_ignore_ascii_case("MULTIPOLYGONZ") => {
                 let mpoly_or_err = <MultiPolygon<T> as FromTokens<T>>::from_tokens_with_header(
                     tokens,
                     Some(Dimension::XYZ),
                 );
                 mpoly_or_err.map(Into::into)
             }
+            w if w.eq_ignore_ascii_case("MULTIPOLYGONM") => {
+                let mpoly_or_err = <MultiPolygon<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYM),
+                );
+                mpoly_or_err.map(Into::into)
+            }
+            w if w.eq_ignore_ascii_case("MULTIPOLYGONZM") => {
+                let mpoly_or_err = <MultiPolygon<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYZM),
+                );
+                mpoly_or_err.map(Into::into)
+            }
             w if w.eq_ignore_ascii_case("GEOMETRYCOLLECTION") => {
                 let gc_or_err =
                     <GeometryCollection<T> as FromTokens<T>>::from_tokens_with_header(tokens, None);
                 gc_or_err.map(Into::into)
             }
-            w if w.eq_ignore_ascii_case("GEOMETRYCOLLECTIONZ") | w.eq_ignore_ascii_case("GEOMETRYCOLLECTIONM") => {
+            w if w.eq_ignore_ascii_case("GEOMETRYCOLLECTIONZ") => {
                 let gc_or_err = <GeometryCollection<T> as FromTokens<T>>::from_tokens_with_header(
                     tokens,
                     Some(Dimension::XYZ),
                 );
                 gc_or_err.map(Into::into)
             }
-            _ => Err("Invalid type encountered"),
+            w if w.eq_ignore_ascii_case("GEOMETRYCOLLECTIONM") => {
+                let gc_or_err = <GeometryCollection<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYM),
+                );
+                gc_or_err.map(Into::into)
+            }
+            w if w.eq_ignore_ascii_case("GEOMETRYCOLLECTIONZM") => {
+                let gc_or_err = <GeometryCollection<T> as FromTokens<T>>::from_tokens_with_header(
+                    tokens,
+                    Some(Dimension::XYZM),
+                );
+                gc_or_err.map(Into::into)
+            }
+            _ => Err(ParseWktError::UnknownGeometryType {
+                name: word.to_string(),
+                offset: tokens.token_start(),
+            }),
         }
     }
 }
 
 impl<T> fmt::Display for Wkt<T>
 where
-    T: WktNum + fmt::Display + Float,
+    T: WktNum + CoordNum + fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        Ok(write_geometry(f, self)?)
+        Ok(write_wkt_with_config(f, self, &WriteConfig::default())?)
     }
 }
 
@@ -274,29 +467,215 @@ impl<T> Wkt<T>
 where
     T: WktNum + FromStr,
 {
-    fn from_tokens(tokens: Tokens<T>) -> Result<Self, &'static str> {
-        let mut tokens = tokens.peekable();
+    fn from_tokens(tokens: &mut PeekableTokens<T>) -> Result<Self, ParseWktError> {
         let word = match tokens.next().transpose()? {
             Some(Token::Word(word)) => {
                 if !word.is_ascii() {
-                    return Err("Encountered non-ascii word");
+                    return Err(tokens.unexpected_token_error("Encountered non-ascii word"));
                 }
                 word
             }
-            _ => return Err("Invalid WKT format"),
+            Some(_) => return Err(tokens.unexpected_token_error("Invalid WKT format")),
+            None => return Err(tokens.unexpected_end_error("Invalid WKT format")),
         };
-        Wkt::from_word_and_tokens(&word, &mut tokens)
+        Wkt::from_word_and_tokens(&word, tokens)
+    }
+
+    /// Parse a single geometry from the front of `wkt_str`, returning it along with the byte
+    /// offset just past its end.
+    ///
+    /// Anything following the geometry is left unparsed, which is useful when WKT is embedded in
+    /// a larger grammar. [`Wkt::from_str`] instead fails with [`ParseWktError::TrailingTokens`]
+    /// if anything but whitespace follows the geometry.
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    ///
+    /// let input = "POINT Z(1 2 3) AND more";
+    /// let (wkt, offset): (Wkt<f64>, _) = Wkt::parse_prefix(input).unwrap();
+    /// assert_eq!(wkt.to_string(), "POINT Z(1 2 3)");
+    /// assert_eq!(&input[offset..], " AND more");
+    /// ```
+    pub fn parse_prefix(wkt_str: &str) -> Result<(Self, usize), ParseWktError> {
+        let mut tokens = PeekableTokens::new(Tokens::from_str(wkt_str));
+        let wkt = Wkt::from_tokens(&mut tokens)?;
+        Ok((wkt, tokens.offset()))
+    }
+}
+
+/// Limits on the input accepted when parsing WKT, to bound the resources used by untrusted input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// How deeply `GEOMETRYCOLLECTION`s may be nested, counting a top level collection as 1.
+    /// Deeper input fails with [`ParseWktError::NestingTooDeep`]. Defaults to 32.
+    pub max_nesting_depth: usize,
+    /// Accept infinite and NaN ordinates written as e.g. `-inf` or `+NaN`, where the coordinate
+    /// type parses them. Otherwise they fail with [`ParseWktError::NonFiniteNumber`]. Defaults
+    /// to `false`. Either way, finite numbers too large for the type, such as `1e40` for `f32`,
+    /// fail with [`ParseWktError::NumberOutOfRange`] rather than being read as infinity.
+    pub allow_nonfinite: bool,
+    /// Parse `LINEARRING` as [`Wkt::LinearRing`], so that it is written back as `LINEARRING`.
+    /// Otherwise it is read as a [`Wkt::LineString`]. Defaults to `false`.
+    pub keep_linear_rings: bool,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        ParseLimits {
+            max_nesting_depth: 32,
+            allow_nonfinite: false,
+            keep_linear_rings: false,
+        }
     }
 }
 
+/// Parsing is iterative over the coordinates of a geometry, so stack usage does not grow with the
+/// number of coordinates; only nested `GEOMETRYCOLLECTION`s recurse, and their nesting is limited
+/// as in [`ParseLimits::default`]. Memory usage is roughly the
+/// size of the parsed geometry: an x and y plus an optional z and m per coordinate (48 bytes for
+/// `f64`) plus the `Vec` overhead of each ring, part, and member.
 impl<T> FromStr for Wkt<T>
 where
     T: WktNum + FromStr + Default,
 {
-    type Err = &'static str;
+    type Err = ParseWktError;
 
     fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
-        Wkt::from_tokens(Tokens::from_str(wkt_str))
+        Wkt::from_bytes(wkt_str.as_bytes())
+    }
+}
+
+/// The same as [`Wkt::from_str`], for use in `try_into()` chains.
+impl<T> TryFrom<&str> for Wkt<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    type Error = ParseWktError;
+
+    fn try_from(wkt_str: &str) -> Result<Self, Self::Error> {
+        Wkt::from_str(wkt_str)
+    }
+}
+
+/// The same as [`Wkt::from_str`], for use in `try_into()` chains.
+impl<T> TryFrom<String> for Wkt<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    type Error = ParseWktError;
+
+    fn try_from(wkt_str: String) -> Result<Self, Self::Error> {
+        Wkt::from_str(&wkt_str)
+    }
+}
+
+impl<T> Wkt<T>
+where
+    T: WktNum + FromStr + Default,
+{
+    /// Parse WKT as [`Wkt::from_str`] does, but within `limits` rather than the defaults.
+    ///
+    /// ```
+    /// use wkt::error::ParseWktError;
+    /// use wkt::{ParseLimits, Wkt};
+    ///
+    /// let limits = ParseLimits {
+    ///     max_nesting_depth: 1,
+    ///     ..Default::default()
+    /// };
+    /// let wkt = "GEOMETRYCOLLECTION(GEOMETRYCOLLECTION(POINT(1 2)))";
+    /// let err = Wkt::<f64>::from_str_with_limits(wkt, &limits).unwrap_err();
+    /// assert!(matches!(err, ParseWktError::NestingTooDeep { limit: 1, offset: 37 }));
+    /// ```
+    pub fn from_str_with_limits(
+        wkt_str: &str,
+        limits: &ParseLimits,
+    ) -> Result<Self, ParseWktError> {
+        Wkt::from_bytes_at(wkt_str.as_bytes(), 0, limits)
+    }
+
+    /// Parse WKT as [`Wkt::from_str`] does, but accepting infinite and NaN ordinates.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// assert!(Wkt::<f64>::from_str("POINT(-inf 1)").is_err());
+    /// let wkt = Wkt::<f64>::from_str_allow_nonfinite("POINT(-inf 1)").unwrap();
+    /// assert_eq!(wkt.to_string(), "POINT(-inf 1)");
+    /// ```
+    pub fn from_str_allow_nonfinite(wkt_str: &str) -> Result<Self, ParseWktError> {
+        let limits = ParseLimits {
+            allow_nonfinite: true,
+            ..Default::default()
+        };
+        Wkt::from_str_with_limits(wkt_str, &limits)
+    }
+
+    /// Parse WKT straight from bytes, e.g. a buffer read from a file or socket, without first
+    /// checking that they are valid UTF-8.
+    ///
+    /// Anything other than ASCII can only appear in a word, which is rejected, so this accepts
+    /// exactly the inputs that [`Wkt::from_str`] does.
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_bytes(b"POINT Z(1 2 3)").unwrap();
+    /// assert_eq!(wkt.to_string(), "POINT Z(1 2 3)");
+    /// assert!(Wkt::<f64>::from_bytes(b"POINT\xff(1 2)").is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParseWktError> {
+        Wkt::from_bytes_at(bytes, 0, &ParseLimits::default())
+    }
+
+    /// Read all of `reader` and parse it as a single geometry, as [`Wkt::from_bytes`] does.
+    ///
+    /// This is the counterpart of [`TryFromWkt::try_from_wkt_reader`] for when the [`Wkt`] itself
+    /// is wanted, e.g. to convert it into types other than geo-types'. Errors from the reader are
+    /// returned as [`ReadError::Io`], and invalid WKT as [`ReadError::InvalidWkt`] with the line
+    /// the error is on.
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    ///
+    /// let fake_file = "LINESTRING(1 2,3 4)".as_bytes();
+    /// let wkt: Wkt<f64> = Wkt::from_reader(fake_file).unwrap();
+    /// assert_eq!(wkt.to_string(), "LINESTRING(1 2,3 4)");
+    /// ```
+    pub fn from_reader(mut reader: impl std::io::Read) -> Result<Self, ReadError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes)?;
+        Wkt::from_bytes(&bytes).map_err(|source| {
+            let before = &bytes[..source.offset().min(bytes.len())];
+            ReadError::InvalidWkt {
+                line: before.iter().filter(|&&byte| byte == b'\n').count() + 1,
+                source,
+            }
+        })
+    }
+
+    /// Parse the geometry starting at byte offset `start` of `bytes`, reporting error offsets
+    /// from the beginning of `bytes`.
+    pub(crate) fn from_bytes_at(
+        bytes: &[u8],
+        start: usize,
+        limits: &ParseLimits,
+    ) -> Result<Self, ParseWktError> {
+        let mut tokens =
+            PeekableTokens::new(Tokens::from_bytes_at(bytes, start)).with_limits(limits);
+        let wkt = Wkt::from_tokens(&mut tokens)?;
+        match tokens.peek() {
+            None => Ok(wkt),
+            Some(Ok(Token::ParenClose)) => {
+                tokens.next();
+                Err(tokens.unexpected_token_error("Unbalanced parentheses (unexpected ')')"))
+            }
+            Some(_) => {
+                tokens.next();
+                Err(tokens.trailing_tokens_error())
+            }
+        }
     }
 }
 
@@ -346,7 +725,7 @@ impl<T: WktNum> GeometryTrait for Wkt<T> {
     fn dim(&self) -> geo_traits::Dimensions {
         match self {
             Wkt::Point(geom) => PointTrait::dim(geom),
-            Wkt::LineString(geom) => LineStringTrait::dim(geom),
+            Wkt::LineString(geom) | Wkt::LinearRing(geom) => LineStringTrait::dim(geom),
             Wkt::Polygon(geom) => PolygonTrait::dim(geom),
             Wkt::MultiPoint(geom) => MultiPointTrait::dim(geom),
             Wkt::MultiLineString(geom) => MultiLineStringTrait::dim(geom),
@@ -372,7 +751,9 @@ impl<T: WktNum> GeometryTrait for Wkt<T> {
     > {
         match self {
             Wkt::Point(geom) => geo_traits::GeometryType::Point(geom),
-            Wkt::LineString(geom) => geo_traits::GeometryType::LineString(geom),
+            Wkt::LineString(geom) | Wkt::LinearRing(geom) => {
+                geo_traits::GeometryType::LineString(geom)
+            }
             Wkt::Polygon(geom) => geo_traits::GeometryType::Polygon(geom),
             Wkt::MultiPoint(geom) => geo_traits::GeometryType::MultiPoint(geom),
             Wkt::MultiLineString(geom) => geo_traits::GeometryType::MultiLineString(geom),
@@ -428,7 +809,7 @@ impl<T: WktNum> GeometryTrait for &Wkt<T> {
     fn dim(&self) -> geo_traits::Dimensions {
         match self {
             Wkt::Point(geom) => PointTrait::dim(geom),
-            Wkt::LineString(geom) => LineStringTrait::dim(geom),
+            Wkt::LineString(geom) | Wkt::LinearRing(geom) => LineStringTrait::dim(geom),
             Wkt::Polygon(geom) => PolygonTrait::dim(geom),
             Wkt::MultiPoint(geom) => MultiPointTrait::dim(geom),
             Wkt::MultiLineString(geom) => MultiLineStringTrait::dim(geom),
@@ -454,7 +835,9 @@ impl<T: WktNum> GeometryTrait for &Wkt<T> {
     > {
         match self {
             Wkt::Point(geom) => geo_traits::GeometryType::Point(geom),
-            Wkt::LineString(geom) => geo_traits::GeometryType::LineString(geom),
+            Wkt::LineString(geom) | Wkt::LinearRing(geom) => {
+                geo_traits::GeometryType::LineString(geom)
+            }
             Wkt::Polygon(geom) => geo_traits::GeometryType::Polygon(geom),
             Wkt::MultiPoint(geom) => geo_traits::GeometryType::MultiPoint(geom),
             Wkt::MultiLineString(geom) => geo_traits::GeometryType::MultiLineString(geom),
@@ -467,7 +850,7 @@ impl<T: WktNum> GeometryTrait for &Wkt<T> {
 // Specialized implementations on each WKT concrete type.
 
 macro_rules! impl_specialization {
-    ($geometry_type:ident) => {
+    ($geometry_type:ident, $geometry_trait:ident) => {
         impl<T: WktNum> GeometryTrait for $geometry_type<T> {
             type T = T;
             type PointType<'b>
@@ -512,7 +895,7 @@ macro_rules! impl_specialization {
                 Self: 'b;
 
             fn dim(&self) -> geo_traits::Dimensions {
-                geo_traits::Dimensions::Xy
+                $geometry_trait::dim(self)
             }
 
             fn as_type(
@@ -578,7 +961,7 @@ macro_rules! impl_specialization {
                 Self: 'b;
 
             fn dim(&self) -> geo_traits::Dimensions {
-                geo_traits::Dimensions::Xy
+                $geometry_trait::dim(*self)
             }
 
             fn as_type(
@@ -602,42 +985,76 @@ macro_rules! impl_specialization {
     };
 }
 
-impl_specialization!(Point);
-impl_specialization!(LineString);
-impl_specialization!(Polygon);
-impl_specialization!(MultiPoint);
-impl_specialization!(MultiLineString);
-impl_specialization!(MultiPolygon);
-impl_specialization!(GeometryCollection);
+impl_specialization!(Point, PointTrait);
+impl_specialization!(LineString, LineStringTrait);
+impl_specialization!(Polygon, PolygonTrait);
+impl_specialization!(MultiPoint, MultiPointTrait);
+impl_specialization!(MultiLineString, MultiLineStringTrait);
+impl_specialization!(MultiPolygon, MultiPolygonTrait);
+impl_specialization!(GeometryCollection, GeometryCollectionTrait);
 
 fn infer_geom_dimension<T: WktNum + FromStr + Default>(
     tokens: &mut PeekableTokens<T>,
-) -> Result<Dimension, &'static str> {
+) -> Result<Dimension, ParseWktError> {
     if let Some(Ok(c)) = tokens.peek() {
         match c {
             // If we match a word check if it's Z/M/ZM and consume the token from the stream
             Token::Word(w) => match w.as_str() {
-                w if w.eq_ignore_ascii_case("Z") => {
-                    tokens.next().unwrap().unwrap();
-                    Ok(Dimension::XYZ)
-                }
-                w if w.eq_ignore_ascii_case("M") => {
-                    tokens.next().unwrap().unwrap();
-
-                    Ok(Dimension::XYM)
-                }
-                w if w.eq_ignore_ascii_case("ZM") => {
-                    tokens.next().unwrap().unwrap();
-                    Ok(Dimension::XYZM)
-                }
                 w if w.eq_ignore_ascii_case("EMPTY") => Ok(Dimension::XY),
-                _ => Err("Unexpected word before open paren"),
+                w => match dimension_of_word(w) {
+                    Some(dim) => {
+                        tokens.next().unwrap().unwrap();
+                        check_after_dimension(tokens)?;
+                        Ok(dim)
+                    }
+                    None => {
+                        tokens.next();
+                        Err(tokens.unexpected_token_error("Unexpected word before open paren"))
+                    }
+                },
             },
-            // Not a word, e.g. an open paren
-            _ => Ok(Dimension::XY),
+            // Not a word, e.g. an open paren, so infer the dimension from the first coordinate
+            _ => Ok(match tokens.count_next_coord_ordinates() {
+                3 => Dimension::XYZ,
+                4 => Dimension::XYZM,
+                _ => Dimension::XY,
+            }),
         }
     } else {
-        Err("End of stream")
+        Err(tokens.unexpected_end_error("End of stream"))
+    }
+}
+
+fn dimension_of_word(word: &str) -> Option<Dimension> {
+    match word {
+        w if w.eq_ignore_ascii_case("Z") => Some(Dimension::XYZ),
+        w if w.eq_ignore_ascii_case("M") => Some(Dimension::XYM),
+        w if w.eq_ignore_ascii_case("ZM") => Some(Dimension::XYZM),
+        _ => None,
+    }
+}
+
+/// Check that an explicit dimension is followed by an open paren or `EMPTY`, so that e.g.
+/// `POINT Z M (1 2 3)` is rejected where the second dimension is rather than further on.
+fn check_after_dimension<T: WktNum + FromStr + Default>(
+    tokens: &mut PeekableTokens<T>,
+) -> Result<(), ParseWktError> {
+    match tokens.peek() {
+        Some(Ok(Token::ParenOpen)) => Ok(()),
+        Some(Ok(Token::Word(w))) if w.eq_ignore_ascii_case("EMPTY") => Ok(()),
+        Some(Ok(Token::Word(w))) if dimension_of_word(w).is_some() => {
+            tokens.next();
+            Err(tokens.unexpected_token_error("Unexpected dimension token"))
+        }
+        // Leave tokenizer errors to be returned where the token is consumed
+        Some(Err(_)) => Ok(()),
+        Some(Ok(_)) => {
+            tokens.next();
+            Err(tokens.unexpected_token_error("Missing open parenthesis or EMPTY after dimension"))
+        }
+        None => {
+            Err(tokens.unexpected_end_error("Missing open parenthesis or EMPTY after dimension"))
+        }
     }
 }
 
@@ -645,15 +1062,22 @@ trait FromTokens<T>: Sized + Default
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str>;
+    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, ParseWktError>;
+
+    /// The value for `EMPTY` in dimension `dim`. Only types which record their dimension need to
+    /// override this.
+    fn empty(_dim: Dimension) -> Self {
+        Default::default()
+    }
 
     /// The preferred top-level `FromTokens` API, which additionally checks for the presence of Z, M,
     /// and ZM in the token stream.
     fn from_tokens_with_header(
         tokens: &mut PeekableTokens<T>,
         dim: Option<Dimension>,
-    ) -> Result<Self, &'static str> {
+    ) -> Result<Self, ParseWktError> {
         let dim = if let Some(dim) = dim {
+            check_after_dimension(tokens)?;
             dim
         } else {
             infer_geom_dimension(tokens)?
@@ -664,64 +1088,107 @@ where
     fn from_tokens_with_parens(
         tokens: &mut PeekableTokens<T>,
         dim: Dimension,
-    ) -> Result<Self, &'static str> {
+    ) -> Result<Self, ParseWktError> {
         match tokens.next().transpose()? {
             Some(Token::ParenOpen) => (),
             Some(Token::Word(ref s)) if s.eq_ignore_ascii_case("EMPTY") => {
-                // Maybe create a DefaultXY, DefaultXYZ trait etc for each geometry type, and then
-                // here match on the dim to decide which default trait to use.
-                return Ok(Default::default());
+                return Ok(Self::empty(dim));
+            }
+            Some(_) => {
+                return Err(tokens.unexpected_token_error("Missing open parenthesis for type"))
             }
-            _ => return Err("Missing open parenthesis for type"),
+            None => return Err(tokens.unexpected_end_error("Missing open parenthesis for type")),
         };
-        let result = FromTokens::from_tokens(tokens, dim);
+        // Fail on the inner error first, so it isn't masked by the tokens following it
+        let result = FromTokens::from_tokens(tokens, dim)?;
         match tokens.next().transpose()? {
             Some(Token::ParenClose) => (),
-            _ => return Err("Missing closing parenthesis for type"),
+            None => return Err(tokens.unbalanced_parens_error()),
+            _ => return Err(tokens.unexpected_token_error("Missing closing parenthesis for type")),
         };
-        result
+        Ok(result)
     }
 
     fn from_tokens_with_optional_parens(
         tokens: &mut PeekableTokens<T>,
         dim: Dimension,
-    ) -> Result<Self, &'static str> {
+    ) -> Result<Self, ParseWktError> {
         match tokens.peek() {
             Some(Ok(Token::ParenOpen)) => Self::from_tokens_with_parens(tokens, dim),
+            Some(Ok(Token::Word(w))) if w.eq_ignore_ascii_case("EMPTY") => {
+                Self::from_tokens_with_parens(tokens, dim)
+            }
             _ => Self::from_tokens(tokens, dim),
         }
     }
 
+    /// Parse a comma separated sequence of items with `f`.
+    ///
+    /// This loops over the items rather than recursing, so arbitrarily long coordinate sequences
+    /// can be parsed without growing the stack. The items are collected into a `Vec` sized from
+    /// [`PeekableTokens::count_items_hint`], so long sequences aren't reallocated as they grow.
     fn comma_many<F>(
         f: F,
         tokens: &mut PeekableTokens<T>,
         dim: Dimension,
-    ) -> Result<Vec<Self>, &'static str>
+    ) -> Result<Vec<Self>, ParseWktError>
     where
-        F: Fn(&mut PeekableTokens<T>, Dimension) -> Result<Self, &'static str>,
+        F: Fn(&mut PeekableTokens<T>, Dimension) -> Result<Self, ParseWktError>,
     {
-        let mut items = Vec::new();
+        let mut items = Vec::with_capacity(tokens.count_items_hint());
+        // Track which item is being parsed, for errors about a coordinate
+        let outer_index = tokens.item_index();
 
+        tokens.set_item_index(0);
         let item = f(tokens, dim)?;
         items.push(item);
 
         while let Some(&Ok(Token::Comma)) = tokens.peek() {
             tokens.next(); // throw away comma
 
+            tokens.set_item_index(items.len());
             let item = f(tokens, dim)?;
             items.push(item);
         }
 
+        tokens.set_item_index(outer_index);
         Ok(items)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::types::{Coord, MultiPolygon, Point};
-    use crate::Wkt;
+    use crate::error::ParseWktError;
+    use crate::types::{Coord, Dimension, LineString, MultiPolygon, Point};
+    use crate::{ParseLimits, Wkt};
     use std::str::FromStr;
 
+    #[test]
+    fn signed_and_bare_decimal_numbers() {
+        let wkt: Wkt<f64> = Wkt::from_str("POINT (+1 .5)").unwrap();
+        assert_eq!(wkt, Wkt::from_str("POINT(1 0.5)").unwrap());
+
+        let wkt: Wkt<f64> = Wkt::from_str("LINESTRING(1. 2.,-.25 +3.)").unwrap();
+        assert_eq!(wkt, Wkt::from_str("LINESTRING(1 2,-0.25 3)").unwrap());
+
+        let wkt: Wkt<i32> = Wkt::from_str("POINT(+1 -2)").unwrap();
+        assert_eq!(wkt, Wkt::from_str("POINT(1 -2)").unwrap());
+
+        assert!(Wkt::<f64>::from_str("POINT(+-1 2)").is_err());
+    }
+
+    #[test]
+    fn long_linestring_is_allocated_once() {
+        let coords: Vec<String> = (0..50_000).map(|i| format!("{i} {i}")).collect();
+        let wkt = format!("LINESTRING({})", coords.join(","));
+        let Wkt::LineString(line_string) = Wkt::<f64>::from_str(&wkt).unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(line_string.0.len(), 50_000);
+        // Exactly the hint; growing by doubling from empty would have reached 65536
+        assert_eq!(line_string.0.capacity(), 50_000);
+    }
+
     #[test]
     fn empty_string() {
         let res: Result<Wkt<f64>, _> = Wkt::from_str("");
@@ -732,13 +1199,13 @@ mod tests {
     fn empty_items() {
         let wkt: Wkt<f64> = Wkt::from_str("POINT EMPTY").ok().unwrap();
         match wkt {
-            Wkt::Point(Point(None)) => (),
+            Wkt::Point(Point(None, _)) => (),
             _ => unreachable!(),
         };
 
         let wkt: Wkt<f64> = Wkt::from_str("MULTIPOLYGON EMPTY").ok().unwrap();
         match wkt {
-            Wkt::MultiPolygon(MultiPolygon(polygons)) => assert_eq!(polygons.len(), 0),
+            Wkt::MultiPolygon(MultiPolygon(polygons, _)) => assert_eq!(polygons.len(), 0),
             _ => unreachable!(),
         };
     }
@@ -747,18 +1214,433 @@ mod tests {
     fn lowercase_point() {
         let wkt: Wkt<f64> = Wkt::from_str("point EMPTY").ok().unwrap();
         match wkt {
-            Wkt::Point(Point(None)) => (),
+            Wkt::Point(Point(None, _)) => (),
             _ => unreachable!(),
         };
     }
 
     #[test]
     fn invalid_number() {
-        let msg = <Wkt<f64>>::from_str("POINT (10 20.1A)").unwrap_err();
+        let err = <Wkt<f64>>::from_str("POINT (10 20.1A)").unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::InvalidNumber {
+                found: "20.1A".to_string(),
+                offset: 10,
+            }
+        );
+        assert_eq!(
+            "Unable to parse input number '20.1A' at offset 10 as the desired output type",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn parse_from_bytes() {
+        let input = "GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING Z(+4 5 6,7 8 9))";
+        assert_eq!(
+            <Wkt<f64>>::from_bytes(input.as_bytes()).unwrap(),
+            <Wkt<f64>>::from_str(input).unwrap()
+        );
+
+        let err = <Wkt<f64>>::from_bytes(b"POINT (1 2\xff)").unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::InvalidNumber {
+                found: "2\u{fffd}".to_string(),
+                offset: 9,
+            }
+        );
+
+        let err = <Wkt<f64>>::from_bytes(b"\xc0\xc1 (1 2)").unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::UnexpectedToken {
+                expected: "Encountered non-ascii word",
+                found: "\u{fffd}\u{fffd}".to_string(),
+                offset: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_from_reader() {
+        use crate::error::ReadError;
+
+        let input = "MULTIPOINT Z((1 2 3),(4 5 6))";
+        let wkt = <Wkt<f64>>::from_reader(input.as_bytes()).unwrap();
+        assert_eq!(wkt, <Wkt<f64>>::from_str(input).unwrap());
+
+        // Invalid UTF-8 is a WKT error, as with `from_bytes`
+        let err = <Wkt<f64>>::from_reader(&b"POINT (1 2\xff)"[..]).unwrap_err();
+        assert!(matches!(
+            err,
+            ReadError::InvalidWkt {
+                line: 1,
+                source: ParseWktError::InvalidNumber { offset: 9, .. }
+            }
+        ));
+
+        let err = <Wkt<f64>>::from_reader("LINESTRING(\n1 2,\n3 x)".as_bytes()).unwrap_err();
+        assert!(matches!(err, ReadError::InvalidWkt { line: 3, .. }));
+
+        struct FailingReader;
+        impl std::io::Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disk on fire"))
+            }
+        }
+        let err = <Wkt<f64>>::from_reader(FailingReader).unwrap_err();
+        assert!(matches!(&err, ReadError::Io(io) if io.to_string() == "disk on fire"));
+    }
+
+    #[test]
+    fn unknown_geometry_type() {
+        let err = <Wkt<f64>>::from_str("CIRCLE (1 2 3)").unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::UnknownGeometryType {
+                name: "CIRCLE".to_string(),
+                offset: 0,
+            }
+        );
+
+        let err =
+            <Wkt<f64>>::from_str("GEOMETRYCOLLECTION (POINT (1 2), CIRCLE (1 2 3))").unwrap_err();
+        assert_eq!(err.offset(), 33);
+
+        let err = <Wkt<f64>>::from_str("POINT Z(1 2").unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::UnexpectedEndOfInput {
+                expected: "Expected a number for the Z coordinate",
+                offset: 11,
+            }
+        );
+    }
+
+    #[test]
+    fn untagged_dimension_from_coordinate_length() {
+        let wkt = <Wkt<f64>>::from_str("LINESTRING(+1 -2 +3, -4 +5 -6)").unwrap();
+        assert_eq!(wkt, Wkt::from_str("LINESTRING Z(1 -2 3,-4 5 -6)").unwrap());
+
+        let wkt = <Wkt<f64>>::from_str("POINT (+1 -2 +3 -4)").unwrap();
+        assert_eq!(wkt, Wkt::from_str("POINT ZM(1 -2 3 -4)").unwrap());
+
+        let wkt =
+            <Wkt<f64>>::from_str("MULTIPOLYGON (((+0 +0 -1,1 0 -1,+1 +1 -1,0 0 -1)))").unwrap();
+        assert_eq!(
+            wkt,
+            Wkt::from_str("MULTIPOLYGON Z(((0 0 -1,1 0 -1,1 1 -1,0 0 -1)))").unwrap()
+        );
+
+        let wkt =
+            <Wkt<f64>>::from_str("GEOMETRYCOLLECTION (POINT (-1 +2), POINT (1 -2 +3))").unwrap();
+        assert_eq!(
+            wkt,
+            Wkt::from_str("GEOMETRYCOLLECTION (POINT (-1 2), POINT Z(1 -2 3))").unwrap()
+        );
+
+        // Every coordinate must have as many ordinates as the first
+        assert!(<Wkt<f64>>::from_str("LINESTRING(+1 -2 +3, -4 +5)").is_err());
+        assert!(<Wkt<f64>>::from_str("LINESTRING(+1 -2, -4 +5 -6)").is_err());
+    }
+
+    #[test]
+    fn exponent_only_coordinates() {
+        let wkt = <Wkt<f64>>::from_str("POINT Z(1E6 2e-3 3)").unwrap();
+        assert_eq!(
+            wkt,
+            Wkt::Point(Point::new(Coord {
+                x: 1_000_000.0,
+                y: 0.002,
+                z: Some(3.0),
+                m: None,
+            }))
+        );
+
+        let wkt = <Wkt<f64>>::from_str("POINT (1.5e10 -2.3E-4 0)").unwrap();
+        assert_eq!(
+            wkt,
+            Wkt::Point(Point::new(Coord {
+                x: 1.5e10,
+                y: -2.3e-4,
+                z: Some(0.0),
+                m: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn unclosed_polygon() {
+        let err = <Wkt<f64>>::from_str("POLYGON Z((1 2 3,4 5 6,7 8 9,1 2 3)").unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::UnbalancedParentheses {
+                missing: 1,
+                offset: 35,
+            }
+        );
+
+        let err = <Wkt<f64>>::from_str("MULTIPOLYGON Z(((1 2 3,4 5 6,7 8 9,1 2 3)").unwrap_err();
+        assert_eq!(
+            "Unbalanced parentheses (expected 2 more ')') at offset 41",
+            err.to_string()
+        );
+
+        let input = format!("{}POINT(1 2)", "GEOMETRYCOLLECTION(".repeat(7));
+        let err = <Wkt<f64>>::from_str(&input).unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::UnbalancedParentheses {
+                missing: 7,
+                offset: input.len(),
+            }
+        );
+    }
+
+    #[test]
+    fn extra_closing_paren() {
+        let err = <Wkt<f64>>::from_str("POLYGON Z((1 2 3,4 5 6,7 8 9,1 2 3)))").unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::UnexpectedToken {
+                expected: "Unbalanced parentheses (unexpected ')')",
+                found: ")".to_string(),
+                offset: 36,
+            }
+        );
+    }
+
+    #[test]
+    fn inconsistent_dimension() {
+        for (input, expected, at_coord_index, offset) in [
+            ("LINESTRING Z(1 2 3, 4 5)", Dimension::XYZ, 1, 23),
+            ("LINESTRING(1 2,3 4 5,6 7)", Dimension::XY, 1, 19),
+            ("MULTIPOINT M((1 2 3),(4 5))", Dimension::XYM, 1, 25),
+            (
+                "POLYGON ZM((0 0 0 0,1 0 0,0 0 0 0))",
+                Dimension::XYZM,
+                1,
+                25,
+            ),
+            ("POINT Z(1 2)", Dimension::XYZ, 0, 11),
+            (
+                "GEOMETRYCOLLECTION(LINESTRING(1 2,3 4),POINT Z(5 6 7 8))",
+                Dimension::XYZ,
+                0,
+                53,
+            ),
+        ] {
+            assert_eq!(
+                <Wkt<f64>>::from_str(input).unwrap_err(),
+                ParseWktError::InconsistentDimension {
+                    expected,
+                    at_coord_index,
+                    offset,
+                },
+                "{input}"
+            );
+        }
+        let err = <Wkt<f64>>::from_str("LINESTRING Z(1 2 3, 4 5)").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Coordinate at index 1 doesn't match the XYZ dimension of its geometry at offset 23"
+        );
+    }
+
+    #[test]
+    fn trailing_tokens() {
+        let err = <Wkt<f64>>::from_str("POINT Z(1 2 3) EXTRA STUFF").unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::TrailingTokens {
+                found: "EXTRA STUFF".to_string(),
+                offset: 15,
+            }
+        );
+        for input in [
+            "POINT(1 2),POINT(3 4)",
+            "POINT EMPTY 5",
+            "LINESTRING(1 2,3 4)(",
+        ] {
+            assert!(
+                matches!(
+                    <Wkt<f64>>::from_str(input),
+                    Err(ParseWktError::TrailingTokens { .. })
+                ),
+                "{input}"
+            );
+        }
+        assert!(<Wkt<f64>>::from_str("POINT(1 2) \n\t").is_ok());
+
+        let (wkt, offset) = <Wkt<f64>>::parse_prefix("POINT Z(1 2 3) EXTRA STUFF").unwrap();
+        assert_eq!(wkt, <Wkt<f64>>::from_str("POINT Z(1 2 3)").unwrap());
+        assert_eq!(offset, 14);
+    }
+
+    #[test]
+    fn repeated_dimension_token() {
+        for (input, offset) in [
+            ("POINT Z M (1 2 3)", 8),
+            ("POINT ZM Z(1 2 3 4)", 9),
+            ("POINTZ Z(1 2 3)", 7),
+            ("LINESTRING M m(1 2 3,4 5 6)", 13),
+        ] {
+            let err = <Wkt<f64>>::from_str(input).unwrap_err();
+            assert_eq!(
+                err,
+                ParseWktError::UnexpectedToken {
+                    expected: "Unexpected dimension token",
+                    found: input[offset..offset + 1].to_string(),
+                    offset,
+                },
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn dimension_token_without_paren() {
+        let err = <Wkt<f64>>::from_str("POINT Z 1 2 3").unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::UnexpectedToken {
+                expected: "Missing open parenthesis or EMPTY after dimension",
+                found: "1".to_string(),
+                offset: 8,
+            }
+        );
+
+        let err = <Wkt<f64>>::from_str("POINT Z").unwrap_err();
         assert_eq!(
-            "Unable to parse input number as the desired output type",
-            msg
+            err,
+            ParseWktError::UnexpectedEndOfInput {
+                expected: "Missing open parenthesis or EMPTY after dimension",
+                offset: 7,
+            }
         );
+
+        assert!(<Wkt<f64>>::from_str("POINT Z EMPTY").is_ok());
+    }
+
+    #[test]
+    fn dangling_sign() {
+        let err = <Wkt<f64>>::from_str("POINT Z(- 1 2 3)").unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::UnexpectedToken {
+                expected: "Dangling sign not attached to a number",
+                found: "-".to_string(),
+                offset: 8,
+            }
+        );
+    }
+
+    #[test]
+    fn deeply_nested_collections() {
+        let depth = 10_000;
+        let input = format!(
+            "{}POINT(1 2){}",
+            "GEOMETRYCOLLECTION(".repeat(depth),
+            ")".repeat(depth)
+        );
+        let err = <Wkt<f64>>::from_str(&input).unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::NestingTooDeep {
+                limit: 32,
+                offset: 32 * 19 + 18,
+            }
+        );
+
+        let limits = ParseLimits {
+            max_nesting_depth: 3,
+            ..Default::default()
+        };
+        let nested = |depth: usize| {
+            format!(
+                "{}POINT(1 2){}",
+                "GEOMETRYCOLLECTION(".repeat(depth),
+                ")".repeat(depth)
+            )
+        };
+        assert!(Wkt::<f64>::from_str_with_limits(&nested(3), &limits).is_ok());
+        assert!(Wkt::<f64>::from_str_with_limits(&nested(4), &limits).is_err());
+    }
+
+    #[test]
+    fn nonfinite_ordinates() {
+        for (input, found, offset) in [
+            ("POINT Z(NaN 1 2)", "NaN", 8),
+            ("POINT Z(inf 1 2)", "inf", 8),
+            ("POINT(1 -Infinity)", "-Infinity", 8),
+            ("LINESTRING(0 0,+nan 1)", "+nan", 15),
+        ] {
+            assert_eq!(
+                <Wkt<f64>>::from_str(input).unwrap_err(),
+                ParseWktError::NonFiniteNumber {
+                    found: found.to_string(),
+                    offset,
+                },
+                "{input}"
+            );
+        }
+
+        let Wkt::Point(Point(Some(coord), _)) =
+            <Wkt<f64>>::from_str_allow_nonfinite("POINT Z(NaN -inf 2)").unwrap()
+        else {
+            unreachable!()
+        };
+        assert!(coord.x.is_nan());
+        assert_eq!(coord.y, f64::NEG_INFINITY);
+        assert_eq!(coord.z, Some(2.));
+    }
+
+    #[test]
+    fn error_offset_deep_in_multipolygon() {
+        let input = "MULTIPOLYGON (((0 0,10 0,10 10,0 0)),((20 20,30 20,30 3O,20 20)))";
+        let err = <Wkt<f64>>::from_str(input).unwrap_err();
+        assert_eq!(err.offset(), 54);
+        assert_eq!(&input[err.offset()..], "3O,20 20)))");
+        assert_eq!(
+            "Unable to parse input number '3O' at offset 54 as the desired output type",
+            err.to_string()
+        );
+
+        let input = "MULTIPOLYGON Z(((0 0 0,1 0 0,1 1 0,0 0 0)),((2 2 2,3 2 2,3 3,2 2 2)))";
+        let err = <Wkt<f64>>::from_str(input).unwrap_err();
+        assert_eq!(
+            err,
+            ParseWktError::InconsistentDimension {
+                expected: Dimension::XYZ,
+                at_coord_index: 2,
+                offset: 60,
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_str_and_string() {
+        let from_str: Wkt<f64> = "POINT Z(1 2 3)".try_into().unwrap();
+        let from_string = Wkt::<f64>::try_from(String::from("POINT Z(1 2 3)")).unwrap();
+        assert_eq!(from_str, from_string);
+        assert_eq!(from_str, Wkt::from_str("POINT Z(1 2 3)").unwrap());
+
+        let err = Wkt::<f64>::try_from("POINT Z(1 2").unwrap_err();
+        assert_eq!(Some(err), Wkt::<f64>::from_str("POINT Z(1 2").err());
+    }
+
+    #[test]
+    fn parse_prefix_followed_by_text() {
+        let input = "LINESTRING Z(1 2 3,4 5 6), POINT Z(7 8 9)";
+        let (wkt, offset) = <Wkt<f64>>::parse_prefix(input).unwrap();
+        assert_eq!(wkt, Wkt::from_str("LINESTRING Z(1 2 3,4 5 6)").unwrap());
+        assert_eq!(offset, 25);
+        assert_eq!(&input[offset..], ", POINT Z(7 8 9)");
+
+        let (_, offset) = <Wkt<f64>>::parse_prefix("POINT EMPTY rest").unwrap();
+        assert_eq!(offset, 11);
     }
 
     #[test]
@@ -766,10 +1648,10 @@ mod tests {
         // point(x, y, z)
         let wkt = <Wkt<f64>>::from_str("POINT Z (10 20.1 5)").ok().unwrap();
         match wkt {
-            Wkt::Point(Point(Some(coord))) => {
+            Wkt::Point(Point(Some(coord), _)) => {
                 assert_eq!(coord.x, 10.0);
                 assert_eq!(coord.y, 20.1);
-                assert_eq!(coord.z, 5.0);
+                assert_eq!(coord.z, Some(5.0));
             }
             _ => panic!("excepted to be parsed as a POINT"),
         }
@@ -777,10 +1659,10 @@ mod tests {
         // point(x, y, z)
         let wkt = <Wkt<f64>>::from_str("POINT Z (10 20.1 80)").ok().unwrap();
         match wkt {
-            Wkt::Point(Point(Some(coord))) => {
+            Wkt::Point(Point(Some(coord), _)) => {
                 assert_eq!(coord.x, 10.0);
                 assert_eq!(coord.y, 20.1);
-                assert_eq!(coord.z, 80.0);
+                assert_eq!(coord.z, Some(80.0));
             }
             _ => panic!("excepted to be parsed as a POINT"),
         }
@@ -790,10 +1672,10 @@ mod tests {
             .ok()
             .unwrap();
         match wkt {
-            Wkt::Point(Point(Some(coord))) => {
+            Wkt::Point(Point(Some(coord), _)) => {
                 assert_eq!(coord.x, 10.0);
                 assert_eq!(coord.y, 20.1);
-                assert_eq!(coord.z, 5.0);
+                assert_eq!(coord.z, Some(5.0));
             }
             _ => panic!("excepted to be parsed as a POINT"),
         }
@@ -808,23 +1690,114 @@ mod tests {
         };
     }
 
+    #[test]
+    fn linearring_round_trip() {
+        let limits = ParseLimits {
+            keep_linear_rings: true,
+            ..Default::default()
+        };
+        let wkt: Wkt<f64> =
+            Wkt::from_str_with_limits("linearring Z(10 20 30, 40 50 60)", &limits).unwrap();
+        assert!(matches!(wkt, Wkt::LinearRing(_)));
+
+        for input in [
+            "LINEARRING Z(10 20 30,40 50 60,10 20 30)",
+            "LINEARRING EMPTY",
+            "GEOMETRYCOLLECTION(LINEARRING(0 0,1 0,1 1,0 0),LINESTRING(1 2,3 4))",
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str_with_limits(input, &limits).unwrap();
+            assert_eq!(wkt.to_string(), input);
+        }
+
+        let wkt: Wkt<f64> =
+            Wkt::from_str_with_limits("LINEARRINGM(0 0 1,1 0 1,0 0 1)", &limits).unwrap();
+        assert_eq!(wkt.to_string(), "LINEARRING M(0 0 1,1 0 1,0 0 1)");
+        assert_eq!(wkt.dimensions(), geo_traits::Dimensions::Xym);
+    }
+
     #[test]
     fn test_debug() {
-        let g = Wkt::Point(Point(Some(Coord {
+        let g = Wkt::Point(Point::new(Coord {
             x: 1.0,
             y: 2.0,
-            z: 3.0,
-        })));
+            z: Some(3.0),
+            m: None,
+        }));
         assert_eq!(
             format!("{:?}", g),
-            "Point(Point(Some(Coord { x: 1.0, y: 2.0, z: 3.0 })))"
+            "Point(Point(Some(Coord { x: 1.0, y: 2.0, z: Some(3.0), m: None }), XYZ))"
         );
     }
 
+    #[test]
+    #[ignore = "allocates several hundred megabytes; run with `cargo test --release -- --ignored`"]
+    fn parse_huge_linestring() {
+        // 10 million coordinates take ~240MB once parsed as `f64`, plus the input string.
+        let count = 10_000_000;
+        let mut input = String::with_capacity(count * 16);
+        input.push_str("LINESTRING Z(");
+        for i in 0..count {
+            if i > 0 {
+                input.push(',');
+            }
+            input.push_str(&format!("{} {} 0", i % 1000, i / 1000));
+        }
+        input.push(')');
+
+        let wkt: Wkt<f64> = Wkt::from_str(&input).unwrap();
+        match wkt {
+            Wkt::LineString(ls) => {
+                assert_eq!(ls.0.len(), count);
+                assert_eq!(ls.0[count - 1].x, 999.0);
+                assert_eq!(ls.0[count - 1].y, 9999.0);
+            }
+            _ => unreachable!(),
+        }
+    }
+
     #[test]
     fn test_display_on_wkt() {
         let wktls: Wkt<f64> = Wkt::from_str("LINESTRING Z(10 20 30, 40 50 60)").unwrap();
 
         assert_eq!(wktls.to_string(), "LINESTRING Z(10 20 30,40 50 60)");
     }
+
+    /// A fixed-point number with three decimal places, standing in for a decimal type.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Milli(i64);
+
+    impl FromStr for Milli {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, ()> {
+            let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+            if fraction.len() > 3 {
+                return Err(());
+            }
+            let sign = if whole.starts_with('-') { -1 } else { 1 };
+            let whole: i64 = whole.parse().map_err(|_| ())?;
+            let fraction: i64 = format!("{fraction:0<3}").parse().map_err(|_| ())?;
+            Ok(Milli(whole * 1000 + sign * fraction))
+        }
+    }
+
+    #[test]
+    fn parse_fixed_point_coordinates() {
+        let wkt: Wkt<Milli> = Wkt::from_str("LINESTRING Z(1.5 -0.25 3,10 20.125 0)").unwrap();
+        let Wkt::LineString(LineString(coords, _)) = wkt else {
+            unreachable!()
+        };
+        assert_eq!(
+            coords[0],
+            Coord {
+                x: Milli(1500),
+                y: Milli(-250),
+                z: Some(Milli(3000)),
+                m: None,
+            }
+        );
+        assert_eq!(coords[1].y, Milli(20_125));
+
+        assert!(Wkt::<Milli>::from_str("POINT Z(0.0001 0 0)").is_err());
+    }
 }
@@ -0,0 +1,157 @@
+//! Parsing which keeps the original text of every number, so that writing reproduces it exactly.
+
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use geo_types::CoordNum;
+
+use crate::error::ParseWktError;
+use crate::tokenizer::{Token, Tokens};
+use crate::{Wkt, WktNum};
+
+/// A [`Wkt`] geometry along with the text it was parsed from.
+///
+/// Parse with [`FromStr`] rather than [`Wkt::from_str`] to keep the text. While the geometry is
+/// unchanged, the [`Display`] impl writes the input back byte for byte, including its keyword
+/// case, whitespace and the text of each ordinate, e.g. `1.100` rather than `1.1`.
+///
+/// Once the geometry is changed through [`geometry`](Self::geometry), it's written in the usual
+/// layout, but each ordinate which still has the value it was parsed with keeps its original
+/// text.
+///
+/// ```
+/// use wkt::WktWithText;
+///
+/// let input = "point z (1.100 2.0 3)";
+/// let parsed: WktWithText<f64> = input.parse().unwrap();
+/// assert_eq!(parsed.geometry.to_string(), "POINT Z(1.1 2 3)");
+/// assert_eq!(parsed.to_string(), input);
+/// ```
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Debug, PartialEq)]
+pub struct WktWithText<T: WktNum> {
+    pub geometry: Wkt<T>,
+    /// The input, as parsed.
+    input: String,
+    /// The geometry as written when parsed, to tell whether it has changed since.
+    written: String,
+    /// Each ordinate in document order, as written by this crate and as written in the input.
+    ordinates: Vec<(String, Range<usize>)>,
+}
+
+impl<T: WktNum> WktWithText<T> {
+    /// Discard the original text and return the geometry.
+    pub fn into_geometry(self) -> Wkt<T> {
+        self.geometry
+    }
+}
+
+impl<T> FromStr for WktWithText<T>
+where
+    T: WktNum + CoordNum + fmt::Display + FromStr + Default,
+{
+    type Err = ParseWktError;
+
+    fn from_str(wkt_str: &str) -> Result<Self, Self::Err> {
+        let geometry = Wkt::from_str(wkt_str)?;
+
+        // The input parsed, so every number token in it is an ordinate
+        let mut ordinates = Vec::new();
+        let mut tokens = Tokens::<T>::from_str(wkt_str);
+        while let Some(token) = tokens.next() {
+            if let Ok(Token::Number(value)) = token {
+                ordinates.push((value.to_string(), tokens.token_start()..tokens.offset()));
+            }
+        }
+
+        Ok(WktWithText {
+            written: geometry.to_string(),
+            geometry,
+            input: wkt_str.to_string(),
+            ordinates,
+        })
+    }
+}
+
+impl<T> fmt::Display for WktWithText<T>
+where
+    T: WktNum + CoordNum + fmt::Display + FromStr,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let written = self.geometry.to_string();
+        if written == self.written {
+            return f.write_str(&self.input);
+        }
+
+        // Swap each number for its original text if it's still written the same way. Comparing
+        // the written text rather than the values tells `-0` from `0`.
+        let mut ordinates = self.ordinates.iter();
+        let mut tokens = Tokens::<T>::from_str(&written);
+        let mut copied = 0;
+        while let Some(token) = tokens.next() {
+            if !matches!(token, Ok(Token::Number(_))) {
+                continue;
+            }
+            let number = &written[tokens.token_start()..tokens.offset()];
+            if let Some((_, span)) = ordinates.next().filter(|(parsed, _)| parsed == number) {
+                f.write_str(&written[copied..tokens.token_start()])?;
+                f.write_str(&self.input[span.clone()])?;
+                copied = tokens.offset();
+            }
+        }
+        f.write_str(&written[copied..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WktWithText;
+    use crate::types::Coord;
+    use crate::Wkt;
+
+    #[test]
+    fn round_trip_original_text() {
+        for input in [
+            "POINT Z(1.100 2.0 3)",
+            "LINESTRING(1e2 +2,3.50 -0.0)",
+            "MULTIPOLYGON ZM(((0.0 0 0 0,1 0 0 0,1 1.000 0 0,0 0 0 0)))",
+            "GEOMETRYCOLLECTION(POINT(01 2),POINT EMPTY)",
+            "POINT (1 2)",
+            "linestring z ( 1 2 3 , 4 5 6 )",
+            "MultiPoint((1 2), (3 4))",
+        ] {
+            let parsed: WktWithText<f64> = input.parse().unwrap();
+            assert_eq!(parsed.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn edited_ordinates_are_rewritten() {
+        let mut parsed: WktWithText<f64> = "LINESTRING Z(1.50 2.0 3,4.0 5 6)".parse().unwrap();
+        if let Wkt::LineString(line_string) = &mut parsed.geometry {
+            line_string.0[0] = Coord {
+                x: 7.25,
+                y: 2.,
+                z: Some(3.),
+                m: None,
+            };
+        }
+        assert_eq!(parsed.to_string(), "LINESTRING Z(7.25 2.0 3,4.0 5 6)");
+        assert_eq!(
+            parsed.into_geometry().to_string(),
+            "LINESTRING Z(7.25 2 3,4 5 6)"
+        );
+    }
+
+    #[test]
+    fn signed_zero_is_not_misattributed() {
+        let mut parsed: WktWithText<f64> = "LINESTRING(0.0 1,2 3)".parse().unwrap();
+        if let Wkt::LineString(line_string) = &mut parsed.geometry {
+            line_string.0[0].x = -0.;
+            line_string.0[1].x = 5.;
+        }
+        assert_eq!(parsed.to_string(), "LINESTRING(-0 1,5 3)");
+    }
+}
@@ -0,0 +1,138 @@
+//! Reading a sequence of geometries written one per line.
+
+use std::io::{self, BufRead};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::error::ReadError;
+use crate::{Wkt, WktNum};
+
+/// An iterator over the geometries in a reader holding one WKT geometry per line.
+///
+/// Lines are read one at a time, so files of any size can be processed without loading them into
+/// memory. Blank lines are skipped. A line which isn't valid WKT yields [`ReadError::InvalidWkt`],
+/// one which isn't UTF-8 yields [`ReadError::Io`], and either way reading carries on with the next
+/// line.
+///
+/// ```
+/// use wkt::read::WktReader;
+/// use wkt::Wkt;
+///
+/// let input = "POINT (1 2)\n\nLINESTRING (0 0, 1 1)\n";
+/// let geometries: Vec<Wkt<f64>> = WktReader::new(input.as_bytes())
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(geometries.len(), 2);
+/// assert_eq!(geometries[1].to_string(), "LINESTRING(0 0,1 1)");
+/// ```
+pub struct WktReader<R, T> {
+    reader: R,
+    line: Vec<u8>,
+    line_number: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<R: BufRead, T> WktReader<R, T> {
+    /// Read geometries from `reader`. Wrap unbuffered readers, such as a
+    /// [`File`](std::fs::File), in a [`BufReader`](std::io::BufReader).
+    pub fn new(reader: R) -> Self {
+        WktReader {
+            reader,
+            line: Vec::new(),
+            line_number: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Return the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R, T> Iterator for WktReader<R, T>
+where
+    R: BufRead,
+    T: WktNum + FromStr + Default,
+{
+    type Item = Result<Wkt<T>, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_until(b'\n', &mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => self.line_number += 1,
+                Err(err) => return Some(Err(err.into())),
+            }
+            // Count the line before decoding it, so that the lines after one which isn't UTF-8
+            // keep their numbers
+            let line = match std::str::from_utf8(&self.line) {
+                Ok(line) => line,
+                Err(err) => {
+                    return Some(Err(io::Error::new(io::ErrorKind::InvalidData, err).into()))
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            return Some(Wkt::from_str(line).map_err(|source| ReadError::InvalidWkt {
+                line: self.line_number,
+                source,
+            }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WktReader;
+    use crate::error::ReadError;
+    use crate::Wkt;
+    use std::str::FromStr;
+
+    #[test]
+    fn one_geometry_per_line() {
+        let input = "POINT (1 2)\nLINESTRING (0 0, 1 1)\n";
+        let geometries: Vec<Wkt<f64>> = WktReader::new(input.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            geometries,
+            [
+                Wkt::from_str("POINT(1 2)").unwrap(),
+                Wkt::from_str("LINESTRING(0 0,1 1)").unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn blank_lines_and_errors() {
+        let input = "\r\n  \nPOINT Z(1 2 3)\r\nPOINT(1\n\nPOINT EMPTY";
+        let results: Vec<Result<Wkt<f64>, ReadError>> = WktReader::new(input.as_bytes()).collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(matches!(
+            results[1],
+            Err(ReadError::InvalidWkt { line: 4, .. })
+        ));
+        // The parse error is the source, so isn't repeated in the message
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.to_string(), "Invalid WKT on line 4");
+        assert!(std::error::Error::source(err).is_some());
+        assert_eq!(results[2].as_ref().unwrap().to_string(), "POINT EMPTY");
+    }
+
+    #[test]
+    fn line_numbers_after_invalid_utf8() {
+        let input = b"POINT(1 2)\n\xffPOINT(3 4)\nPOINT(5\n";
+        let results: Vec<Result<Wkt<f64>, ReadError>> = WktReader::new(&input[..]).collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(ReadError::Io(_))));
+        assert!(matches!(
+            results[2],
+            Err(ReadError::InvalidWkt { line: 3, .. })
+        ));
+    }
+}
@@ -1,12 +1,15 @@
 use std::fmt;
 use std::fmt::Write;
 
-use geo_traits::to_geo::ToGeoRect;
+use crate::error::Error;
+use crate::types::{Coord, GeometryCollection};
+use crate::{Wkt, WktNum};
 use geo_traits::{
-    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, LineStringTrait, LineTrait, MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait, TriangleTrait
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
 };
-use crate::error::Error;
-use crate::WktNum;
+use geo_types::CoordNum;
 
 /// The physical size of the coordinate dimension
 ///
@@ -16,6 +19,7 @@ use crate::WktNum;
 enum PhysicalCoordinateDimension {
     Two,
     Three,
+    Four,
 }
 
 impl TryFrom<Dimensions> for PhysicalCoordinateDimension {
@@ -25,32 +29,150 @@ impl TryFrom<Dimensions> for PhysicalCoordinateDimension {
         match value.size() {
             2 => Ok(Self::Two),
             3 => Ok(Self::Three),
+            4 => Ok(Self::Four),
             _ => Err(Error::UnknownDimension),
         }
     }
 }
 
+/// Options controlling how geometries are written, for [`write_geometry_with_config`].
+///
+/// The default writes every ordinate of the input in full.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WriteConfig {
+    /// Write only the x and y ordinates, whatever the dimension of the input.
+    pub force_2d: bool,
+    /// Round each ordinate to this many decimal places, dropping trailing zeros. `None` writes
+    /// the shortest text which reads back as the same number.
+    pub precision: Option<usize>,
+    /// Whether to write e.g. `POINT Z` or `POINTZ`.
+    pub dimension_style: DimensionStyle,
+    /// Whether to write e.g. `POINT Z` or `point z`.
+    pub keyword_case: KeywordCase,
+    /// Whether to write e.g. `LINESTRING(1 2,3 4)` or `LINESTRING(1 2, 3 4)`.
+    pub coord_separator: CoordSeparator,
+    /// Whether to write e.g. `MULTIPOINT((1 2),(3 4))` or `MULTIPOINT(1 2,3 4)`.
+    pub multipoint_style: MultiPointStyle,
+}
+
+/// How the dimension tag is written after the geometry type, for [`WriteConfig`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DimensionStyle {
+    /// Separate the tag from the type with a space, e.g. `POINT Z(1 2 3)`.
+    #[default]
+    Spaced,
+    /// Join the tag to the type, e.g. `POINTZ(1 2 3)`.
+    Compact,
+}
+
+/// What separates coordinates, rings and members, for [`WriteConfig`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CoordSeparator {
+    /// A bare comma, e.g. `LINESTRING Z(10 20 30,40 50 60)`.
+    #[default]
+    Comma,
+    /// A comma and a space, e.g. `LINESTRING Z(10 20 30, 40 50 60)`.
+    CommaSpace,
+}
+
+/// How the points of a `MULTIPOINT` are written, for [`WriteConfig`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MultiPointStyle {
+    /// Wrap each point in parentheses, e.g. `MULTIPOINT Z((0 0 0),(4 0 -4))`.
+    #[default]
+    Parenthesized,
+    /// Write the points as a plain coordinate list, e.g. `MULTIPOINT Z(0 0 0,4 0 -4)`. Empty
+    /// points are still written as `EMPTY`.
+    Flat,
+}
+
+/// The case keywords such as `POINT`, `Z` and `EMPTY` are written in, for [`WriteConfig`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeywordCase {
+    /// e.g. `POINT Z EMPTY`
+    #[default]
+    Upper,
+    /// e.g. `point z empty`
+    Lower,
+}
+
+impl WriteConfig {
+    /// The dimension to write for a geometry of dimension `dim`.
+    fn dim(&self, dim: Dimensions) -> Dimensions {
+        if self.force_2d {
+            Dimensions::Xy
+        } else {
+            dim
+        }
+    }
+}
+
+/// Write the geometry type `keyword` followed by the tag for `dim`, e.g. `POINT Z`.
+fn write_prefix(
+    f: &mut impl Write,
+    keyword: &str,
+    dim: Dimensions,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let tag = match dim {
+        Dimensions::Xy | Dimensions::Unknown(2) => "",
+        Dimensions::Xyz | Dimensions::Unknown(3) => "Z",
+        Dimensions::Xym => "M",
+        Dimensions::Xyzm | Dimensions::Unknown(4) => "ZM",
+        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
+    };
+    write_keyword(f, keyword, config)?;
+    if !tag.is_empty() {
+        if config.dimension_style == DimensionStyle::Spaced {
+            f.write_char(' ')?;
+        }
+        write_keyword(f, tag, config)?;
+    }
+    Ok(())
+}
+
+/// Write the separator between items of a sequence chosen by `config`.
+fn write_separator(f: &mut impl Write, config: &WriteConfig) -> fmt::Result {
+    match config.coord_separator {
+        CoordSeparator::Comma => f.write_char(','),
+        CoordSeparator::CommaSpace => f.write_str(", "),
+    }
+}
+
+/// Write an uppercase `keyword` in the case chosen by `config`.
+fn write_keyword(f: &mut impl Write, keyword: &str, config: &WriteConfig) -> fmt::Result {
+    match config.keyword_case {
+        KeywordCase::Upper => f.write_str(keyword),
+        KeywordCase::Lower => keyword
+            .chars()
+            .try_for_each(|c| f.write_char(c.to_ascii_lowercase())),
+    }
+}
+
 /// Write an object implementing [`PointTrait`] to a WKT string.
 pub fn write_point<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     g: &impl PointTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = g.dim();
+    write_point_with_config(f, g, &WriteConfig::default())
+}
+
+pub(crate) fn write_point_with_config<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    g: &impl PointTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let dim = config.dim(g.dim());
     // Write prefix
-    match dim {
-        Dimensions::Xy | Dimensions::Unknown(2) => f.write_str("POINT"),
-        Dimensions::Xyz | Dimensions::Xym | Dimensions::Unknown(3) => f.write_str("POINT Z"),
-        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-        Dimensions::Xyzm => return Err(Error::UnknownDimension),
-    }?;
+    write_prefix(f, "POINT", dim, config)?;
     let size = dim.try_into()?;
     if let Some(coord) = g.coord() {
         f.write_char('(')?;
-        write_coord(f, &coord, size)?;
+        write_coord_with_config(f, &coord, size, config)?;
         f.write_char(')')?;
         Ok(())
     } else {
-        Ok(f.write_str(" EMPTY")?)
+        Ok(write_keyword(f, " EMPTY", config)?)
     }
 }
 
@@ -59,23 +181,32 @@ pub fn write_linestring<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     linestring: &impl LineStringTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = linestring.dim();
+    write_linestring_with_config(f, linestring, &WriteConfig::default())
+}
+
+pub(crate) fn write_linestring_with_config<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    linestring: &impl LineStringTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    write_linestring_as(f, "LINESTRING", linestring, config)
+}
+
+/// Write a line string with the type `keyword`, which is `LINEARRING` for linear rings.
+fn write_linestring_as<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    keyword: &str,
+    linestring: &impl LineStringTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let dim = config.dim(linestring.dim());
     // Write prefix
-    match dim {
-        Dimensions::Xy | Dimensions::Unknown(2) => {
-            f.write_str("LINESTRING")
-        }
-        Dimensions::Xyz | Dimensions::Xym | Dimensions::Unknown(3) => {
-            f.write_str("LINESTRING Z")
-        }
-        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-        Dimensions::Xyzm => return Err(Error::UnknownDimension),
-    }?;
+    write_prefix(f, keyword, dim, config)?;
     let size = dim.try_into()?;
     if linestring.num_coords() == 0 {
-        Ok(f.write_str(" EMPTY")?)
+        Ok(write_keyword(f, " EMPTY", config)?)
     } else {
-        write_coord_sequence(f, linestring.coords(), size)
+        write_coord_sequence(f, linestring.coords(), size, config)
     }
 }
 
@@ -84,33 +215,34 @@ pub fn write_polygon<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     polygon: &impl PolygonTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = polygon.dim();
+    write_polygon_with_config(f, polygon, &WriteConfig::default())
+}
+
+pub(crate) fn write_polygon_with_config<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    polygon: &impl PolygonTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let dim = config.dim(polygon.dim());
     // Write prefix
-    match dim {
-        Dimensions::Xy | Dimensions::Unknown(2) => f.write_str("POLYGON"),
-        Dimensions::Xyz | Dimensions::Xym | Dimensions::Unknown(3) => {
-            f.write_str("POLYGON Z")
-        }
-        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-        Dimensions::Xyzm => return Err(Error::UnknownDimension),
-    }?;
+    write_prefix(f, "POLYGON", dim, config)?;
     let size = dim.try_into()?;
     if let Some(exterior) = polygon.exterior() {
         if exterior.num_coords() != 0 {
             f.write_str("(")?;
-            write_coord_sequence(f, exterior.coords(), size)?;
+            write_coord_sequence(f, exterior.coords(), size, config)?;
 
             for interior in polygon.interiors() {
-                f.write_char(',')?;
-                write_coord_sequence(f, interior.coords(), size)?;
+                write_separator(f, config)?;
+                write_coord_sequence(f, interior.coords(), size, config)?;
             }
 
             Ok(f.write_char(')')?)
         } else {
-            Ok(f.write_str(" EMPTY")?)
+            Ok(write_keyword(f, " EMPTY", config)?)
         }
     } else {
-        Ok(f.write_str(" EMPTY")?)
+        Ok(write_keyword(f, " EMPTY", config)?)
     }
 }
 
@@ -119,18 +251,17 @@ pub fn write_multi_point<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     multipoint: &impl MultiPointTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = multipoint.dim();
+    write_multi_point_with_config(f, multipoint, &WriteConfig::default())
+}
+
+pub(crate) fn write_multi_point_with_config<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    multipoint: &impl MultiPointTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let dim = config.dim(multipoint.dim());
     // Write prefix
-    match dim {
-        Dimensions::Xy | Dimensions::Unknown(2) => {
-            f.write_str("MULTIPOINT")
-        }
-        Dimensions::Xyz | Dimensions::Xym | Dimensions::Unknown(3) => {
-            f.write_str("MULTIPOINT Z")
-        }
-        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-        Dimensions::Xyzm => return Err(Error::UnknownDimension),
-    }?;
+    write_prefix(f, "MULTIPOINT", dim, config)?;
     let size = dim.try_into()?;
 
     let mut points = multipoint.points();
@@ -138,191 +269,582 @@ pub fn write_multi_point<T: WktNum + fmt::Display>(
     // Note: This is largely copied from `write_coord_sequence`, because `multipoint.points()`
     // yields a sequence of Point, not Coord.
     if let Some(first_point) = points.next() {
-        f.write_str("((")?;
-
-        // Assume no empty points within this MultiPoint
-        write_coord(f, &first_point.coord().unwrap(), size)?;
+        f.write_char('(')?;
+        write_multi_point_member(f, &first_point, size, config)?;
 
         for point in points {
-            f.write_str("),(")?;
-            write_coord(f, &point.coord().unwrap(), size)?;
+            write_separator(f, config)?;
+            write_multi_point_member(f, &point, size, config)?;
         }
 
-        f.write_str("))")?;
+        f.write_char(')')?;
     } else {
-        f.write_str(" EMPTY")?;
+        write_keyword(f, " EMPTY", config)?;
     }
 
     Ok(())
 }
 
+fn write_multi_point_member<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    point: &impl PointTrait<T = T>,
+    size: PhysicalCoordinateDimension,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    match point.coord() {
+        Some(coord) if config.multipoint_style == MultiPointStyle::Flat => {
+            write_coord_with_config(f, &coord, size, config)
+        }
+        Some(coord) => {
+            f.write_char('(')?;
+            write_coord_with_config(f, &coord, size, config)?;
+            Ok(f.write_char(')')?)
+        }
+        None => Ok(write_keyword(f, "EMPTY", config)?),
+    }
+}
+
 /// Write an object implementing [`MultiLineStringTrait`] to a WKT string.
 pub fn write_multi_linestring<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     multilinestring: &impl MultiLineStringTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = multilinestring.dim();
+    write_multi_linestring_with_config(f, multilinestring, &WriteConfig::default())
+}
+
+pub(crate) fn write_multi_linestring_with_config<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    multilinestring: &impl MultiLineStringTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let dim = config.dim(multilinestring.dim());
     // Write prefix
-    match dim {
-        Dimensions::Xy | Dimensions::Unknown(2) => {
-            f.write_str("MULTILINESTRING")
-        }
-        Dimensions::Xyz | Dimensions::Xym | Dimensions::Unknown(3) => {
-            f.write_str("MULTILINESTRING Z")
-        }
-        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-        Dimensions::Xyzm => return Err(Error::UnknownDimension),
-    }?;
+    write_prefix(f, "MULTILINESTRING", dim, config)?;
     let size = dim.try_into()?;
     let mut line_strings = multilinestring.line_strings();
     if let Some(first_linestring) = line_strings.next() {
         f.write_str("(")?;
-        write_coord_sequence(f, first_linestring.coords(), size)?;
+        write_multi_linestring_member(f, &first_linestring, size, config)?;
 
         for linestring in line_strings {
-            f.write_char(',')?;
-            write_coord_sequence(f, linestring.coords(), size)?;
+            write_separator(f, config)?;
+            write_multi_linestring_member(f, &linestring, size, config)?;
         }
 
         f.write_char(')')?;
     } else {
-        f.write_str(" EMPTY")?;
+        write_keyword(f, " EMPTY", config)?;
     };
 
     Ok(())
 }
 
+fn write_multi_linestring_member<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    linestring: &impl LineStringTrait<T = T>,
+    size: PhysicalCoordinateDimension,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    if linestring.num_coords() == 0 {
+        Ok(write_keyword(f, "EMPTY", config)?)
+    } else {
+        write_coord_sequence(f, linestring.coords(), size, config)
+    }
+}
+
 /// Write an object implementing [`MultiPolygonTrait`] to a WKT string.
 pub fn write_multi_polygon<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     multipolygon: &impl MultiPolygonTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = multipolygon.dim();
+    write_multi_polygon_with_config(f, multipolygon, &WriteConfig::default())
+}
+
+pub(crate) fn write_multi_polygon_with_config<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    multipolygon: &impl MultiPolygonTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let dim = config.dim(multipolygon.dim());
     // Write prefix
-    match dim {
-        Dimensions::Xy | Dimensions::Unknown(2) => {
-            f.write_str("MULTIPOLYGON")
-        }
-        Dimensions::Xyz | Dimensions::Xym | Dimensions::Unknown(3) => {
-            f.write_str("MULTIPOLYGON Z")
-        }
-        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-        Dimensions::Xyzm => return Err(Error::UnknownDimension),
-    }?;
+    write_prefix(f, "MULTIPOLYGON", dim, config)?;
     let size = dim.try_into()?;
 
     let mut polygons = multipolygon.polygons();
 
     if let Some(first_polygon) = polygons.next() {
-        f.write_str("((")?;
-
-        write_coord_sequence(f, first_polygon.exterior().unwrap().coords(), size)?;
-        for interior in first_polygon.interiors() {
-            f.write_char(',')?;
-            write_coord_sequence(f, interior.coords(), size)?;
-        }
+        f.write_char('(')?;
+        write_multi_polygon_member(f, &first_polygon, size, config)?;
 
         for polygon in polygons {
-            f.write_str("),(")?;
-
-            write_coord_sequence(f, polygon.exterior().unwrap().coords(), size)?;
-            for interior in polygon.interiors() {
-                f.write_char(',')?;
-                write_coord_sequence(f, interior.coords(), size)?;
-            }
+            write_separator(f, config)?;
+            write_multi_polygon_member(f, &polygon, size, config)?;
         }
 
-        f.write_str("))")?;
+        f.write_char(')')?;
     } else {
-        f.write_str(" EMPTY")?;
+        write_keyword(f, " EMPTY", config)?;
     };
 
     Ok(())
 }
 
+fn write_multi_polygon_member<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    polygon: &impl PolygonTrait<T = T>,
+    size: PhysicalCoordinateDimension,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    match polygon.exterior() {
+        Some(exterior) if exterior.num_coords() != 0 => {
+            f.write_char('(')?;
+            write_coord_sequence(f, exterior.coords(), size, config)?;
+            for interior in polygon.interiors() {
+                write_separator(f, config)?;
+                write_coord_sequence(f, interior.coords(), size, config)?;
+            }
+            Ok(f.write_char(')')?)
+        }
+        _ => Ok(write_keyword(f, "EMPTY", config)?),
+    }
+}
+
 /// Write an object implementing [`GeometryTrait`] to a WKT string.
-pub fn write_geometry<T: WktNum + fmt::Display>(
+pub fn write_geometry<T: WktNum + CoordNum + fmt::Display>(
     f: &mut impl Write,
     geometry: &impl GeometryTrait<T = T>,
+) -> Result<(), Error> {
+    write_geometry_with_config(f, geometry, &WriteConfig::default())
+}
+
+/// Write an object implementing [`GeometryTrait`] to a WKT string, with the options in `config`.
+///
+/// A [`Wkt::LinearRing`] is a line string to [`GeometryTrait`], so is written as a `LINESTRING`;
+/// use [`write_wkt_with_config`] to keep its keyword.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wkt::to_wkt::{write_geometry_with_config, WriteConfig};
+/// use wkt::Wkt;
+///
+/// let wkt: Wkt<f64> = Wkt::from_str("POINT (1.123456789 2.0)").unwrap();
+/// let config = WriteConfig {
+///     precision: Some(3),
+///     ..Default::default()
+/// };
+/// let mut written = String::new();
+/// write_geometry_with_config(&mut written, &wkt, &config).unwrap();
+/// assert_eq!(written, "POINT(1.123 2)");
+/// ```
+pub fn write_geometry_with_config<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    geometry: &impl GeometryTrait<T = T>,
+    config: &WriteConfig,
 ) -> Result<(), Error> {
     match geometry.as_type() {
-        geo_traits::GeometryType::Point(point) => write_point(f, point),
-        geo_traits::GeometryType::LineString(linestring) => write_linestring(f, linestring),
-        geo_traits::GeometryType::Polygon(polygon) => write_polygon(f, polygon),
-        geo_traits::GeometryType::MultiPoint(multi_point) => write_multi_point(f, multi_point),
-        geo_traits::GeometryType::MultiLineString(mls) => write_multi_linestring(f, mls),
+        geo_traits::GeometryType::Point(point) => write_point_with_config(f, point, config),
+        geo_traits::GeometryType::LineString(linestring) => {
+            write_linestring_with_config(f, linestring, config)
+        }
+        geo_traits::GeometryType::Polygon(polygon) => write_polygon_with_config(f, polygon, config),
+        geo_traits::GeometryType::MultiPoint(multi_point) => {
+            write_multi_point_with_config(f, multi_point, config)
+        }
+        geo_traits::GeometryType::MultiLineString(mls) => {
+            write_multi_linestring_with_config(f, mls, config)
+        }
         geo_traits::GeometryType::MultiPolygon(multi_polygon) => {
-            write_multi_polygon(f, multi_polygon)
+            write_multi_polygon_with_config(f, multi_polygon, config)
+        }
+        geo_traits::GeometryType::GeometryCollection(gc) => {
+            write_geometry_collection_with_config(f, gc, config)
         }
-        geo_traits::GeometryType::GeometryCollection(gc) => write_geometry_collection(f, gc),
-        geo_traits::GeometryType::Rect(rect) => write_rect(f, rect),
-        geo_traits::GeometryType::Triangle(triangle) => write_triangle(f, triangle),
-        geo_traits::GeometryType::Line(line) => write_line(f, line),
+        geo_traits::GeometryType::Rect(rect) => write_rect_with_config(f, rect, config),
+        geo_traits::GeometryType::Triangle(triangle) => {
+            write_triangle_with_config(f, triangle, config)
+        }
+        geo_traits::GeometryType::Line(line) => write_line_with_config(f, line, config),
     }
 }
 
+/// Options for writing extended WKT, for [`write_ewkt_with_config`].
+///
+/// The default writes plain WKT with no SRID declaration.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EwktConfig {
+    /// The spatial reference identifier to declare with a leading `SRID=<srid>;`, if any.
+    pub srid: Option<u32>,
+    /// How to write the geometry after the declaration.
+    pub write_config: WriteConfig,
+}
+
+/// Write an object implementing [`GeometryTrait`] as extended WKT, which declares the SRID with a
+/// leading `SRID=<srid>;` as PostGIS does. Without an SRID this writes plain WKT.
+///
+/// As with [`write_geometry`], a [`Wkt::LinearRing`] is written as a `LINESTRING`;
+/// [`WktWithSrid::ewkt_string`](crate::WktWithSrid::ewkt_string) writes the same text but keeps
+/// its keyword, as [`write_wkt_with_config`] does.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wkt::to_wkt::write_ewkt;
+/// use wkt::Wkt;
+///
+/// let wkt: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+/// let mut ewkt = String::new();
+/// write_ewkt(&mut ewkt, &wkt, Some(4326)).unwrap();
+/// assert_eq!(ewkt, "SRID=4326;POINT Z(1 2 3)");
+/// ```
+pub fn write_ewkt<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    geometry: &impl GeometryTrait<T = T>,
+    srid: Option<u32>,
+) -> Result<(), Error> {
+    let config = EwktConfig {
+        srid,
+        ..Default::default()
+    };
+    write_ewkt_with_config(f, geometry, &config)
+}
+
+/// Write an object implementing [`GeometryTrait`] as extended WKT, as [`write_ewkt`] does, with
+/// the options in `config`.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wkt::to_wkt::{write_ewkt_with_config, EwktConfig, WriteConfig};
+/// use wkt::Wkt;
+///
+/// let wkt: Wkt<f64> = Wkt::from_str("POINT Z(1.26 2 3)").unwrap();
+/// let config = EwktConfig {
+///     srid: Some(4326),
+///     write_config: WriteConfig {
+///         force_2d: true,
+///         precision: Some(1),
+///         ..Default::default()
+///     },
+/// };
+/// let mut ewkt = String::new();
+/// write_ewkt_with_config(&mut ewkt, &wkt, &config).unwrap();
+/// assert_eq!(ewkt, "SRID=4326;POINT(1.3 2)");
+/// ```
+pub fn write_ewkt_with_config<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    geometry: &impl GeometryTrait<T = T>,
+    config: &EwktConfig,
+) -> Result<(), Error> {
+    write_srid(f, config.srid)?;
+    write_geometry_with_config(f, geometry, &config.write_config)
+}
+
+/// [`write_ewkt_with_config`] for a [`Wkt`], keeping the `LINEARRING` keyword of linear rings.
+pub(crate) fn write_wkt_as_ewkt<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    wkt: &Wkt<T>,
+    config: &EwktConfig,
+) -> Result<(), Error> {
+    write_srid(f, config.srid)?;
+    write_wkt_with_config(f, wkt, &config.write_config)
+}
+
+/// Write the `SRID=<srid>;` declaration of extended WKT, if there is an SRID.
+fn write_srid(f: &mut impl Write, srid: Option<u32>) -> Result<(), Error> {
+    if let Some(srid) = srid {
+        write!(f, "SRID={srid};")?;
+    }
+    Ok(())
+}
+
 /// Write an object implementing [`GeometryCollectionTrait`] to a WKT string.
-pub fn write_geometry_collection<T: WktNum + fmt::Display>(
+pub fn write_geometry_collection<T: WktNum + CoordNum + fmt::Display>(
     f: &mut impl Write,
     gc: &impl GeometryCollectionTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = gc.dim();
+    write_geometry_collection_with_config(f, gc, &WriteConfig::default())
+}
+
+pub(crate) fn write_geometry_collection_with_config<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    gc: &impl GeometryCollectionTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    write_collection_with(f, gc.dim(), gc.geometries(), config, |f, geom| {
+        write_geometry_with_config(f, geom, config)
+    })
+}
+
+/// Write each of `geometries` as a member of one `GEOMETRYCOLLECTION`, without first collecting
+/// them into a collection type.
+///
+/// The collection takes the dimension of the first geometry.
+///
+/// ```
+/// use geo_types::{point, line_string, Geometry};
+/// use wkt::to_wkt::geometries_to_collection_string;
+///
+/// let geometries: Vec<Geometry<f64>> = vec![
+///     point!(x: 1., y: 2., z: 3.).into(),
+///     line_string![(x: 0., y: 0., z: 0.), (x: 1., y: 1., z: 1.)].into(),
+/// ];
+/// assert_eq!(
+///     geometries_to_collection_string(geometries).unwrap(),
+///     "GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING Z(0 0 0,1 1 1))"
+/// );
+/// ```
+pub fn geometries_to_collection_string<T: WktNum + CoordNum + fmt::Display>(
+    geometries: impl IntoIterator<Item = impl GeometryTrait<T = T>>,
+) -> Result<String, Error> {
+    let mut geometries = geometries.into_iter().peekable();
+    let dim = geometries
+        .peek()
+        .map_or(Dimensions::Xy, |first| first.dim());
+    let config = WriteConfig::default();
+    let mut wkt_string = String::new();
+    write_collection_with(&mut wkt_string, dim, geometries, &config, |f, geom| {
+        write_geometry_with_config(f, geom, &config)
+    })?;
+    Ok(wkt_string)
+}
+
+/// Write a `GEOMETRYCOLLECTION` of dimension `dim`, writing each member with `write_member`.
+fn write_collection_with<W: Write, G>(
+    f: &mut W,
+    dim: Dimensions,
+    mut geometries: impl Iterator<Item = G>,
+    config: &WriteConfig,
+    mut write_member: impl FnMut(&mut W, &G) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let dim = config.dim(dim);
     // Write prefix
-    match dim {
-        Dimensions::Xy | Dimensions::Unknown(2) => {
-            f.write_str("GEOMETRYCOLLECTION")
-        }
-        Dimensions::Xyz | Dimensions::Xym | Dimensions::Unknown(3) => {
-            f.write_str("GEOMETRYCOLLECTION Z")
-        }
-        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-        Dimensions::Xyzm => return Err(Error::UnknownDimension),
-    }?;
-    let mut geometries = gc.geometries();
+    write_prefix(f, "GEOMETRYCOLLECTION", dim, config)?;
 
     if let Some(first_geometry) = geometries.next() {
         f.write_str("(")?;
 
-        write_geometry(f, &first_geometry)?;
+        write_member(f, &first_geometry)?;
         for geom in geometries {
-            f.write_char(',')?;
-            write_geometry(f, &geom)?;
+            write_separator(f, config)?;
+            write_member(f, &geom)?;
         }
 
         f.write_char(')')?;
     } else {
-        f.write_str(" EMPTY")?;
+        write_keyword(f, " EMPTY", config)?;
+    }
+    Ok(())
+}
+
+/// Write a [`Wkt`] with the options in `config`, keeping the `LINEARRING` keyword of linear rings.
+/// The other writers only see a linear ring through [`GeometryTrait`], so write it as a
+/// `LINESTRING`.
+///
+/// ```
+/// use wkt::to_wkt::{write_geometry_with_config, write_wkt_with_config, WriteConfig};
+/// use wkt::{ParseLimits, Wkt};
+///
+/// let limits = ParseLimits {
+///     keep_linear_rings: true,
+///     ..Default::default()
+/// };
+/// let wkt: Wkt<f64> = Wkt::from_str_with_limits("LINEARRING(0 0,1 0,0 0)", &limits).unwrap();
+/// let mut written = String::new();
+/// write_wkt_with_config(&mut written, &wkt, &WriteConfig::default()).unwrap();
+/// assert_eq!(written, "LINEARRING(0 0,1 0,0 0)");
+///
+/// let mut written = String::new();
+/// write_geometry_with_config(&mut written, &wkt, &WriteConfig::default()).unwrap();
+/// assert_eq!(written, "LINESTRING(0 0,1 0,0 0)");
+/// ```
+pub fn write_wkt_with_config<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    wkt: &Wkt<T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    match wkt {
+        Wkt::LinearRing(ring) => write_linestring_as(f, "LINEARRING", ring, config),
+        Wkt::GeometryCollection(gc) => write_wkt_collection_with_config(f, gc, config),
+        other => write_geometry_with_config(f, other, config),
+    }
+}
+
+/// Write a [`GeometryCollection`], keeping the `LINEARRING` keyword of members which are linear
+/// rings, as [`write_wkt_with_config`] does.
+pub(crate) fn write_wkt_collection_with_config<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    gc: &GeometryCollection<T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let dim = GeometryCollectionTrait::dim(gc);
+    write_collection_with(f, dim, gc.0.iter(), config, |f, geom| {
+        write_wkt_with_config(f, geom, config)
+    })
+}
+
+/// Write an object implementing [`GeometryTrait`] to a WKT string, putting each member of a
+/// `GEOMETRYCOLLECTION` on its own line, indented by `indent` spaces for each level of nesting.
+///
+/// Other geometries are written on one line as [`write_geometry`] does, so this only changes the
+/// layout of collections.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wkt::to_wkt::write_geometry_pretty;
+/// use wkt::Wkt;
+///
+/// let wkt: Wkt<f64> =
+///     Wkt::from_str("GEOMETRYCOLLECTION(POINT(1 2),LINESTRING(3 4,5 6))").unwrap();
+/// let mut written = String::new();
+/// write_geometry_pretty(&mut written, &wkt, 4).unwrap();
+/// assert_eq!(
+///     written,
+///     "GEOMETRYCOLLECTION(\n    POINT(1 2),\n    LINESTRING(3 4,5 6)\n)"
+/// );
+/// ```
+pub fn write_geometry_pretty<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    geometry: &impl GeometryTrait<T = T>,
+    indent: usize,
+) -> Result<(), Error> {
+    write_geometry_pretty_at(f, geometry, indent, 0)
+}
+
+fn write_geometry_pretty_at<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    geometry: &impl GeometryTrait<T = T>,
+    indent: usize,
+    depth: usize,
+) -> Result<(), Error> {
+    match geometry.as_type() {
+        geo_traits::GeometryType::GeometryCollection(gc) => {
+            write_pretty_collection(f, gc.dim(), gc.geometries(), indent, depth, |f, geom| {
+                write_geometry_pretty_at(f, geom, indent, depth + 1)
+            })
+        }
+        _ => write_geometry(f, geometry),
+    }
+}
+
+/// [`write_geometry_pretty`] for a [`Wkt`], keeping the `LINEARRING` keyword of linear rings.
+pub(crate) fn write_wkt_pretty<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    wkt: &Wkt<T>,
+    indent: usize,
+    depth: usize,
+) -> Result<(), Error> {
+    match wkt {
+        Wkt::GeometryCollection(gc) => {
+            let dim = GeometryCollectionTrait::dim(gc);
+            write_pretty_collection(f, dim, gc.0.iter(), indent, depth, |f, geom| {
+                write_wkt_pretty(f, geom, indent, depth + 1)
+            })
+        }
+        other => write_wkt_with_config(f, other, &WriteConfig::default()),
+    }
+}
+
+/// Write a `GEOMETRYCOLLECTION` at nesting `depth` with each member on its own line, writing each
+/// member with `write_member`.
+fn write_pretty_collection<W: Write, G>(
+    f: &mut W,
+    dim: Dimensions,
+    geometries: impl Iterator<Item = G>,
+    indent: usize,
+    depth: usize,
+    mut write_member: impl FnMut(&mut W, &G) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let config = WriteConfig::default();
+    write_prefix(f, "GEOMETRYCOLLECTION", dim, &config)?;
+
+    let mut geometries = geometries.peekable();
+    if geometries.peek().is_none() {
+        return Ok(write_keyword(f, " EMPTY", &config)?);
     }
+    f.write_char('(')?;
+    while let Some(geom) = geometries.next() {
+        write!(f, "\n{:width$}", "", width = indent * (depth + 1))?;
+        write_member(f, &geom)?;
+        if geometries.peek().is_some() {
+            f.write_char(',')?;
+        }
+    }
+    write!(f, "\n{:width$})", "", width = indent * depth)?;
     Ok(())
 }
 
 /// Write an object implementing [`RectTrait`] to a WKT string.
 ///
-/// The Rect will written as a Polygon with one exterior ring.
+/// The Rect will written as a Polygon with one exterior ring: the closed ring of its four
+/// corners, counter-clockwise from the minimum,
+/// `(minx miny, maxx miny, maxx maxy, minx maxy, minx miny)`. A 3D `Rect` whose minimum and
+/// maximum z are equal has that z on every corner. A 3D `Rect` with depth is written as the box:
+/// the ring of its bottom face at the minimum z, then the ring of its top face at the maximum z,
+/// then back down to the first corner, 11 coordinates in all. The box's corners don't lie in one
+/// plane, so convert it yourself if you need a valid polygon.
 ///
-/// Note that only 2D `Rect`s are supported, because it's unclear how to map a higher-dimensional
-/// Rect to a Polygon. For higher dimensional `Rect`, transform your data to a Polygon and use
+/// Measured and 4D `Rect`s aren't supported; transform your data to a Polygon and use
 /// [`write_polygon`].
-pub fn write_rect<T: WktNum + fmt::Display>(
+pub fn write_rect<T: WktNum + CoordNum + fmt::Display>(
     f: &mut impl Write,
-    rect: &(impl RectTrait<T = T> + ToGeoRect<T>),
+    rect: &impl RectTrait<T = T>,
 ) -> Result<(), Error> {
-    // Write prefix 3D
-    match &rect.dim() {
-        Dimensions::Xy | Dimensions::Unknown(2) => f.write_str("POLYGON"),
-        Dimensions::Xyz | Dimensions::Xym | Dimensions::Unknown(3) => f.write_str("POLYGON Z"),
-        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-        Dimensions::Xyzm => return Err(Error::UnknownDimension),
-    }?;
+    write_rect_with_config(f, rect, &WriteConfig::default())
+}
 
-    // We need to construct the points of the rect that make up the exterior Polygon
-    let coords = rect.to_rect().to_coords();
+pub(crate) fn write_rect_with_config<T: WktNum + CoordNum + fmt::Display>(
+    f: &mut impl Write,
+    rect: &impl RectTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let dim = match config.dim(rect.dim()) {
+        Dimensions::Xy | Dimensions::Unknown(2) => Dimensions::Xy,
+        Dimensions::Xyz | Dimensions::Unknown(3) => Dimensions::Xyz,
+        Dimensions::Xym | Dimensions::Xyzm | Dimensions::Unknown(_) => {
+            return Err(Error::UnknownDimension)
+        }
+    };
+    let ring = rect_ring(rect, dim)?;
+    write_prefix(f, "POLYGON", dim, config)?;
 
     f.write_str("(")?;
-    write_coord_sequence(f, coords.iter(), PhysicalCoordinateDimension::Three)?;
+    let size = if dim == Dimensions::Xy {
+        PhysicalCoordinateDimension::Two
+    } else {
+        PhysicalCoordinateDimension::Three
+    };
+    write_coord_sequence(f, ring.iter(), size, config)?;
     Ok(f.write_char(')')?)
 }
 
+/// The closed ring of the corners of a rect, counter-clockwise from its minimum corner, in `dim`,
+/// as [`write_rect`] documents: 5 coordinates for a rect without depth and 11 for a box.
+pub(crate) fn rect_ring<T: WktNum>(
+    rect: &impl RectTrait<T = T>,
+    dim: Dimensions,
+) -> Result<Vec<Coord<T>>, Error> {
+    let (min, max) = (rect.min(), rect.max());
+    let face = |z| {
+        let corner = |x, y| Coord { x, y, z, m: None };
+        [
+            corner(min.x(), min.y()),
+            corner(max.x(), min.y()),
+            corner(max.x(), max.y()),
+            corner(min.x(), max.y()),
+            corner(min.x(), min.y()),
+        ]
+    };
+    if dim == Dimensions::Xy {
+        return Ok(face(None).to_vec());
+    }
+    let min_z = min.nth(2).ok_or(Error::UnknownDimension)?;
+    let max_z = max.nth(2).ok_or(Error::UnknownDimension)?;
+    let mut ring = face(Some(min_z)).to_vec();
+    if max_z != min_z {
+        ring.extend(face(Some(max_z)));
+        ring.push(ring[0].clone());
+    }
+    Ok(ring)
+}
+
 /// Write an object implementing [`TriangleTrait`] to a WKT string.
 ///
 /// The Triangle will written as a Polygon with one exterior ring.
@@ -330,19 +852,17 @@ pub fn write_triangle<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     triangle: &impl TriangleTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = triangle.dim();
+    write_triangle_with_config(f, triangle, &WriteConfig::default())
+}
+
+pub(crate) fn write_triangle_with_config<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    triangle: &impl TriangleTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let dim = config.dim(triangle.dim());
     // Write prefix
-    match dim {
-        Dimensions::Xy | Dimensions::Unknown(2) => f.write_str("POLYGON"),
-        Dimensions::Xyz | Dimensions::Unknown(3) => {
-            f.write_str("POLYGON Z")
-        }
-        Dimensions::Xym => f.write_str("POLYGON M"),
-        Dimensions::Xyzm | Dimensions::Unknown(4) => {
-            f.write_str("POLYGON ZM")
-        }
-        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-    }?;
+    write_prefix(f, "POLYGON", dim, config)?;
     let size = dim.try_into()?;
     f.write_str("(")?;
 
@@ -350,7 +870,7 @@ pub fn write_triangle<T: WktNum + fmt::Display>(
         .coords()
         .into_iter()
         .chain(std::iter::once(triangle.first()));
-    write_coord_sequence(f, coords_iter, size)?;
+    write_coord_sequence(f, coords_iter, size, config)?;
 
     Ok(f.write_char(')')?)
 }
@@ -362,38 +882,118 @@ pub fn write_line<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     line: &impl LineTrait<T = T>,
 ) -> Result<(), Error> {
-    let dim = line.dim();
-    // Write prefix
-    match dim {
-        Dimensions::Xy | Dimensions::Unknown(2) => {
-            f.write_str("LINESTRING")
-        }
-        Dimensions::Xyz | Dimensions::Xym | Dimensions::Unknown(3) => {
-            f.write_str("LINESTRING Z")
-        }
-        Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
-        Dimensions::Xyzm => return Err(Error::UnknownDimension),
-    }?;
+    write_line_with_config(f, line, &WriteConfig::default())
+}
+
+pub(crate) fn write_line_with_config<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    line: &impl LineTrait<T = T>,
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    let dim = config.dim(line.dim());
+    write_prefix(f, "LINESTRING", dim, config)?;
     let size = dim.try_into()?;
-    write_coord_sequence(f, line.coords().into_iter(), size)
+    write_coord_sequence(f, line.coords().into_iter(), size, config)
+}
+
+/// Write a single coordinate with `dim` ordinates, as it would appear inside a geometry.
+///
+/// Ordinates are separated by single spaces and written with the same number formatting as the
+/// rest of this module, without any surrounding `()` and without allocating. Use this to build
+/// WKT fragments by hand that match the crate's own output. Returns
+/// [`Error::MissingOrdinate`] if `dim` has more ordinates than `coord`.
+///
+/// ```
+/// use geo_traits::Dimensions;
+/// use wkt::to_wkt::write_coord;
+/// use wkt::types::Coord;
+///
+/// let coord = Coord { x: 1.5, y: 2., z: Some(-3.), m: None };
+/// let mut written = String::new();
+/// write_coord(&mut written, &coord, Dimensions::Xyz).unwrap();
+/// assert_eq!(written, "1.5 2 -3");
+/// ```
+pub fn write_coord<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    coord: &impl CoordTrait<T = T>,
+    dim: Dimensions,
+) -> Result<(), Error> {
+    let size = PhysicalCoordinateDimension::try_from(dim)?;
+    write_coord_with_config(f, coord, size, &WriteConfig::default())
 }
 
 /// Write a single coordinate to the writer.
 ///
-/// Will not include any start or end `()` characters.
-fn write_coord<T: WktNum + fmt::Display>(
+/// Will not include any start or end `()` characters. Returns [`Error::MissingOrdinate`] if the
+/// coordinate has fewer ordinates than `size`.
+fn write_coord_with_config<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     coord: &impl CoordTrait<T = T>,
     size: PhysicalCoordinateDimension,
-) -> Result<(), std::fmt::Error> {
+    config: &WriteConfig,
+) -> Result<(), Error> {
+    if let Some(precision) = config.precision {
+        return write_rounded_coord(f, coord, size, precision);
+    }
     match size {
-        PhysicalCoordinateDimension::Two => write!(f, "{} {}", coord.x(), coord.y()),
+        PhysicalCoordinateDimension::Two => write!(f, "{} {}", coord.x(), coord.y())?,
         PhysicalCoordinateDimension::Three => {
-            // Safety:
-            // We've validated that there are three dimensions
-            write!(f, "{} {} {}", coord.x(), coord.y(), coord.z())
-        },
+            write!(f, "{} {} {}", coord.x(), coord.y(), nth_ordinate(coord, 2)?)?
+        }
+        PhysicalCoordinateDimension::Four => write!(
+            f,
+            "{} {} {} {}",
+            coord.x(),
+            coord.y(),
+            nth_ordinate(coord, 2)?,
+            nth_ordinate(coord, 3)?
+        )?,
     }
+    Ok(())
+}
+
+fn write_rounded_coord<T: WktNum + fmt::Display>(
+    f: &mut impl Write,
+    coord: &impl CoordTrait<T = T>,
+    size: PhysicalCoordinateDimension,
+    precision: usize,
+) -> Result<(), Error> {
+    let len = match size {
+        PhysicalCoordinateDimension::Two => 2,
+        PhysicalCoordinateDimension::Three => 3,
+        PhysicalCoordinateDimension::Four => 4,
+    };
+    for n in 0..len {
+        if n > 0 {
+            f.write_char(' ')?;
+        }
+        write_ordinate(f, nth_ordinate(coord, n)?, precision)?;
+    }
+    Ok(())
+}
+
+/// The `n`th ordinate of `coord`, or an error if it has fewer than `n + 1`, e.g. when a 2D
+/// coordinate is written with a 3D dimension.
+fn nth_ordinate<T: WktNum>(coord: &impl CoordTrait<T = T>, n: usize) -> Result<T, Error> {
+    coord.nth(n).ok_or(Error::MissingOrdinate(n))
+}
+
+/// Write `value` rounded to `precision` decimal places, without trailing zeros or a negative
+/// sign on zero.
+fn write_ordinate<T: fmt::Display>(
+    f: &mut impl Write,
+    value: T,
+    precision: usize,
+) -> Result<(), std::fmt::Error> {
+    let mut text = format!("{value:.precision$}");
+    if text.contains('.') {
+        let trimmed_len = text.trim_end_matches('0').trim_end_matches('.').len();
+        text.truncate(trimmed_len);
+    }
+    if text == "-0" {
+        text.remove(0);
+    }
+    f.write_str(&text)
 }
 
 /// Includes the `()` characters to start and end this sequence.
@@ -407,15 +1007,16 @@ fn write_coord_sequence<T: WktNum + fmt::Display>(
     f: &mut impl Write,
     mut coords: impl Iterator<Item = impl CoordTrait<T = T>>,
     size: PhysicalCoordinateDimension,
+    config: &WriteConfig,
 ) -> Result<(), Error> {
     f.write_char('(')?;
 
     if let Some(first_coord) = coords.next() {
-        write_coord(f, &first_coord, size)?;
+        write_coord_with_config(f, &first_coord, size, config)?;
 
         for coord in coords {
-            f.write_char(',')?;
-            write_coord(f, &coord, size)?;
+            write_separator(f, config)?;
+            write_coord_with_config(f, &coord, size, config)?;
         }
     }
 
@@ -1,14 +1,24 @@
 //! Serialize geometries to WKT strings.
 
+use geo_traits::GeometryTrait;
+use geo_types::CoordNum;
+
 use crate::{Wkt, WktNum};
 
 mod geo_trait_impl;
+mod wkb;
 
 pub use geo_trait_impl::{
-    write_geometry, write_geometry_collection, write_line, write_linestring,
-    write_multi_linestring, write_multi_point, write_multi_polygon, write_point, write_polygon,
-    write_rect, write_triangle,
+    geometries_to_collection_string, write_coord, write_ewkt, write_ewkt_with_config,
+    write_geometry, write_geometry_collection, write_geometry_pretty, write_geometry_with_config,
+    write_line, write_linestring, write_multi_linestring, write_multi_point, write_multi_polygon,
+    write_point, write_polygon, write_rect, write_triangle, write_wkt_with_config, CoordSeparator,
+    DimensionStyle, EwktConfig, KeywordCase, MultiPointStyle, WriteConfig,
+};
+pub(crate) use geo_trait_impl::{
+    rect_ring, write_wkt_as_ewkt, write_wkt_collection_with_config, write_wkt_pretty,
 };
+pub use wkb::write_wkb;
 
 use crate::error::Error;
 use std::io;
@@ -31,7 +41,7 @@ impl<W: io::Write> WriterWrapper<W> {
 
 impl<W: io::Write> std::fmt::Write for WriterWrapper<W> {
     fn write_str(&mut self, s: &str) -> std::fmt::Result {
-        self.writer.write(s.as_bytes()).map_err(|err| {
+        self.writer.write_all(s.as_bytes()).map_err(|err| {
             self.most_recent_err = Some(err);
             std::fmt::Error
         })?;
@@ -42,7 +52,7 @@ impl<W: io::Write> std::fmt::Write for WriterWrapper<W> {
 /// A trait for converting values to WKT
 pub trait ToWkt<T>
 where
-    T: WktNum + std::fmt::Display,
+    T: WktNum + CoordNum + std::fmt::Display,
 {
     /// Converts the value of `self` to an [`Wkt`] struct.
     ///
@@ -62,6 +72,10 @@ where
     }
 
     /// Write a WKT string to a [`File`](std::fs::File), or anything else that implements [`Write`](std::io::Write).
+    ///
+    /// The text is passed to `writer` piece by piece as it's produced, so even a very large
+    /// geometry collection is never held in memory as a whole string. Wrap unbuffered writers in a
+    /// [`BufWriter`](std::io::BufWriter) to avoid many small writes.
     /// ```
     /// // This example requires the geo-types feature (on by default).
     /// use wkt::ToWkt;
@@ -79,17 +93,152 @@ where
     /// assert_eq!(wkt_string, "POINT Z(1.2 3.4 7.5)");
     /// ```
     fn write_wkt(&self, writer: impl io::Write) -> io::Result<()> {
-        let mut writer_wrapper = WriterWrapper::new(writer);
-        write_geometry(&mut writer_wrapper, &self.to_wkt()).map_err(|err| {
-            match (err, writer_wrapper.most_recent_err) {
-                (Error::FmtError(_), Some(io_err)) => io_err,
-                (Error::FmtError(fmt_err), None) => {
-                    debug_assert!(false, "FmtError without setting an error on WriterWrapper");
-                    io::Error::other(fmt_err.to_string())
-                }
-                (other, _) => io::Error::other(other.to_string()),
+        write_wkt_to_io(writer, &self.to_wkt())
+    }
+}
+
+fn write_wkt_to_io<T>(writer: impl io::Write, wkt: &Wkt<T>) -> io::Result<()>
+where
+    T: WktNum + CoordNum + std::fmt::Display,
+{
+    let mut writer_wrapper = WriterWrapper::new(writer);
+    write_wkt_with_config(&mut writer_wrapper, wkt, &WriteConfig::default()).map_err(|err| {
+        match (err, writer_wrapper.most_recent_err) {
+            (Error::FmtError(_), Some(io_err)) => io_err,
+            (Error::FmtError(fmt_err), None) => {
+                debug_assert!(false, "FmtError without setting an error on WriterWrapper");
+                io::Error::other(fmt_err.to_string())
             }
-        })
+            (other, _) => io::Error::other(other.to_string()),
+        }
+    })
+}
+
+/// Lets an already parsed [`Wkt`] be used wherever a [`ToWkt`] is expected. Writing goes straight
+/// through [`Display`](std::fmt::Display), without cloning the geometry.
+///
+/// # Examples
+/// ```
+/// use std::str::FromStr;
+/// use wkt::{ToWkt, Wkt};
+///
+/// let wkt: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+///
+/// assert_eq!(ToWkt::wkt_string(&wkt), "POINT Z(1 2 3)");
+/// ```
+impl<T> ToWkt<T> for Wkt<T>
+where
+    T: WktNum + CoordNum + std::fmt::Display,
+{
+    fn to_wkt(&self) -> Wkt<T> {
+        self.clone()
+    }
+
+    fn wkt_string(&self) -> String {
+        self.to_string()
+    }
+
+    fn write_wkt(&self, writer: impl io::Write) -> io::Result<()> {
+        write_wkt_to_io(writer, self)
+    }
+}
+
+/// Write any [`GeometryTrait`] implementation as a WKT string, without first converting it to a
+/// [`Wkt`] or [`geo_types`] geometry.
+///
+/// This is implemented for every geometry whose coordinates can be written, so it's enough to
+/// import the trait.
+///
+/// ```
+/// use wkt::ToWktString;
+///
+/// use geo_types::{coord, Line};
+///
+/// let line = Line::<f64>::new(coord!(x: 1., y: 2., z: 3.), coord!(x: 4., y: 5., z: 6.));
+/// assert_eq!(line.to_wkt_string().unwrap(), "LINESTRING Z(1 2 3,4 5 6)");
+/// ```
+pub trait ToWktString {
+    /// Serialize as a WKT string, as [`write_geometry`] does. Fails if the geometry's
+    /// dimensions can't be written as WKT.
+    ///
+    /// [`Wkt`] has an inherent [`to_wkt_string`](Wkt::to_wkt_string) which takes precedence in
+    /// method calls and keeps the `LINEARRING` keyword.
+    fn to_wkt_string(&self) -> Result<String, Error>;
+}
+
+impl<G> ToWktString for G
+where
+    G: GeometryTrait,
+    G::T: WktNum + CoordNum + std::fmt::Display,
+{
+    fn to_wkt_string(&self) -> Result<String, Error> {
+        let mut wkt_string = String::new();
+        write_geometry(&mut wkt_string, self)?;
+        Ok(wkt_string)
+    }
+}
+
+impl<T> Wkt<T>
+where
+    T: WktNum + CoordNum + std::fmt::Display,
+{
+    /// Serialize as a WKT string, as [`ToWktString::to_wkt_string`] does for other geometries,
+    /// but keeping the `LINEARRING` keyword of linear rings.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+    /// assert_eq!(wkt.to_wkt_string().unwrap(), "POINT Z(1 2 3)");
+    /// ```
+    pub fn to_wkt_string(&self) -> Result<String, Error> {
+        let mut wkt_string = String::new();
+        write_wkt_with_config(&mut wkt_string, self, &WriteConfig::default())?;
+        Ok(wkt_string)
+    }
+
+    /// Serialize as a 2D WKT string, dropping any z or m ordinates.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(1 2 3,4 5 6)").unwrap();
+    /// assert_eq!(wkt.to_wkt_string_2d(), "LINESTRING(1 2,4 5)");
+    /// ```
+    pub fn to_wkt_string_2d(&self) -> String {
+        let config = WriteConfig {
+            force_2d: true,
+            ..Default::default()
+        };
+        let mut wkt_string = String::new();
+        // Only x and y are written, which every coordinate has, and a `Wkt`'s dimension is never
+        // unknown, so the only error left would be from the `String`, which can't fail
+        write_wkt_with_config(&mut wkt_string, self, &config)
+            .expect("writing x and y of a Wkt to a String can't fail");
+        wkt_string
+    }
+
+    /// Serialize as a WKT string with each member of a `GEOMETRYCOLLECTION` on its own line,
+    /// indented by two spaces for each level of nesting, for reading large collections while
+    /// debugging.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> =
+    ///     Wkt::from_str("GEOMETRYCOLLECTION(POINT(1 2),GEOMETRYCOLLECTION(POINT(3 4)))").unwrap();
+    /// assert_eq!(
+    ///     wkt.to_wkt_string_pretty().unwrap(),
+    ///     "GEOMETRYCOLLECTION(\n  POINT(1 2),\n  GEOMETRYCOLLECTION(\n    POINT(3 4)\n  )\n)"
+    /// );
+    /// ```
+    pub fn to_wkt_string_pretty(&self) -> Result<String, Error> {
+        let mut wkt_string = String::new();
+        write_wkt_pretty(&mut wkt_string, self, 2, 0)?;
+        Ok(wkt_string)
     }
 }
 
@@ -102,10 +251,7 @@ mod tests {
         struct FailingWriter;
         impl io::Write for FailingWriter {
             fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
-                Err(io::Error::new(
-                    io::ErrorKind::Other,
-                    "FailingWriter always fails",
-                ))
+                Err(io::Error::other("FailingWriter always fails"))
             }
 
             fn flush(&mut self) -> io::Result<()> {
@@ -117,4 +263,498 @@ mod tests {
         let err = point.write_wkt(FailingWriter).unwrap_err();
         assert_eq!(err.to_string(), "FailingWriter always fails");
     }
+
+    #[test]
+    fn write_wkt_streams_through_short_writes() {
+        /// Accepts at most 3 bytes per call, like a congested socket
+        struct TrickleWriter {
+            written: Vec<u8>,
+            calls: usize,
+        }
+        impl io::Write for TrickleWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                let n = buf.len().min(3);
+                self.written.extend_from_slice(&buf[..n]);
+                self.calls += 1;
+                Ok(n)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let collection: geo_types::GeometryCollection<f64> = (0..1000)
+            .map(|i| geo_types::Geometry::Point(geo_types::Point::new(i as f64, 0.5, -1.0)))
+            .collect();
+        let mut writer = TrickleWriter {
+            written: vec![],
+            calls: 0,
+        };
+        collection.write_wkt(&mut writer).unwrap();
+        assert_eq!(
+            String::from_utf8(writer.written).unwrap(),
+            collection.wkt_string()
+        );
+        assert!(writer.calls > 1000);
+    }
+
+    #[test]
+    fn wkt_as_to_wkt() {
+        use std::str::FromStr;
+
+        fn export<G: ToWkt<f64>>(geometry: &G) -> String {
+            geometry.wkt_string()
+        }
+
+        let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(1 2 3,4 5 6)").unwrap();
+        assert_eq!(export(&wkt), "LINESTRING Z(1 2 3,4 5 6)");
+        assert_eq!(
+            export(&wkt),
+            export(&geo_types::Geometry::try_from(wkt.clone()).unwrap())
+        );
+        assert_eq!(wkt.to_wkt(), wkt);
+
+        let mut written = vec![];
+        ToWkt::write_wkt(&wkt, &mut written).unwrap();
+        assert_eq!(written, b"LINESTRING Z(1 2 3,4 5 6)");
+    }
+
+    #[test]
+    fn to_wkt_string_from_geo_traits() {
+        use geo_types::polygon;
+        use std::str::FromStr;
+
+        let polygon: geo_types::Polygon<f64> =
+            polygon![(x: 0., y: 0., z: 1.), (x: 1., y: 0., z: 1.), (x: 1., y: 1., z: 1.)];
+        assert_eq!(
+            polygon.to_wkt_string().unwrap(),
+            "POLYGON Z((0 0 1,1 0 1,1 1 1,0 0 1))"
+        );
+
+        let wkt: Wkt<f64> = Wkt::from_str("MULTIPOINT M((1 2 3))").unwrap();
+        assert_eq!(wkt.to_wkt_string().unwrap(), wkt.to_string());
+    }
+
+    #[test]
+    fn write_with_precision() {
+        use std::str::FromStr;
+
+        let write = |wkt: &str, precision| {
+            let wkt: Wkt<f64> = Wkt::from_str(wkt).unwrap();
+            let config = WriteConfig {
+                precision,
+                ..Default::default()
+            };
+            let mut written = String::new();
+            write_geometry_with_config(&mut written, &wkt, &config).unwrap();
+            written
+        };
+
+        assert_eq!(write("POINT (1.123456789 2.0)", Some(3)), "POINT(1.123 2)");
+        assert_eq!(
+            write("POINT Z(10.12345 20.67891 30.63831)", None),
+            "POINT Z(10.12345 20.67891 30.63831)"
+        );
+        assert_eq!(
+            write(
+                "LINESTRING ZM(0.0004 -0.0004 99.9996 1.5,10 20 30 40)",
+                Some(3)
+            ),
+            "LINESTRING ZM(0 0 100 1.5,10 20 30 40)"
+        );
+        assert_eq!(write("POINT (1.25 2)", Some(0)), "POINT(1 2)");
+    }
+
+    #[test]
+    fn write_comma_space_separator() {
+        use std::str::FromStr;
+
+        let config = WriteConfig {
+            coord_separator: CoordSeparator::CommaSpace,
+            ..Default::default()
+        };
+        let write = |wkt: &str| {
+            let wkt: Wkt<f64> = Wkt::from_str(wkt).unwrap();
+            let mut written = String::new();
+            write_geometry_with_config(&mut written, &wkt, &config).unwrap();
+            written
+        };
+        assert_eq!(
+            write("LINESTRING Z(10 20 30,40 50 60)"),
+            "LINESTRING Z(10 20 30, 40 50 60)"
+        );
+        assert_eq!(
+            write("POLYGON((0 0,1 0,0 0),(2 2,3 2,2 2))"),
+            "POLYGON((0 0, 1 0, 0 0), (2 2, 3 2, 2 2))"
+        );
+        assert_eq!(write("MULTIPOINT((1 2),(3 4))"), "MULTIPOINT((1 2), (3 4))");
+        assert_eq!(
+            write("GEOMETRYCOLLECTION(POINT(1 2),MULTIPOLYGON(((0 0,1 0,0 0)),((5 5,6 5,5 5))))"),
+            "GEOMETRYCOLLECTION(POINT(1 2), MULTIPOLYGON(((0 0, 1 0, 0 0)), ((5 5, 6 5, 5 5))))"
+        );
+    }
+
+    #[test]
+    fn write_flat_multipoint() {
+        use std::str::FromStr;
+
+        let config = WriteConfig {
+            multipoint_style: MultiPointStyle::Flat,
+            ..Default::default()
+        };
+        let write = |wkt: &str| {
+            let wkt: Wkt<f64> = Wkt::from_str(wkt).unwrap();
+            let mut written = String::new();
+            write_geometry_with_config(&mut written, &wkt, &config).unwrap();
+            written
+        };
+        let flat = write("MULTIPOINT Z((0 0 0),(4 0 -4))");
+        assert_eq!(flat, "MULTIPOINT Z(0 0 0,4 0 -4)");
+        assert_eq!(
+            Wkt::<f64>::from_str(&flat).unwrap(),
+            Wkt::from_str("MULTIPOINT Z((0 0 0),(4 0 -4))").unwrap()
+        );
+        assert_eq!(write("MULTIPOINT((1 2),EMPTY)"), "MULTIPOINT(1 2,EMPTY)");
+        assert_eq!(write("MULTIPOINT EMPTY"), "MULTIPOINT EMPTY");
+        assert_eq!(
+            write("GEOMETRYCOLLECTION(MULTIPOINT((1 2),(3 4)))"),
+            "GEOMETRYCOLLECTION(MULTIPOINT(1 2,3 4))"
+        );
+
+        // The default is unchanged
+        let mut written = String::new();
+        let wkt: Wkt<f64> = Wkt::from_str("MULTIPOINT(1 2,3 4)").unwrap();
+        write_geometry_with_config(&mut written, &wkt, &WriteConfig::default()).unwrap();
+        assert_eq!(written, "MULTIPOINT((1 2),(3 4))");
+    }
+
+    /// A line with any dimension, as `geo_types::Line` always has exactly x, y and z.
+    struct AnyLine(crate::types::Coord<f64>, crate::types::Coord<f64>);
+
+    impl geo_traits::LineTrait for AnyLine {
+        type T = f64;
+        type CoordType<'a>
+            = &'a crate::types::Coord<f64>
+        where
+            Self: 'a;
+
+        fn dim(&self) -> geo_traits::Dimensions {
+            geo_traits::CoordTrait::dim(&self.0)
+        }
+
+        fn start(&self) -> Self::CoordType<'_> {
+            &self.0
+        }
+
+        fn end(&self) -> Self::CoordType<'_> {
+            &self.1
+        }
+    }
+
+    #[test]
+    fn write_measured_and_4d_lines() {
+        let coord = |x, z, m| crate::types::Coord { x, y: 0., z, m };
+        let mut written = String::new();
+        write_line(
+            &mut written,
+            &AnyLine(coord(1., Some(2.), Some(3.)), coord(4., Some(5.), Some(6.))),
+        )
+        .unwrap();
+        assert_eq!(written, "LINESTRING ZM(1 0 2 3,4 0 5 6)");
+
+        let mut written = String::new();
+        write_line(
+            &mut written,
+            &AnyLine(coord(1., None, Some(3.)), coord(4., None, Some(6.))),
+        )
+        .unwrap();
+        assert_eq!(written, "LINESTRING M(1 0 3,4 0 6)");
+
+        let mut written = String::new();
+        write_line(
+            &mut written,
+            &AnyLine(coord(1., None, None), coord(4., None, None)),
+        )
+        .unwrap();
+        assert_eq!(written, "LINESTRING(1 0,4 0)");
+    }
+
+    /// A rect with the dimension of its minimum corner, as `geo_types::Rect` always has a z and
+    /// never an m.
+    pub(super) struct CoordRect(
+        pub(super) crate::types::Coord<f64>,
+        pub(super) crate::types::Coord<f64>,
+    );
+
+    impl geo_traits::RectTrait for CoordRect {
+        type T = f64;
+        type CoordType<'a>
+            = &'a crate::types::Coord<f64>
+        where
+            Self: 'a;
+
+        fn dim(&self) -> geo_traits::Dimensions {
+            geo_traits::CoordTrait::dim(&self.0)
+        }
+
+        fn min(&self) -> Self::CoordType<'_> {
+            &self.0
+        }
+
+        fn max(&self) -> Self::CoordType<'_> {
+            &self.1
+        }
+    }
+
+    #[test]
+    fn write_2d_and_3d_rects() {
+        let corner = |x, y| crate::types::Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+        };
+        let rect_2d = CoordRect(corner(1., 2.), corner(3., 4.));
+        let mut written = String::new();
+        write_rect(&mut written, &rect_2d).unwrap();
+        assert_eq!(written, "POLYGON((1 2,3 2,3 4,1 4,1 2))");
+
+        let flat = geo_types::Rect::new(
+            geo_types::coord!(x: 4., y: 4., z: 2.),
+            geo_types::coord!(x: 8., y: 8., z: 2.),
+        );
+        let mut written = String::new();
+        write_rect(&mut written, &flat).unwrap();
+        assert_eq!(written, "POLYGON Z((4 4 2,8 4 2,8 8 2,4 8 2,4 4 2))");
+
+        let rect = geo_types::Rect::new(
+            geo_types::coord!(x: 4., y: 4., z: 4.),
+            geo_types::coord!(x: 8., y: 8., z: 8.),
+        );
+        let mut written = String::new();
+        write_rect(&mut written, &rect).unwrap();
+        assert_eq!(
+            written,
+            "POLYGON Z((4 4 4,8 4 4,8 8 4,4 8 4,4 4 4,4 4 8,8 4 8,8 8 8,4 8 8,4 4 8,4 4 4))"
+        );
+
+        let mut written = String::new();
+        let config = WriteConfig {
+            force_2d: true,
+            ..Default::default()
+        };
+        geo_trait_impl::write_rect_with_config(&mut written, &rect, &config).unwrap();
+        assert_eq!(written, "POLYGON((4 4,8 4,8 8,4 8,4 4))");
+
+        let measured = |x, y| crate::types::Coord {
+            x,
+            y,
+            z: None,
+            m: Some(0.),
+        };
+        assert!(matches!(
+            write_rect(
+                &mut String::new(),
+                &CoordRect(measured(1., 2.), measured(3., 4.))
+            ),
+            Err(crate::error::Error::UnknownDimension)
+        ));
+    }
+
+    #[test]
+    fn write_single_coords() {
+        let coord = crate::types::Coord {
+            x: 0.1,
+            y: -2.,
+            z: Some(3e20),
+            m: Some(4.),
+        };
+        let mut written = String::new();
+        write_coord(&mut written, &coord, geo_traits::Dimensions::Xy).unwrap();
+        assert_eq!(written, "0.1 -2");
+
+        let mut written = String::new();
+        write_coord(&mut written, &coord, geo_traits::Dimensions::Xyzm).unwrap();
+        assert_eq!(written, "0.1 -2 300000000000000000000 4");
+
+        assert!(matches!(
+            write_coord(
+                &mut String::new(),
+                &coord,
+                geo_traits::Dimensions::Unknown(5)
+            ),
+            Err(crate::error::Error::UnknownDimension)
+        ));
+
+        let flat = crate::types::Coord {
+            x: 1.,
+            y: 2.,
+            z: None,
+            m: None,
+        };
+        assert!(matches!(
+            write_coord(&mut String::new(), &flat, geo_traits::Dimensions::Xyz),
+            Err(crate::error::Error::MissingOrdinate(2))
+        ));
+    }
+
+    #[test]
+    fn pretty_collection() {
+        use crate::types::{Coord, GeometryCollection, LineString};
+        use std::str::FromStr;
+
+        let limits = crate::ParseLimits {
+            keep_linear_rings: true,
+            ..Default::default()
+        };
+        let wkt: Wkt<f64> = Wkt::from_str_with_limits(
+            "GEOMETRYCOLLECTION Z(POINT Z(1 2 3),\
+             GEOMETRYCOLLECTION Z(LINEARRING Z(0 0 0,1 0 0,0 0 0),GEOMETRYCOLLECTION EMPTY))",
+            &limits,
+        )
+        .unwrap();
+        assert_eq!(
+            wkt.to_wkt_string_pretty().unwrap(),
+            "\
+GEOMETRYCOLLECTION Z(
+  POINT Z(1 2 3),
+  GEOMETRYCOLLECTION Z(
+    LINEARRING Z(0 0 0,1 0 0,0 0 0),
+    GEOMETRYCOLLECTION EMPTY
+  )
+)"
+        );
+
+        let point: Wkt<f64> = Wkt::from_str("POINT(1 2)").unwrap();
+        assert_eq!(point.to_wkt_string_pretty().unwrap(), "POINT(1 2)");
+
+        // A hand-built line string whose second coordinate is missing the z of the first
+        let ragged = Wkt::LineString(LineString::new(vec![
+            Coord {
+                x: 1.,
+                y: 2.,
+                z: Some(3.),
+                m: None,
+            },
+            Coord {
+                x: 4.,
+                y: 5.,
+                z: None,
+                m: None,
+            },
+        ]));
+        let collection = Wkt::GeometryCollection(GeometryCollection::new(vec![ragged]));
+        assert!(matches!(
+            collection.to_wkt_string_pretty(),
+            Err(Error::MissingOrdinate(2))
+        ));
+    }
+
+    #[test]
+    fn collection_string_from_geometries() {
+        use std::str::FromStr;
+
+        let geometries: Vec<Wkt<f64>> = [
+            "POINT M(1 2 3)",
+            "POINT M EMPTY",
+            "LINESTRING M(0 0 1,1 1 2)",
+        ]
+        .into_iter()
+        .map(|wkt| Wkt::from_str(wkt).unwrap())
+        .collect();
+        assert_eq!(
+            geometries_to_collection_string(geometries.iter()).unwrap(),
+            "GEOMETRYCOLLECTION M(POINT M(1 2 3),POINT M EMPTY,LINESTRING M(0 0 1,1 1 2))"
+        );
+        assert_eq!(
+            geometries_to_collection_string(Vec::<Wkt<f64>>::new()).unwrap(),
+            "GEOMETRYCOLLECTION EMPTY"
+        );
+    }
+
+    #[test]
+    fn write_compact_dimension_style() {
+        use std::str::FromStr;
+
+        let config = WriteConfig {
+            dimension_style: DimensionStyle::Compact,
+            ..Default::default()
+        };
+        let write = |wkt: &str| {
+            let wkt: Wkt<f64> = Wkt::from_str(wkt).unwrap();
+            let mut written = String::new();
+            write_geometry_with_config(&mut written, &wkt, &config).unwrap();
+            written
+        };
+
+        assert_eq!(write("POINT Z(1 2 3)"), "POINTZ(1 2 3)");
+        assert_eq!(
+            write("LINESTRING M(1 2 3,4 5 6)"),
+            "LINESTRINGM(1 2 3,4 5 6)"
+        );
+        assert_eq!(write("POLYGON ZM EMPTY"), "POLYGONZM EMPTY");
+        assert_eq!(
+            write("GEOMETRYCOLLECTION Z(POINT Z(1 2 3))"),
+            "GEOMETRYCOLLECTIONZ(POINTZ(1 2 3))"
+        );
+        assert_eq!(write("POINT(1 2)"), "POINT(1 2)");
+    }
+
+    #[test]
+    fn write_lowercase_keywords() {
+        use std::str::FromStr;
+
+        let config = WriteConfig {
+            keyword_case: KeywordCase::Lower,
+            ..Default::default()
+        };
+        let write = |wkt: &str| {
+            let wkt: Wkt<f64> = Wkt::from_str(wkt).unwrap();
+            let mut written = String::new();
+            write_geometry_with_config(&mut written, &wkt, &config).unwrap();
+            written
+        };
+
+        assert_eq!(write("POINT Z(1 2 3)"), "point z(1 2 3)");
+        assert_eq!(write("MULTIPOINT ZM EMPTY"), "multipoint zm empty");
+        assert_eq!(
+            write("GEOMETRYCOLLECTION(POINT(1 2),LINESTRING EMPTY)"),
+            "geometrycollection(point(1 2),linestring empty)"
+        );
+    }
+
+    #[test]
+    fn to_wkt_string_2d() {
+        use crate::types::{Coord, LineString};
+        use std::str::FromStr;
+
+        let point: Wkt<f64> = Wkt::from_str("POINT Z(1 2 3)").unwrap();
+        assert_eq!(point.to_wkt_string_2d(), "POINT(1 2)");
+        assert_eq!(point.to_string(), "POINT Z(1 2 3)");
+
+        let collection: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(1 2 3),POLYGON Z EMPTY)").unwrap();
+        assert_eq!(
+            collection.to_wkt_string_2d(),
+            "GEOMETRYCOLLECTION(POINT(1 2),POLYGON EMPTY)"
+        );
+
+        // Coordinates missing the z of the first still have an x and y to write
+        let ragged: Wkt<f64> = Wkt::LineString(LineString::new(vec![
+            Coord {
+                x: 1.,
+                y: 2.,
+                z: Some(3.),
+                m: None,
+            },
+            Coord {
+                x: 4.,
+                y: 5.,
+                z: None,
+                m: None,
+            },
+        ]));
+        assert_eq!(ragged.to_wkt_string_2d(), "LINESTRING(1 2,4 5)");
+    }
 }
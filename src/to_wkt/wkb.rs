@@ -0,0 +1,313 @@
+use std::io;
+
+use geo_traits::{
+    CoordTrait, Dimensions, GeometryCollectionTrait, GeometryTrait, LineStringTrait, LineTrait,
+    MultiLineStringTrait, MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
+    TriangleTrait,
+};
+
+use crate::error::Error;
+use crate::to_wkt::geo_trait_impl::rect_ring;
+use crate::wkb::Endianness;
+
+/// ISO WKB type codes, before the dimension is added.
+const POINT: u32 = 1;
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_POINT: u32 = 4;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+const GEOMETRY_COLLECTION: u32 = 7;
+
+struct WkbWriter<'a, W: io::Write> {
+    writer: &'a mut W,
+    endianness: Endianness,
+}
+
+impl<W: io::Write> WkbWriter<'_, W> {
+    fn write_u32(&mut self, n: u32) -> Result<(), Error> {
+        let bytes = match self.endianness {
+            Endianness::BigEndian => n.to_be_bytes(),
+            Endianness::LittleEndian => n.to_le_bytes(),
+        };
+        Ok(self.writer.write_all(&bytes)?)
+    }
+
+    fn write_f64(&mut self, value: f64) -> Result<(), Error> {
+        let bytes = match self.endianness {
+            Endianness::BigEndian => value.to_be_bytes(),
+            Endianness::LittleEndian => value.to_le_bytes(),
+        };
+        Ok(self.writer.write_all(&bytes)?)
+    }
+
+    fn write_len(&mut self, len: usize) -> Result<(), Error> {
+        let len = len.try_into().map_err(|_| Error::TooManyWkbItems(len))?;
+        self.write_u32(len)
+    }
+
+    /// Write the byte order marker and the ISO type code for `code` in dimension `dim`.
+    fn write_header(&mut self, code: u32, dim: Dimensions) -> Result<(), Error> {
+        let marker = match self.endianness {
+            Endianness::BigEndian => 0,
+            Endianness::LittleEndian => 1,
+        };
+        self.writer.write_all(&[marker])?;
+        let dim_code = match dim {
+            Dimensions::Xy | Dimensions::Unknown(2) => 0,
+            Dimensions::Xyz | Dimensions::Unknown(3) => 1000,
+            Dimensions::Xym => 2000,
+            Dimensions::Xyzm | Dimensions::Unknown(4) => 3000,
+            Dimensions::Unknown(_) => return Err(Error::UnknownDimension),
+        };
+        self.write_u32(code + dim_code)
+    }
+
+    fn write_coord(
+        &mut self,
+        coord: &impl CoordTrait<T = f64>,
+        dim: Dimensions,
+    ) -> Result<(), Error> {
+        for n in 0..dim.size() {
+            self.write_f64(coord.nth(n).ok_or(Error::MissingOrdinate(n))?)?;
+        }
+        Ok(())
+    }
+
+    fn write_coords(
+        &mut self,
+        coords: impl ExactSizeIterator<Item = impl CoordTrait<T = f64>>,
+        dim: Dimensions,
+    ) -> Result<(), Error> {
+        self.write_len(coords.len())?;
+        for coord in coords {
+            self.write_coord(&coord, dim)?;
+        }
+        Ok(())
+    }
+
+    /// An empty point is written with every ordinate NaN, as PostGIS and GEOS do.
+    fn write_point(
+        &mut self,
+        point: &impl PointTrait<T = f64>,
+        dim: Dimensions,
+    ) -> Result<(), Error> {
+        self.write_header(POINT, dim)?;
+        match point.coord() {
+            Some(coord) => self.write_coord(&coord, dim),
+            None => (0..dim.size()).try_for_each(|_| self.write_f64(f64::NAN)),
+        }
+    }
+
+    fn write_line_string(
+        &mut self,
+        line_string: &impl LineStringTrait<T = f64>,
+        dim: Dimensions,
+    ) -> Result<(), Error> {
+        self.write_header(LINE_STRING, dim)?;
+        self.write_coords(line_string.coords(), dim)
+    }
+
+    fn write_polygon(
+        &mut self,
+        polygon: &impl PolygonTrait<T = f64>,
+        dim: Dimensions,
+    ) -> Result<(), Error> {
+        self.write_header(POLYGON, dim)?;
+        let Some(exterior) = polygon.exterior() else {
+            return self.write_u32(0);
+        };
+        self.write_len(1 + polygon.num_interiors())?;
+        self.write_coords(exterior.coords(), dim)?;
+        for interior in polygon.interiors() {
+            self.write_coords(interior.coords(), dim)?;
+        }
+        Ok(())
+    }
+
+    fn write_geometry(&mut self, geometry: &impl GeometryTrait<T = f64>) -> Result<(), Error> {
+        let dim = geometry.dim();
+        match geometry.as_type() {
+            geo_traits::GeometryType::Point(point) => self.write_point(point, dim),
+            geo_traits::GeometryType::LineString(line_string) => {
+                self.write_line_string(line_string, dim)
+            }
+            geo_traits::GeometryType::Polygon(polygon) => self.write_polygon(polygon, dim),
+            geo_traits::GeometryType::MultiPoint(multi_point) => {
+                self.write_header(MULTI_POINT, dim)?;
+                self.write_len(multi_point.num_points())?;
+                multi_point
+                    .points()
+                    .try_for_each(|point| self.write_point(&point, dim))
+            }
+            geo_traits::GeometryType::MultiLineString(mls) => {
+                self.write_header(MULTI_LINE_STRING, dim)?;
+                self.write_len(mls.num_line_strings())?;
+                mls.line_strings()
+                    .try_for_each(|line_string| self.write_line_string(&line_string, dim))
+            }
+            geo_traits::GeometryType::MultiPolygon(multi_polygon) => {
+                self.write_header(MULTI_POLYGON, dim)?;
+                self.write_len(multi_polygon.num_polygons())?;
+                multi_polygon
+                    .polygons()
+                    .try_for_each(|polygon| self.write_polygon(&polygon, dim))
+            }
+            geo_traits::GeometryType::GeometryCollection(gc) => {
+                self.write_header(GEOMETRY_COLLECTION, dim)?;
+                self.write_len(gc.num_geometries())?;
+                gc.geometries()
+                    .try_for_each(|geometry| self.write_geometry(&geometry))
+            }
+            geo_traits::GeometryType::Rect(rect) => self.write_rect(rect),
+            geo_traits::GeometryType::Triangle(triangle) => {
+                self.write_header(POLYGON, dim)?;
+                self.write_u32(1)?;
+                let coords = triangle.coords();
+                self.write_len(coords.len() + 1)?;
+                for coord in coords.iter().chain(std::iter::once(&triangle.first())) {
+                    self.write_coord(coord, dim)?;
+                }
+                Ok(())
+            }
+            geo_traits::GeometryType::Line(line) => {
+                self.write_header(LINE_STRING, dim)?;
+                self.write_coords(line.coords().into_iter(), dim)
+            }
+        }
+    }
+
+    /// Rects are written as a polygon with one exterior ring, as
+    /// [`write_rect`](super::write_rect) does.
+    fn write_rect(&mut self, rect: &impl RectTrait<T = f64>) -> Result<(), Error> {
+        let dim = match rect.dim() {
+            Dimensions::Xy | Dimensions::Unknown(2) => Dimensions::Xy,
+            Dimensions::Xyz | Dimensions::Unknown(3) => Dimensions::Xyz,
+            _ => return Err(Error::UnknownDimension),
+        };
+        let ring = rect_ring(rect, dim)?;
+        self.write_header(POLYGON, dim)?;
+        self.write_u32(1)?;
+        self.write_coords(ring.iter(), dim)
+    }
+}
+
+/// Write an object implementing [`GeometryTrait`] as ISO well-known binary (WKB).
+///
+/// The type code and the number of ordinates written for each coordinate follow the dimension
+/// the geometry reports. Rects and triangles are written as polygons and lines as line strings,
+/// as they are when writing WKT. Wrap unbuffered writers in a
+/// [`BufWriter`](std::io::BufWriter), since each number is written separately.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wkt::to_wkt::write_wkb;
+/// use wkt::wkb::Endianness;
+/// use wkt::Wkt;
+///
+/// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(1 2 3,4 5 6)").unwrap();
+/// let mut wkb = vec![];
+/// write_wkb(&mut wkb, &wkt, Endianness::LittleEndian).unwrap();
+///
+/// assert_eq!(wkb[..5], [1, 0xea, 0x03, 0, 0]);
+/// assert_eq!(Wkt::from_wkb(&wkb).unwrap(), wkt);
+/// ```
+pub fn write_wkb<W: io::Write>(
+    w: &mut W,
+    geom: &impl GeometryTrait<T = f64>,
+    endianness: Endianness,
+) -> Result<(), Error> {
+    WkbWriter {
+        writer: w,
+        endianness,
+    }
+    .write_geometry(geom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_wkb, WkbWriter};
+    use crate::error::Error;
+    use crate::to_wkt::tests::CoordRect;
+    use crate::wkb::Endianness;
+    use crate::Wkt;
+    use std::str::FromStr;
+
+    fn round_trip(wkt: &str, endianness: Endianness) -> Wkt<f64> {
+        let wkt = Wkt::from_str(wkt).unwrap();
+        let mut wkb = vec![];
+        write_wkb(&mut wkb, &wkt, endianness).unwrap();
+        Wkt::from_wkb(&wkb).unwrap()
+    }
+
+    #[test]
+    fn write_wkb_round_trip() {
+        for wkt in [
+            "POINT(1 2)",
+            "POINT Z EMPTY",
+            "LINESTRING M(1 2 3,4 5 6)",
+            "POLYGON Z((0 0 1,4 0 1,4 4 1,0 0 1),(1 1 2,2 1 2,2 2 2,1 1 2))",
+            "POLYGON EMPTY",
+            "MULTIPOINT ZM((1 2 3 4),(5 6 7 8))",
+            "MULTILINESTRING((1 2,3 4),(5 6,7 8))",
+            "MULTIPOLYGON Z(((0 0 0,1 0 0,1 1 0,0 0 0)),((5 5 5,6 5 5,6 6 5,5 5 5)))",
+            "GEOMETRYCOLLECTION(POINT(1 2),GEOMETRYCOLLECTION(LINESTRING(3 4,5 6)))",
+            "GEOMETRYCOLLECTION EMPTY",
+        ] {
+            for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+                assert_eq!(round_trip(wkt, endianness), Wkt::from_str(wkt).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn write_wkb_point_bytes() {
+        let wkt: Wkt<f64> = Wkt::from_str("POINT(1 2)").unwrap();
+        let mut wkb = vec![];
+        write_wkb(&mut wkb, &wkt, Endianness::BigEndian).unwrap();
+
+        let mut expected = vec![0, 0, 0, 0, 1];
+        expected.extend(1f64.to_be_bytes());
+        expected.extend(2f64.to_be_bytes());
+        assert_eq!(wkb, expected);
+    }
+
+    #[test]
+    fn write_wkb_typed_geometries() {
+        // The concrete types report their own dimension, not only when wrapped in a `Wkt`
+        for wkt in [
+            "LINESTRING Z(1 2 3,4 5 6)",
+            "POLYGON M((0 0 1,1 0 2,1 1 3,0 0 1))",
+            "MULTIPOINT ZM((1 2 3 4))",
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str(wkt).unwrap();
+            let mut wkb = vec![];
+            match &wkt {
+                Wkt::LineString(g) => write_wkb(&mut wkb, g, Endianness::LittleEndian),
+                Wkt::Polygon(g) => write_wkb(&mut wkb, g, Endianness::LittleEndian),
+                Wkt::MultiPoint(g) => write_wkb(&mut wkb, g, Endianness::LittleEndian),
+                _ => unreachable!(),
+            }
+            .unwrap();
+            assert_eq!(Wkt::from_wkb(&wkb).unwrap(), wkt);
+        }
+    }
+
+    #[test]
+    fn write_wkb_measured_rect_fails() {
+        let corner = |x, y| crate::types::Coord {
+            x,
+            y,
+            z: None,
+            m: Some(0.),
+        };
+        let mut writer = WkbWriter {
+            writer: &mut vec![],
+            endianness: Endianness::LittleEndian,
+        };
+        assert!(matches!(
+            writer.write_rect(&CoordRect(corner(1., 2.), corner(3., 4.))),
+            Err(Error::UnknownDimension)
+        ));
+    }
+}
@@ -12,39 +12,100 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::WktNum;
+//! The lexer used to parse WKT, for building parsers of other WKT dialects.
+//!
+//! [`Tokens`] splits WKT into [`Token`]s, and [`PeekableTokens`] adds one token of look-ahead
+//! and the offsets of the tokens read, for reporting errors.
+//!
+//! ```
+//! use wkt::tokenizer::{PeekableTokens, Token, Tokens};
+//!
+//! let mut tokens = PeekableTokens::<f64>::new(Tokens::from_str("CIRCLE(1 2, 3)"));
+//! assert_eq!(tokens.next().unwrap().unwrap(), Token::Word("CIRCLE".to_string()));
+//! assert_eq!(tokens.peek(), Some(&Ok(Token::ParenOpen)));
+//! assert_eq!(tokens.count_next_coord_ordinates(), 2);
+//! assert_eq!(tokens.next().unwrap().unwrap(), Token::ParenOpen);
+//! assert_eq!(tokens.next().unwrap().unwrap(), Token::Number(1.));
+//! ```
+
+use crate::error::ParseWktError;
+use crate::types::Dimension;
+use crate::{ParseLimits, WktNum};
 use std::any::type_name;
-use std::iter::Peekable;
 use std::marker::PhantomData;
 use std::str;
 
+/// A token of WKT.
+///
+/// Whitespace separates tokens but isn't one itself, and parentheses and commas are tokens even
+/// without whitespace around them.
 #[derive(Debug, PartialEq, Eq)]
 pub enum Token<T>
 where
     T: WktNum,
 {
+    /// `,`
     Comma,
+    /// A number, starting with a digit, `.`, `-` or `+`. `inf` and `NaN` are only read as numbers
+    /// when [`ParseLimits::allow_nonfinite`] is set, and are an error otherwise.
     Number(T),
+    /// `)`
     ParenClose,
+    /// `(`
     ParenOpen,
+    /// Any other run of characters up to whitespace, a parenthesis or a comma, such as a geometry
+    /// keyword or a dimension tag. Case is kept as written.
     Word(String),
 }
 
 #[inline]
-fn is_whitespace(c: char) -> bool {
-    c == ' ' || c == '\n' || c == '\r' || c == '\t'
+fn is_whitespace(b: u8) -> bool {
+    b == b' ' || b == b'\n' || b == b'\r' || b == b'\t'
 }
 
 #[inline]
-fn is_numberlike(c: char) -> bool {
-    c == '.' || c == '-' || c == '+' || c.is_ascii_digit()
+fn is_numberlike(b: u8) -> bool {
+    b == b'.' || b == b'-' || b == b'+' || b.is_ascii_digit()
 }
 
-pub type PeekableTokens<'a, T> = Peekable<Tokens<'a, T>>;
+/// Whether `number` spells out infinity or NaN as `FromStr` for floats accepts them, e.g. `-inf`
+/// or `NaN`.
+fn is_nonfinite_literal(number: &[u8]) -> bool {
+    let unsigned = number.strip_prefix(b"-").unwrap_or(number);
+    let words: [&[u8]; 3] = [b"inf", b"infinity", b"nan"];
+    words.iter().any(|word| unsigned.eq_ignore_ascii_case(word))
+}
 
-#[derive(Debug)]
+/// The most characters of a token quoted in a [`ParseWktError`].
+const MAX_SNIPPET_CHARS: usize = 32;
+
+/// The fewest bytes an item followed by a comma can take, e.g. `1 2,`, for
+/// [`PeekableTokens::count_items_hint`].
+const MIN_ITEM_BYTES: usize = 4;
+
+/// The largest number of items [`PeekableTokens::count_items_hint`] will suggest reserving.
+const MAX_ITEMS_HINT: usize = 1 << 16;
+
+fn snippet(text: &[u8]) -> String {
+    String::from_utf8_lossy(text)
+        .chars()
+        .take(MAX_SNIPPET_CHARS)
+        .collect()
+}
+
+/// Splits WKT into tokens, walking the bytes of the input.
+///
+/// Every byte with a meaning in WKT is ASCII, so the input needn't be valid UTF-8. A word which
+/// isn't is read with replacement characters, and rejected by the parser like any other
+/// non-ASCII word.
+#[derive(Clone, Debug)]
 pub struct Tokens<'a, T> {
-    chars: Peekable<str::Chars<'a>>,
+    input: &'a [u8],
+    offset: usize,
+    token_start: usize,
+    allow_nonfinite: bool,
+    /// `T`'s positive and negative infinity, if it has them, parsed when first needed.
+    infinities: Option<(Option<T>, Option<T>)>,
     phantom: PhantomData<T>,
 }
 
@@ -52,81 +113,390 @@ impl<'a, T> Tokens<'a, T>
 where
     T: WktNum,
 {
+    /// Tokenize `input` from the start.
+    // Can't be `FromStr`, since the tokens borrow `input`
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(input: &'a str) -> Self {
+        Self::from_bytes(input.as_bytes())
+    }
+
+    /// Tokenize `input` from the start.
+    pub fn from_bytes(input: &'a [u8]) -> Self {
+        Self::from_bytes_at(input, 0)
+    }
+
+    /// Tokenize `input` from byte offset `start`. Offsets still count from the beginning of
+    /// `input`.
+    pub fn from_bytes_at(input: &'a [u8], start: usize) -> Self {
         Tokens {
-            chars: input.chars().peekable(),
+            input,
+            offset: start,
+            token_start: start,
+            allow_nonfinite: false,
+            infinities: None,
             phantom: PhantomData,
         }
     }
+
+    /// The byte offset into the input just past the last token read.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The byte offset into the input of the start of the last token read.
+    pub fn token_start(&self) -> usize {
+        self.token_start
+    }
+}
+
+/// A peeked token, with the byte offsets of its start and of its end.
+type PeekedToken<T> = (Option<Result<Token<T>, ParseWktError>>, usize, usize);
+
+/// A token stream which can look one token ahead.
+///
+/// Unlike [`std::iter::Peekable`], peeking doesn't move [`offset`](Self::offset), so it always
+/// reports the end of the last token returned by `next`.
+#[derive(Debug)]
+pub struct PeekableTokens<'a, T>
+where
+    T: WktNum,
+{
+    tokens: Tokens<'a, T>,
+    peeked: Option<PeekedToken<T>>,
+    token_start: usize,
+    offset: usize,
+    depth: usize,
+    collection_depth: usize,
+    max_collection_depth: usize,
+    keep_linear_rings: bool,
+    item_index: usize,
+}
+
+impl<'a, T> PeekableTokens<'a, T>
+where
+    T: WktNum + str::FromStr,
+{
+    /// Wrap `tokens`, with the default [`ParseLimits`].
+    pub fn new(tokens: Tokens<'a, T>) -> Self {
+        PeekableTokens {
+            token_start: tokens.offset(),
+            offset: tokens.offset(),
+            tokens,
+            peeked: None,
+            depth: 0,
+            collection_depth: 0,
+            max_collection_depth: ParseLimits::default().max_nesting_depth,
+            keep_linear_rings: false,
+            item_index: 0,
+        }
+    }
+
+    /// Apply `limits` to the tokens still to be parsed.
+    pub(crate) fn with_limits(mut self, limits: &ParseLimits) -> Self {
+        self.max_collection_depth = limits.max_nesting_depth;
+        self.tokens.allow_nonfinite = limits.allow_nonfinite;
+        self.keep_linear_rings = limits.keep_linear_rings;
+        self
+    }
+
+    /// Whether a `LINEARRING` is parsed as a distinct geometry, as set by
+    /// [`ParseLimits::keep_linear_rings`].
+    pub(crate) fn keep_linear_rings(&self) -> bool {
+        self.keep_linear_rings
+    }
+
+    /// Record that a `GEOMETRYCOLLECTION` starts at the last token returned by `next`, failing if
+    /// that nests collections more deeply than allowed. Pair with
+    /// [`leave_collection`](Self::leave_collection).
+    pub(crate) fn enter_collection(&mut self) -> Result<(), ParseWktError> {
+        if self.collection_depth >= self.max_collection_depth {
+            return Err(ParseWktError::NestingTooDeep {
+                limit: self.max_collection_depth,
+                offset: self.token_start,
+            });
+        }
+        self.collection_depth += 1;
+        Ok(())
+    }
+
+    /// Record that the innermost collection entered with
+    /// [`enter_collection`](Self::enter_collection) has ended.
+    pub(crate) fn leave_collection(&mut self) {
+        self.collection_depth -= 1;
+    }
+
+    /// The token `next` will return, without consuming it.
+    pub fn peek(&mut self) -> Option<&Result<Token<T>, ParseWktError>> {
+        let tokens = &mut self.tokens;
+        self.peeked
+            .get_or_insert_with(|| (tokens.next(), tokens.token_start(), tokens.offset()))
+            .0
+            .as_ref()
+    }
+
+    /// The byte offset into the input just past the last token returned by `next`.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The byte offset into the input of the start of the last token returned by `next`.
+    pub fn token_start(&self) -> usize {
+        self.token_start
+    }
+
+    /// The number of ordinates in the next coordinate, found by scanning ahead past any open
+    /// parentheses and counting numbers up to the next other token. Nothing is consumed.
+    ///
+    /// Call this after peeking at the parenthesis opening a geometry, to infer the dimension of
+    /// WKT without a `Z`, `M` or `ZM` tag.
+    pub fn count_next_coord_ordinates(&self) -> usize {
+        let mut count = 0;
+        for token in self.tokens.clone() {
+            match token {
+                Ok(Token::ParenOpen) if count == 0 => (),
+                Ok(Token::Number(_)) => count += 1,
+                _ => break,
+            }
+        }
+        count
+    }
+
+    /// An estimate of the number of items in the comma separated sequence starting after the last
+    /// token returned by `next`, found by counting the commas outside parentheses before the
+    /// closing parenthesis. Nothing is consumed.
+    ///
+    /// This only looks at bytes, so it's cheap but can be wrong for invalid input; use it to size
+    /// allocations, not to parse. The hint is capped at the number of items the rest of the
+    /// input could hold and at 65536, so that input such as a run of commas can't reserve far
+    /// more memory than it takes up.
+    pub(crate) fn count_items_hint(&self) -> usize {
+        let remaining = &self.tokens.input[self.offset..];
+        let mut depth = 0usize;
+        let mut commas = 0;
+        for &b in remaining {
+            match b {
+                b'(' => depth += 1,
+                b')' if depth == 0 => break,
+                b')' => depth -= 1,
+                b',' if depth == 0 => commas += 1,
+                _ => (),
+            }
+        }
+        (commas + 1)
+            .min(remaining.len() / MIN_ITEM_BYTES + 1)
+            .min(MAX_ITEMS_HINT)
+    }
+
+    /// The error for finding the last token returned by `next` where `expected` was wanted.
+    pub(crate) fn unexpected_token_error(&self, expected: &'static str) -> ParseWktError {
+        ParseWktError::UnexpectedToken {
+            expected,
+            found: snippet(&self.tokens.input[self.token_start..self.offset]),
+            offset: self.token_start,
+        }
+    }
+
+    /// The index of the item being parsed in the innermost comma separated sequence.
+    pub(crate) fn item_index(&self) -> usize {
+        self.item_index
+    }
+
+    pub(crate) fn set_item_index(&mut self, index: usize) {
+        self.item_index = index;
+    }
+
+    /// The error for finding the last token returned by `next` where the current coordinate
+    /// should have had another ordinate, or should have ended, to match `expected`.
+    pub(crate) fn inconsistent_dimension_error(&self, expected: Dimension) -> ParseWktError {
+        ParseWktError::InconsistentDimension {
+            expected,
+            at_coord_index: self.item_index,
+            offset: self.token_start,
+        }
+    }
+
+    /// The error for finding the last token returned by `next` after a complete geometry.
+    pub(crate) fn trailing_tokens_error(&self) -> ParseWktError {
+        ParseWktError::TrailingTokens {
+            found: snippet(&self.tokens.input[self.token_start..]),
+            offset: self.token_start,
+        }
+    }
+
+    /// The error for reaching the end of the input where `expected` was wanted.
+    pub(crate) fn unexpected_end_error(&self, expected: &'static str) -> ParseWktError {
+        ParseWktError::UnexpectedEndOfInput {
+            expected,
+            offset: self.tokens.input.len(),
+        }
+    }
+
+    /// The number of parentheses opened but not yet closed by the tokens returned by `next`.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The error for reaching the end of the input while parentheses are still open.
+    pub(crate) fn unbalanced_parens_error(&self) -> ParseWktError {
+        ParseWktError::UnbalancedParentheses {
+            missing: self.depth.max(1),
+            offset: self.tokens.input.len(),
+        }
+    }
+}
+
+impl<T> Iterator for PeekableTokens<'_, T>
+where
+    T: WktNum + str::FromStr,
+{
+    type Item = Result<Token<T>, ParseWktError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (token, token_start, offset) = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => (
+                self.tokens.next(),
+                self.tokens.token_start(),
+                self.tokens.offset(),
+            ),
+        };
+        self.token_start = token_start;
+        self.offset = offset;
+        match token {
+            Some(Ok(Token::ParenOpen)) => self.depth += 1,
+            Some(Ok(Token::ParenClose)) => self.depth = self.depth.saturating_sub(1),
+            _ => (),
+        }
+        token
+    }
 }
 
 impl<T> Iterator for Tokens<'_, T>
 where
     T: WktNum + str::FromStr,
 {
-    type Item = Result<Token<T>, &'static str>;
+    type Item = Result<Token<T>, ParseWktError>;
 
     fn next(&mut self) -> Option<Self::Item> {
         // TODO: should this return Result?
-        let mut next_char = self.chars.next()?;
-
         // Skip whitespace
-        while is_whitespace(next_char) {
-            next_char = self.chars.next()?
-        }
-
-        let token = match next_char {
-            '\0' => return None,
-            '(' => Token::ParenOpen,
-            ')' => Token::ParenClose,
-            ',' => Token::Comma,
-            c if is_numberlike(c) => {
-                let number = self.read_until_whitespace(if c == '+' { None } else { Some(c) });
-                match number.parse::<T>() {
-                    Ok(parsed_num) => Token::Number(parsed_num),
-                    Err(_) => {
+        while self
+            .input
+            .get(self.offset)
+            .is_some_and(|&b| is_whitespace(b))
+        {
+            self.offset += 1;
+        }
+        let next_byte = *self.input.get(self.offset)?;
+        self.token_start = self.offset;
+        self.offset += 1;
+
+        let token = match next_byte {
+            b'\0' => return None,
+            b'(' => Token::ParenOpen,
+            b')' => Token::ParenClose,
+            b',' => Token::Comma,
+            b if is_numberlike(b) => {
+                let text = self.read_until_whitespace();
+                if text == b"-" || text == b"+" {
+                    return Some(Err(ParseWktError::UnexpectedToken {
+                        expected: "Dangling sign not attached to a number",
+                        found: snippet(text),
+                        offset: self.token_start,
+                    }));
+                }
+                // Not every `FromStr` accepts a leading `+`, so strip it, but only from a number
+                // which isn't signed again, or `+-1` would read as `-1`
+                let number = match text {
+                    [b'+', b'+' | b'-', ..] => {
+                        return Some(Err(ParseWktError::InvalidNumber {
+                            found: snippet(text),
+                            offset: self.token_start,
+                        }));
+                    }
+                    [b'+', rest @ ..] => rest,
+                    _ => text,
+                };
+                match str::from_utf8(number)
+                    .ok()
+                    .and_then(|n| n.parse::<T>().ok())
+                {
+                    Some(_) if !self.allow_nonfinite && is_nonfinite_literal(number) => {
+                        return Some(Err(self.nonfinite_error(text)));
+                    }
+                    // Floats parse numbers too large for them as infinity rather than failing
+                    Some(parsed_num)
+                        if !is_nonfinite_literal(number) && self.is_infinite(parsed_num) =>
+                    {
+                        return Some(Err(ParseWktError::NumberOutOfRange {
+                            found: snippet(text),
+                            offset: self.token_start,
+                        }));
+                    }
+                    Some(parsed_num) => Token::Number(parsed_num),
+                    None => {
                         log::warn!(
                             "Failed to parse input: '{}' as {}",
-                            &number,
+                            String::from_utf8_lossy(number),
                             type_name::<T>()
                         );
-                        return Some(Err(
-                            "Unable to parse input number as the desired output type",
-                        ));
+                        return Some(Err(ParseWktError::InvalidNumber {
+                            found: snippet(number),
+                            offset: self.token_start,
+                        }));
+                    }
+                }
+            }
+            _ => {
+                let text = self.read_until_whitespace();
+                // An unsigned `inf` or `NaN` reads as a word, but is only ever meant as a number
+                if is_nonfinite_literal(text) {
+                    let parsed = str::from_utf8(text).ok().and_then(|n| n.parse::<T>().ok());
+                    match parsed {
+                        Some(parsed_num) if self.allow_nonfinite => Token::Number(parsed_num),
+                        _ => return Some(Err(self.nonfinite_error(text))),
                     }
+                } else {
+                    Token::Word(String::from_utf8_lossy(text).into_owned())
                 }
             }
-            c => Token::Word(self.read_until_whitespace(Some(c))),
         };
         Some(Ok(token))
     }
 }
 
-impl<T> Tokens<'_, T>
+impl<'a, T> Tokens<'a, T>
 where
-    T: str::FromStr,
+    T: WktNum + str::FromStr,
 {
-    fn read_until_whitespace(&mut self, first_char: Option<char>) -> String {
-        let mut result = String::with_capacity(12); // Big enough for most tokens
-        if let Some(c) = first_char {
-            result.push(c);
-        }
-
-        while let Some(&next_char) = self.chars.peek() {
-            match next_char {
-                '\0' | '(' | ')' | ',' => break, // Just stop on a marker
-                c if is_whitespace(c) => {
-                    let _ = self.chars.next();
-                    break;
-                }
-                _ => {
-                    result.push(next_char);
-                    let _ = self.chars.next();
-                }
+    /// Whether `number` is an infinity of `T`. Types without one, such as integers, fail to parse
+    /// numbers they can't hold instead.
+    fn is_infinite(&mut self, number: T) -> bool {
+        let (infinity, neg_infinity) = *self
+            .infinities
+            .get_or_insert_with(|| ("inf".parse().ok(), "-inf".parse().ok()));
+        Some(number) == infinity || Some(number) == neg_infinity
+    }
+
+    fn nonfinite_error(&self, text: &[u8]) -> ParseWktError {
+        ParseWktError::NonFiniteNumber {
+            found: snippet(text),
+            offset: self.token_start,
+        }
+    }
+
+    /// Read the rest of the token which started at `token_start`, returning all of its bytes.
+    fn read_until_whitespace(&mut self) -> &'a [u8] {
+        while let Some(&next_byte) = self.input.get(self.offset) {
+            match next_byte {
+                // Just stop on a marker, leaving it (or the whitespace) for the next token
+                b'\0' | b'(' | b')' | b',' => break,
+                b if is_whitespace(b) => break,
+                _ => self.offset += 1,
             }
         }
 
-        result
+        &self.input[self.token_start..self.offset]
     }
 }
 
@@ -176,6 +546,44 @@ fn test_tokenizer_1number_plus() {
     assert_eq!(tokens, vec![Token::Number(4.2)]);
 }
 
+#[test]
+fn test_tokenizer_number_forms() {
+    let test_str = "+1 .5 1. +.5 -.5 -1. 0.";
+    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
+    let numbers = [1., 0.5, 1., 0.5, -0.5, -1., 0.];
+    assert_eq!(tokens.unwrap(), numbers.map(Token::Number));
+
+    for (test_str, offset) in [("+-1", 0), ("1 ++1", 2), (".", 0), ("+.", 0)] {
+        let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
+        assert!(
+            matches!(tokens, Err(ParseWktError::InvalidNumber { offset: o, .. }) if o == offset),
+            "{test_str}"
+        );
+    }
+}
+
+#[test]
+fn test_tokenizer_number_out_of_range() {
+    let tokens: Result<Vec<Token<f32>>, _> = Tokens::from_str("1 1e40").collect();
+    assert_eq!(
+        tokens.unwrap_err(),
+        ParseWktError::NumberOutOfRange {
+            found: "1e40".to_string(),
+            offset: 2,
+        }
+    );
+    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str("1e40 -1e309").collect();
+    assert_eq!(
+        tokens.unwrap_err(),
+        ParseWktError::NumberOutOfRange {
+            found: "-1e309".to_string(),
+            offset: 5,
+        }
+    );
+    let tokens: Result<Vec<Token<i8>>, _> = Tokens::from_str("300").collect();
+    assert!(matches!(tokens, Err(ParseWktError::InvalidNumber { .. })));
+}
+
 #[test]
 fn test_tokenizer_invalid_number() {
     let test_str = "4.2p";
@@ -183,7 +591,10 @@ fn test_tokenizer_invalid_number() {
     let tokens = tokens.unwrap_err();
     assert_eq!(
         tokens,
-        "Unable to parse input number as the desired output type"
+        ParseWktError::InvalidNumber {
+            found: "4.2p".to_string(),
+            offset: 0,
+        }
     );
 }
 
@@ -203,6 +614,50 @@ fn test_tokenizer_2numbers() {
     assert_eq!(tokens, vec![Token::Number(0.4), Token::Number(-2.0)]);
 }
 
+#[test]
+fn test_tokenizer_exponent() {
+    let test_str = "1E6 2e-3 -4E+2";
+    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
+    let tokens = tokens.unwrap();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Number(1_000_000.0),
+            Token::Number(0.002),
+            Token::Number(-400.0),
+        ]
+    );
+}
+
+#[test]
+fn test_tokenizer_exponent_forms() {
+    let test_str = "1.5e10 -2.3E-4 +7e+2 .5E1";
+    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
+    assert_eq!(
+        tokens.unwrap(),
+        vec![
+            Token::Number(1.5e10),
+            Token::Number(-2.3e-4),
+            Token::Number(700.0),
+            Token::Number(5.0),
+        ]
+    );
+
+    // The sign of an exponent stays with its number, even right before a marker
+    let test_str = "(1e-4,2E+1)";
+    let tokens: Result<Vec<Token<f64>>, _> = Tokens::from_str(test_str).collect();
+    assert_eq!(
+        tokens.unwrap(),
+        vec![
+            Token::ParenOpen,
+            Token::Number(1e-4),
+            Token::Comma,
+            Token::Number(20.0),
+            Token::ParenClose,
+        ]
+    );
+}
+
 #[test]
 fn test_no_stack_overflow() {
     fn check(c: &str, count: usize, expected: usize) {
@@ -219,12 +674,92 @@ fn test_no_stack_overflow() {
     check("+", count, 0);
     check(" ", count, 0);
     check("A", count, 1);
-    check("1", count, 1);
+    // A hundred thousand digits overflow to infinity, which is rejected
+    check("1", count, 0);
     check("(", count, count);
     check(")", count, count);
     check(",", count, count);
 }
 
+#[test]
+fn test_peekable_tokens_offset() {
+    let test_str = "POINT (10 -20) rest";
+    let mut tokens = PeekableTokens::<f64>::new(Tokens::from_str(test_str));
+    assert_eq!(tokens.next(), Some(Ok(Token::Word("POINT".to_string()))));
+    assert_eq!(tokens.offset(), 5);
+    assert_eq!(tokens.peek(), Some(&Ok(Token::ParenOpen)));
+    assert_eq!(tokens.offset(), 5);
+    assert_eq!(tokens.nth(3), Some(Ok(Token::ParenClose)));
+    assert_eq!(tokens.offset(), 14);
+}
+
+#[test]
+fn test_peekable_tokens_token_start() {
+    let test_str = "POINT (10\n  -20) rest";
+    let mut tokens = PeekableTokens::<f64>::new(Tokens::from_str(test_str));
+    assert_eq!(tokens.nth(2), Some(Ok(Token::Number(10.0))));
+    assert_eq!(tokens.token_start(), 7);
+    assert_eq!(tokens.peek(), Some(&Ok(Token::Number(-20.0))));
+    assert_eq!(tokens.token_start(), 7);
+    tokens.next();
+    assert_eq!(tokens.token_start(), 12);
+    assert_eq!(
+        tokens.unexpected_token_error("Expected a word"),
+        ParseWktError::UnexpectedToken {
+            expected: "Expected a word",
+            found: "-20".to_string(),
+            offset: 12,
+        }
+    );
+}
+
+#[test]
+fn test_peekable_tokens_count_next_coord_ordinates() {
+    let mut tokens = PeekableTokens::<f64>::new(Tokens::from_str("POLYGON ((+1 -2 +3, 4 5 6))"));
+    tokens.next();
+    assert_eq!(tokens.peek(), Some(&Ok(Token::ParenOpen)));
+    assert_eq!(tokens.count_next_coord_ordinates(), 3);
+    assert_eq!(tokens.next(), Some(Ok(Token::ParenOpen)));
+
+    let tokens = PeekableTokens::<f64>::new(Tokens::from_str("(POINT (1 2))"));
+    assert_eq!(tokens.count_next_coord_ordinates(), 0);
+}
+
+#[test]
+fn test_peekable_tokens_depth() {
+    let mut tokens = PeekableTokens::<f64>::new(Tokens::from_str("((1) 2"));
+    assert_eq!(tokens.depth(), 0);
+    tokens.nth(1);
+    assert_eq!(tokens.depth(), 2);
+    tokens.nth(1);
+    assert_eq!(tokens.depth(), 1);
+    assert_eq!(
+        tokens.unbalanced_parens_error().to_string(),
+        "Unbalanced parentheses (expected 1 more ')') at offset 6"
+    );
+}
+
+#[test]
+fn test_peekable_tokens_count_items_hint() {
+    let mut tokens = PeekableTokens::<f64>::new(Tokens::from_str("((1 2,3 4),(5 6)),(7 8)"));
+    tokens.next();
+    assert_eq!(tokens.count_items_hint(), 2);
+    tokens.next();
+    assert_eq!(tokens.count_items_hint(), 2);
+    tokens.peek();
+    assert_eq!(tokens.count_items_hint(), 2);
+
+    // Commas alone can't reserve more items than the input could hold
+    let input = format!("LINESTRING({}", ",".repeat(1_000_000));
+    let mut tokens = PeekableTokens::<f64>::new(Tokens::from_str(&input));
+    tokens.nth(1);
+    assert_eq!(tokens.count_items_hint(), MAX_ITEMS_HINT);
+    let input = format!("LINESTRING({}", ",".repeat(1_000));
+    let mut tokens = PeekableTokens::<f64>::new(Tokens::from_str(&input));
+    tokens.nth(1);
+    assert_eq!(tokens.count_items_hint(), 251);
+}
+
 #[test]
 fn test_tokenizer_point() {
     let test_str = "POINT (10 -20)";
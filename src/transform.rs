@@ -0,0 +1,741 @@
+//! Transformations which consume a [`Wkt`] geometry and return a cleaned up copy or its parts.
+
+use std::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::types::{
+    Coord, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+use crate::{Wkt, WktNum};
+
+/// Which steps [`Wkt::normalize`] applies. All are enabled by default, winding exterior rings
+/// clockwise; [`NormalizeOptions::right_hand_rule`] only closes and orients rings, winding
+/// exterior rings counter-clockwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    /// Close polygon rings whose last coordinate differs from their first.
+    pub close_rings: bool,
+    /// Wind rings in the xy plane: exterior rings as [`exterior_orientation`] says, and interior
+    /// rings the other way.
+    ///
+    /// [`exterior_orientation`]: NormalizeOptions::exterior_orientation
+    pub orient_rings: bool,
+    /// Which way [`orient_rings`](NormalizeOptions::orient_rings) winds exterior rings.
+    pub exterior_orientation: RingOrientation,
+    /// Sort the parts of multi-geometries by their coordinates.
+    pub sort_parts: bool,
+    /// Remove empty members, as in [`Wkt::strip_empties`].
+    pub strip_empties: bool,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        NormalizeOptions {
+            close_rings: true,
+            orient_rings: true,
+            exterior_orientation: RingOrientation::Clockwise,
+            sort_parts: true,
+            strip_empties: true,
+        }
+    }
+}
+
+impl NormalizeOptions {
+    /// Close rings and wind them by the right-hand rule, exterior rings counter-clockwise and
+    /// interior rings clockwise, leaving the order of parts and any empty members alone. This is
+    /// what [`Wkt::normalized`] applies.
+    pub fn right_hand_rule() -> Self {
+        NormalizeOptions {
+            close_rings: true,
+            orient_rings: true,
+            exterior_orientation: RingOrientation::CounterClockwise,
+            sort_parts: false,
+            strip_empties: false,
+        }
+    }
+}
+
+/// The winding of a polygon ring in the xy plane, for [`NormalizeOptions`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RingOrientation {
+    /// Exterior rings clockwise and interior rings counter-clockwise, as in shapefiles.
+    #[default]
+    Clockwise,
+    /// Exterior rings counter-clockwise and interior rings clockwise, following the right-hand
+    /// rule required by e.g. GeoJSON and some spatial databases.
+    CounterClockwise,
+}
+
+/// Twice the signed area of `ring` projected onto the xy plane, positive if it is wound
+/// counter-clockwise.
+fn signed_area_2x<T: WktNum + Float>(ring: &[Coord<T>]) -> T {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .fold(T::zero(), |area, (a, b)| area + (a.x * b.y - b.x * a.y))
+}
+
+fn cmp_ordinates<T: WktNum + Float>(a: T, b: T) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+fn cmp_coords<T: WktNum + Float>(a: &Coord<T>, b: &Coord<T>) -> Ordering {
+    cmp_ordinates(a.x, b.x)
+        .then_with(|| cmp_ordinates(a.y, b.y))
+        .then_with(|| cmp_optional_ordinates(a.z, b.z))
+        .then_with(|| cmp_optional_ordinates(a.m, b.m))
+}
+
+/// Orders a missing ordinate before any present one.
+fn cmp_optional_ordinates<T: WktNum + Float>(a: Option<T>, b: Option<T>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => cmp_ordinates(a, b),
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    }
+}
+
+fn cmp_points<T: WktNum + Float>(a: &Point<T>, b: &Point<T>) -> Ordering {
+    match (&a.0, &b.0) {
+        (Some(a), Some(b)) => cmp_coords(a, b),
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    }
+}
+
+fn cmp_line_strings<T: WktNum + Float>(a: &LineString<T>, b: &LineString<T>) -> Ordering {
+    a.0.iter()
+        .zip(&b.0)
+        .map(|(a, b)| cmp_coords(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.0.len().cmp(&b.0.len()))
+}
+
+fn cmp_polygons<T: WktNum + Float>(a: &Polygon<T>, b: &Polygon<T>) -> Ordering {
+    a.0.iter()
+        .zip(&b.0)
+        .map(|(a, b)| cmp_line_strings(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.0.len().cmp(&b.0.len()))
+}
+
+/// Whether `a` and `b` have the same ordinates, each to within `epsilon`.
+fn coords_within<T: WktNum + Float>(a: &Coord<T>, b: &Coord<T>, epsilon: T) -> bool {
+    let within = |a: T, b: T| (a - b).abs() <= epsilon;
+    let optional_within = |a: Option<T>, b: Option<T>| match (a, b) {
+        (Some(a), Some(b)) => within(a, b),
+        (a, b) => a.is_none() && b.is_none(),
+    };
+    within(a.x, b.x) && within(a.y, b.y) && optional_within(a.z, b.z) && optional_within(a.m, b.m)
+}
+
+/// Close `ring` by repeating its first coordinate, unless it is already closed to within
+/// `epsilon`. A ring closed only to within `epsilon` has its last coordinate replaced by its first,
+/// so it is exactly closed afterwards.
+fn close_ring<T: WktNum + Float>(ring: &mut LineString<T>, epsilon: Option<T>) {
+    let closed = ring.is_closed(epsilon);
+    if let (Some(first), Some(last)) = (ring.0.first().cloned(), ring.0.last_mut()) {
+        if !closed {
+            ring.0.push(first);
+        } else if *last != first {
+            *last = first;
+        }
+    }
+}
+
+fn normalize_polygon<T: WktNum + Float>(polygon: &mut Polygon<T>, opts: &NormalizeOptions) {
+    for (i, ring) in polygon.0.iter_mut().enumerate() {
+        if opts.close_rings {
+            close_ring(ring, None);
+        }
+        if opts.orient_rings {
+            let area = signed_area_2x(&ring.0);
+            let clockwise = (i == 0) == (opts.exterior_orientation == RingOrientation::Clockwise);
+            if (clockwise && area > T::zero()) || (!clockwise && area < T::zero()) {
+                ring.0.reverse();
+            }
+        }
+    }
+}
+
+/// The distance from `p` to the line through `a` and `b`, or to `a` if they coincide. A missing z
+/// is taken as zero.
+fn distance_to_line<T: WktNum + Float>(p: &Coord<T>, a: &Coord<T>, b: &Coord<T>) -> T {
+    let vector = |from: &Coord<T>, to: &Coord<T>| {
+        [
+            to.x - from.x,
+            to.y - from.y,
+            to.z.unwrap_or_else(T::zero) - from.z.unwrap_or_else(T::zero),
+        ]
+    };
+    let length = |[x, y, z]: [T; 3]| (x * x + y * y + z * z).sqrt();
+    let [dx, dy, dz] = vector(a, b);
+    let [vx, vy, vz] = vector(a, p);
+    let line_length = length([dx, dy, dz]);
+    if line_length == T::zero() {
+        return length([vx, vy, vz]);
+    }
+    length([dy * vz - dz * vy, dz * vx - dx * vz, dx * vy - dy * vx]) / line_length
+}
+
+/// Drop each interior vertex of `line_string` that lies within `tolerance` of the line from the
+/// previous kept vertex to the next vertex. The first and last vertices are always kept, so a
+/// closed ring stays closed.
+fn remove_collinear_vertices<T: WktNum + Float>(line_string: &mut LineString<T>, tolerance: T) {
+    let coords = &mut line_string.0;
+    if coords.len() < 3 {
+        return;
+    }
+    let mut kept = 1;
+    for i in 1..coords.len() - 1 {
+        if distance_to_line(&coords[i], &coords[kept - 1], &coords[i + 1]) > tolerance {
+            coords.swap(kept, i);
+            kept += 1;
+        }
+    }
+    let last = coords.len() - 1;
+    coords.swap(kept, last);
+    coords.truncate(kept + 1);
+}
+
+impl<T: WktNum> Wkt<T> {
+    /// Remove empty members from multi-geometries and geometry collections, recursively.
+    ///
+    /// A collection whose members were all empty becomes an empty collection. Single geometries
+    /// are returned unchanged, even if they are empty.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> =
+    ///     Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z EMPTY,POINT Z(1 2 3))").unwrap();
+    /// assert_eq!(wkt.strip_empties().to_string(), "GEOMETRYCOLLECTION Z(POINT Z(1 2 3))");
+    /// ```
+    pub fn strip_empties(self) -> Wkt<T> {
+        match self {
+            Wkt::MultiPoint(multi_point) => Wkt::MultiPoint(MultiPoint(
+                multi_point
+                    .0
+                    .into_iter()
+                    .filter(|point| point.0.is_some())
+                    .collect(),
+                multi_point.1,
+            )),
+            Wkt::MultiLineString(multi_line_string) => Wkt::MultiLineString(MultiLineString(
+                multi_line_string
+                    .0
+                    .into_iter()
+                    .filter(|line_string: &LineString<T>| !line_string.0.is_empty())
+                    .collect(),
+                multi_line_string.1,
+            )),
+            Wkt::MultiPolygon(multi_polygon) => Wkt::MultiPolygon(MultiPolygon(
+                multi_polygon
+                    .0
+                    .into_iter()
+                    .filter(|polygon: &Polygon<T>| !polygon.0.is_empty())
+                    .collect(),
+                multi_polygon.1,
+            )),
+            Wkt::GeometryCollection(collection) => Wkt::GeometryCollection(GeometryCollection(
+                collection
+                    .0
+                    .into_iter()
+                    .map(Wkt::strip_empties)
+                    .filter(|geometry| !geometry.is_empty())
+                    .collect(),
+                collection.1,
+            )),
+            other => other,
+        }
+    }
+
+    /// Split this geometry into its parts, each as an owned [`Wkt`].
+    ///
+    /// Single geometries yield themselves, multi-geometries yield each part as the matching single
+    /// geometry, and collections yield their members, which are not split any further.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("MULTIPOINT Z(1 2 3,4 5 6)").unwrap();
+    /// let parts: Vec<String> = wkt.into_geometries().map(|part| part.to_string()).collect();
+    /// assert_eq!(parts, ["POINT Z(1 2 3)", "POINT Z(4 5 6)"]);
+    /// ```
+    pub fn into_geometries(self) -> impl Iterator<Item = Wkt<T>> {
+        let parts: Vec<Wkt<T>> = match self {
+            Wkt::MultiPoint(multi_point) => multi_point.0.into_iter().map(Wkt::Point).collect(),
+            Wkt::MultiLineString(multi_line_string) => multi_line_string
+                .0
+                .into_iter()
+                .map(Wkt::LineString)
+                .collect(),
+            Wkt::MultiPolygon(multi_polygon) => {
+                multi_polygon.0.into_iter().map(Wkt::Polygon).collect()
+            }
+            Wkt::GeometryCollection(collection) => collection.0,
+            single => vec![single],
+        };
+        parts.into_iter()
+    }
+
+    /// Bring this geometry into a canonical form for comparison or storage, applying each step
+    /// enabled in `opts`.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::{NormalizeOptions, Wkt};
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POLYGON Z((0 0 0,1 0 0,1 1 0))").unwrap();
+    /// assert_eq!(
+    ///     wkt.normalize(NormalizeOptions::default()).to_string(),
+    ///     "POLYGON Z((0 0 0,1 1 0,1 0 0,0 0 0))"
+    /// );
+    /// ```
+    pub fn normalize(self, opts: NormalizeOptions) -> Wkt<T>
+    where
+        T: Float,
+    {
+        let mut wkt = if opts.strip_empties {
+            self.strip_empties()
+        } else {
+            self
+        };
+
+        match &mut wkt {
+            Wkt::Polygon(polygon) => normalize_polygon(polygon, &opts),
+            Wkt::MultiPoint(multi_point) => {
+                if opts.sort_parts {
+                    multi_point.0.sort_by(cmp_points);
+                }
+            }
+            Wkt::MultiLineString(multi_line_string) => {
+                if opts.sort_parts {
+                    multi_line_string.sort_parts();
+                }
+            }
+            Wkt::MultiPolygon(multi_polygon) => {
+                for polygon in &mut multi_polygon.0 {
+                    normalize_polygon(polygon, &opts);
+                }
+                if opts.sort_parts {
+                    multi_polygon.sort_parts();
+                }
+            }
+            Wkt::GeometryCollection(collection) => {
+                collection.0 = std::mem::take(&mut collection.0)
+                    .into_iter()
+                    .map(|geometry| geometry.normalize(opts))
+                    .collect();
+            }
+            Wkt::Point(_) | Wkt::LineString(_) | Wkt::LinearRing(_) => (),
+        }
+        wkt
+    }
+
+    /// A copy of this geometry with its polygon rings closed and wound by the right-hand rule, as
+    /// [`NormalizeOptions::right_hand_rule`] describes, for engines which require it.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POLYGON((0 0,0 1,1 1))").unwrap();
+    /// assert_eq!(wkt.normalized().to_string(), "POLYGON((0 0,1 1,0 1,0 0))");
+    /// ```
+    pub fn normalized(&self) -> Wkt<T>
+    where
+        T: Float,
+    {
+        self.clone().normalize(NormalizeOptions::right_hand_rule())
+    }
+
+    /// Remove redundant vertices from every line string and ring: those lying within `tolerance`
+    /// of the straight line between their neighbours. The ends of each line string are kept, so
+    /// rings stay closed.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("LINESTRING Z(0 0 0,1 1 1,2 2 2,2 3 2)").unwrap();
+    /// assert_eq!(wkt.remove_collinear(0.).to_string(), "LINESTRING Z(0 0 0,2 2 2,2 3 2)");
+    /// ```
+    pub fn remove_collinear(self, tolerance: T) -> Wkt<T>
+    where
+        T: Float,
+    {
+        let mut wkt = self;
+        match &mut wkt {
+            Wkt::LineString(line_string) | Wkt::LinearRing(line_string) => {
+                remove_collinear_vertices(line_string, tolerance)
+            }
+            Wkt::Polygon(polygon) => {
+                for ring in &mut polygon.0 {
+                    remove_collinear_vertices(ring, tolerance);
+                }
+            }
+            Wkt::MultiLineString(multi_line_string) => {
+                for line_string in &mut multi_line_string.0 {
+                    remove_collinear_vertices(line_string, tolerance);
+                }
+            }
+            Wkt::MultiPolygon(multi_polygon) => {
+                for ring in multi_polygon
+                    .0
+                    .iter_mut()
+                    .flat_map(|polygon| &mut polygon.0)
+                {
+                    remove_collinear_vertices(ring, tolerance);
+                }
+            }
+            Wkt::GeometryCollection(collection) => {
+                collection.0 = std::mem::take(&mut collection.0)
+                    .into_iter()
+                    .map(|geometry| geometry.remove_collinear(tolerance))
+                    .collect();
+            }
+            Wkt::Point(_) | Wkt::MultiPoint(_) => (),
+        }
+        wkt
+    }
+
+    /// Close every polygon ring and `LINEARRING` whose last coordinate differs from its first by
+    /// repeating the first coordinate.
+    ///
+    /// With an `epsilon`, a ring whose ends differ by no more than `epsilon` in each ordinate is
+    /// treated as closed, and its last coordinate is snapped to its first. Without one, the ends
+    /// must be equal.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POLYGON((0 0,1 0,1 1,0.001 0))").unwrap();
+    /// assert_eq!(
+    ///     wkt.clone().ensure_closed_rings(None).to_string(),
+    ///     "POLYGON((0 0,1 0,1 1,0.001 0,0 0))"
+    /// );
+    /// assert_eq!(
+    ///     wkt.ensure_closed_rings(Some(0.01)).to_string(),
+    ///     "POLYGON((0 0,1 0,1 1,0 0))"
+    /// );
+    /// ```
+    pub fn ensure_closed_rings(self, epsilon: Option<T>) -> Wkt<T>
+    where
+        T: Float,
+    {
+        let mut wkt = self;
+        match &mut wkt {
+            Wkt::LinearRing(ring) => close_ring(ring, epsilon),
+            Wkt::Polygon(polygon) => {
+                for ring in &mut polygon.0 {
+                    close_ring(ring, epsilon);
+                }
+            }
+            Wkt::MultiPolygon(multi_polygon) => {
+                for ring in multi_polygon
+                    .0
+                    .iter_mut()
+                    .flat_map(|polygon| &mut polygon.0)
+                {
+                    close_ring(ring, epsilon);
+                }
+            }
+            Wkt::GeometryCollection(collection) => {
+                collection.0 = std::mem::take(&mut collection.0)
+                    .into_iter()
+                    .map(|geometry| geometry.ensure_closed_rings(epsilon))
+                    .collect();
+            }
+            Wkt::Point(_) | Wkt::LineString(_) | Wkt::MultiPoint(_) | Wkt::MultiLineString(_) => (),
+        }
+        wkt
+    }
+}
+
+impl<T: WktNum + Float> LineString<T> {
+    /// Whether the last coordinate of this line string is the same as its first. An empty line
+    /// string is closed.
+    ///
+    /// With an `epsilon`, ends which differ by no more than `epsilon` in each ordinate count as
+    /// the same, which allows for rounding in data that has been through text. Either way, the
+    /// ends must have the same dimensions.
+    pub fn is_closed(&self, epsilon: Option<T>) -> bool {
+        match (self.0.first(), self.0.last(), epsilon) {
+            (Some(first), Some(last), Some(epsilon)) => coords_within(first, last, epsilon),
+            (first, last, _) => first == last,
+        }
+    }
+}
+
+impl<T: WktNum + Float> MultiLineString<T> {
+    /// Order the member line strings lexicographically by their coordinates, so the same set of
+    /// parts always comes out in the same order.
+    pub fn sort_parts(&mut self) {
+        self.0.sort_by(cmp_line_strings);
+    }
+}
+
+impl<T: WktNum + Float> MultiPolygon<T> {
+    /// Order the member polygons lexicographically by their rings' coordinates, starting with the
+    /// exterior, so the same set of parts always comes out in the same order.
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    ///
+    /// let wkt = "MULTIPOLYGON(((5 5,6 5,6 6,5 5)),((0 0,1 0,1 1,0 0)))".parse::<Wkt<f64>>();
+    /// let Ok(Wkt::MultiPolygon(mut multi_polygon)) = wkt else { unreachable!() };
+    /// multi_polygon.sort_parts();
+    /// assert_eq!(
+    ///     Wkt::MultiPolygon(multi_polygon).to_string(),
+    ///     "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((5 5,6 5,6 6,5 5)))"
+    /// );
+    /// ```
+    pub fn sort_parts(&mut self) {
+        self.0.sort_by(cmp_polygons);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NormalizeOptions, RingOrientation};
+    use crate::types::{
+        Coord, Dimension, GeometryCollection, MultiLineString, MultiPoint, MultiPolygon, Point,
+    };
+    use crate::Wkt;
+    use std::str::FromStr;
+
+    #[test]
+    fn normalize_equivalent_multipolygons() {
+        let a: Wkt<f64> = Wkt::from_str(
+            "MULTIPOLYGON Z(((0 0 0,0 4 0,4 4 0,4 0 0,0 0 0),(1 1 0,2 1 0,2 2 0,1 1 0)),((10 10 1,11 10 1,11 11 1,10 10 1)))",
+        )
+        .unwrap();
+        // The same polygons in the opposite order, with every ring wound the other way and the
+        // last ring left open.
+        let b: Wkt<f64> = Wkt::from_str(
+            "MULTIPOLYGON Z(((10 10 1,11 11 1,11 10 1)),((0 0 0,4 0 0,4 4 0,0 4 0,0 0 0),(1 1 0,2 2 0,2 1 0,1 1 0)))",
+        )
+        .unwrap();
+        assert_ne!(a, b);
+
+        let a = a.normalize(NormalizeOptions::default());
+        let b = b.normalize(NormalizeOptions::default());
+        assert_eq!(a, b);
+        assert_eq!(
+            a.to_string(),
+            "MULTIPOLYGON Z(((0 0 0,0 4 0,4 4 0,4 0 0,0 0 0),(1 1 0,2 1 0,2 2 0,1 1 0)),((10 10 1,11 11 1,11 10 1,10 10 1)))"
+        );
+    }
+
+    #[test]
+    fn normalize_options_toggle_steps() {
+        let wkt: Wkt<f64> = Wkt::from_str("POLYGON Z((0 0 0,1 0 0,1 1 0))").unwrap();
+        let opts = NormalizeOptions {
+            close_rings: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            wkt.clone().normalize(opts).to_string(),
+            "POLYGON Z((1 1 0,1 0 0,0 0 0))"
+        );
+        let opts = NormalizeOptions {
+            orient_rings: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            wkt.normalize(opts).to_string(),
+            "POLYGON Z((0 0 0,1 0 0,1 1 0,0 0 0))"
+        );
+    }
+
+    #[test]
+    fn normalize_right_hand_rule() {
+        let opts = NormalizeOptions {
+            exterior_orientation: RingOrientation::CounterClockwise,
+            ..Default::default()
+        };
+        // A clockwise exterior with a counter-clockwise hole, the opposite of the right-hand rule
+        let wkt: Wkt<f64> =
+            Wkt::from_str("POLYGON((0 0,0 4,4 4,4 0,0 0),(1 1,2 1,2 2,1 1))").unwrap();
+        let normalized = wkt.normalize(opts);
+        assert_eq!(
+            normalized.to_string(),
+            "POLYGON((0 0,4 0,4 4,0 4,0 0),(1 1,2 2,2 1,1 1))"
+        );
+        // Already normalized rings are left alone
+        assert_eq!(normalized.clone().normalize(opts), normalized);
+
+        // Open rings are closed before they're oriented
+        let wkt: Wkt<f64> = Wkt::from_str("MULTIPOLYGON(((0 0,1 1,1 0)))").unwrap();
+        assert_eq!(
+            wkt.normalize(opts).to_string(),
+            "MULTIPOLYGON(((0 0,1 0,1 1,0 0)))"
+        );
+    }
+
+    #[test]
+    fn normalized_only_orients_and_closes() {
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "MULTIPOLYGON(((5 5,5 6,6 6,5 5)),EMPTY,((0 0,0 4,4 4,4 0),(1 1,2 1,2 2,1 1)))",
+        )
+        .unwrap();
+        assert_eq!(
+            wkt.normalized().to_string(),
+            "MULTIPOLYGON(((5 5,6 6,5 6,5 5)),EMPTY,((0 0,4 0,4 4,0 4,0 0),(1 1,2 2,2 1,1 1)))"
+        );
+        assert_eq!(
+            wkt.normalized(),
+            wkt.normalize(NormalizeOptions::right_hand_rule())
+        );
+    }
+
+    #[test]
+    fn into_geometries_splits_parts() {
+        let wkt: Wkt<f64> = Wkt::from_str(
+            "MULTIPOLYGON Z(((0 0 0,1 0 0,1 1 0,0 0 0)),((5 5 1,6 5 1,6 6 1,5 5 1)))",
+        )
+        .unwrap();
+        let parts: Vec<Wkt<f64>> = wkt.into_geometries().collect();
+        assert_eq!(
+            parts,
+            vec![
+                Wkt::from_str("POLYGON Z((0 0 0,1 0 0,1 1 0,0 0 0))").unwrap(),
+                Wkt::from_str("POLYGON Z((5 5 1,6 5 1,6 6 1,5 5 1))").unwrap(),
+            ]
+        );
+
+        let line: Wkt<f64> = Wkt::from_str("LINESTRING Z(1 2 3,4 5 6)").unwrap();
+        assert_eq!(
+            line.clone().into_geometries().collect::<Vec<_>>(),
+            vec![line]
+        );
+
+        let collection: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(1 2 3),MULTIPOINT Z((4 5 6)))").unwrap();
+        assert_eq!(collection.into_geometries().count(), 2);
+    }
+
+    #[test]
+    fn strip_empties_mixed_collection() {
+        let multi_point = Wkt::MultiPoint(MultiPoint::new(vec![
+            Point::empty(Dimension::XYZ),
+            Point::new(Coord {
+                x: 7.,
+                y: 8.,
+                z: Some(9.),
+                m: None,
+            }),
+        ]));
+        let nested: Wkt<f64> = Wkt::from_str("GEOMETRYCOLLECTION Z(POLYGON Z EMPTY)").unwrap();
+        let wkt = Wkt::GeometryCollection(GeometryCollection::new(vec![
+            Wkt::from_str("POINT Z EMPTY").unwrap(),
+            Wkt::from_str("LINESTRING Z(1 2 3,4 5 6)").unwrap(),
+            multi_point,
+            nested,
+        ]));
+
+        let expected: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION Z(LINESTRING Z(1 2 3,4 5 6),MULTIPOINT Z((7 8 9)))")
+                .unwrap();
+        assert_eq!(wkt.strip_empties(), expected);
+    }
+
+    #[test]
+    fn strip_empties_all_empty() {
+        let wkt: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z EMPTY,MULTILINESTRING Z EMPTY)").unwrap();
+        assert_eq!(
+            wkt.strip_empties(),
+            Wkt::GeometryCollection(GeometryCollection::empty(Dimension::XYZ))
+        );
+    }
+
+    #[test]
+    fn sort_parts_is_independent_of_input_order() {
+        let parse = |wkt: &str| match Wkt::<f64>::from_str(wkt).unwrap() {
+            Wkt::MultiPolygon(multi_polygon) => multi_polygon,
+            _ => unreachable!(),
+        };
+        let mut a: MultiPolygon<f64> = parse(
+            "MULTIPOLYGON Z(((5 5 1,6 5 1,6 6 1,5 5 1)),((0 0 2,1 0 2,1 1 2,0 0 2)),\
+             ((0 0 1,1 0 1,1 1 1,0 0 1)))",
+        );
+        let mut b = parse(
+            "MULTIPOLYGON Z(((0 0 1,1 0 1,1 1 1,0 0 1)),((5 5 1,6 5 1,6 6 1,5 5 1)),\
+             ((0 0 2,1 0 2,1 1 2,0 0 2)))",
+        );
+        a.sort_parts();
+        b.sort_parts();
+        assert_eq!(a, b);
+        assert_eq!(
+            Wkt::MultiPolygon(a).to_string(),
+            "MULTIPOLYGON Z(((0 0 1,1 0 1,1 1 1,0 0 1)),((0 0 2,1 0 2,1 1 2,0 0 2)),\
+             ((5 5 1,6 5 1,6 6 1,5 5 1)))"
+        );
+
+        let mut lines: MultiLineString<f64> =
+            match Wkt::from_str("MULTILINESTRING((3 3,4 4),(1 1,2 2),(1 1,0 0))").unwrap() {
+                Wkt::MultiLineString(lines) => lines,
+                _ => unreachable!(),
+            };
+        lines.sort_parts();
+        assert_eq!(
+            Wkt::MultiLineString(lines).to_string(),
+            "MULTILINESTRING((1 1,0 0),(1 1,2 2),(3 3,4 4))"
+        );
+    }
+
+    #[test]
+    fn remove_collinear_line_and_ring() {
+        let line: Wkt<f64> = Wkt::from_str("LINESTRING(0 0,1 0,2 0,2 1)").unwrap();
+        assert_eq!(
+            line.remove_collinear(0.).to_string(),
+            "LINESTRING(0 0,2 0,2 1)"
+        );
+
+        let polygon: Wkt<f64> =
+            Wkt::from_str("POLYGON Z((0 0 1,1 0 1,2 0 1,2 2 1,0 2 1,0 1 1,0 0 1))").unwrap();
+        assert_eq!(
+            polygon.remove_collinear(0.).to_string(),
+            "POLYGON Z((0 0 1,2 0 1,2 2 1,0 2 1,0 0 1))"
+        );
+
+        let bent: Wkt<f64> = Wkt::from_str("LINESTRING(0 0,1 0.05,2 0)").unwrap();
+        assert_eq!(
+            bent.clone().remove_collinear(0.1).to_string(),
+            "LINESTRING(0 0,2 0)"
+        );
+        assert_eq!(bent.clone().remove_collinear(0.01), bent);
+
+        let collection: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION(POINT(1 1),MULTILINESTRING((0 0,0 1,0 2)))").unwrap();
+        assert_eq!(
+            collection.remove_collinear(0.).to_string(),
+            "GEOMETRYCOLLECTION(POINT(1 1),MULTILINESTRING((0 0,0 2)))"
+        );
+    }
+
+    #[test]
+    fn ring_closed_within_epsilon() {
+        let wkt: Wkt<f64> =
+            Wkt::from_str("POLYGON Z((0 0 1,1 0 1,1 1 1,0.000000000001 -0.0 1))").unwrap();
+        let Wkt::Polygon(polygon) = &wkt else {
+            unreachable!()
+        };
+        assert!(!polygon.0[0].is_closed(None));
+        assert!(polygon.0[0].is_closed(Some(1e-9)));
+        assert!(!polygon.0[0].is_closed(Some(1e-13)));
+
+        assert_eq!(
+            wkt.clone().ensure_closed_rings(Some(1e-9)).to_string(),
+            "POLYGON Z((0 0 1,1 0 1,1 1 1,0 0 1))"
+        );
+        assert_eq!(
+            wkt.ensure_closed_rings(None).to_string(),
+            "POLYGON Z((0 0 1,1 0 1,1 1 1,0.000000000001 -0 1,0 0 1))"
+        );
+    }
+}
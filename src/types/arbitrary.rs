@@ -0,0 +1,229 @@
+//! `Arbitrary` for geometries, for fuzzing code that consumes them. Enabled by the `arbitrary`
+//! feature.
+//!
+//! Every generated geometry is structurally valid WKT: all coordinates of a geometry share its
+//! dimension, line strings have at least two coordinates, polygons have at least one ring, and
+//! rings are closed with at least four coordinates. Multi-geometries and collections may be
+//! empty, and collections are nested at most [`MAX_COLLECTION_DEPTH`] deep. Ordinates are
+//! whatever `T` generates, so float geometries can include NaN and infinite ordinates, which only
+//! parse with [`Wkt::from_str_allow_nonfinite`]. Linear rings are only parsed back as
+//! [`Wkt::LinearRing`] with [`keep_linear_rings`](crate::ParseLimits::keep_linear_rings) set.
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::types::{
+    Coord, Dimension, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use crate::{Wkt, WktNum};
+
+/// How deeply generated `GEOMETRYCOLLECTION`s are nested, well within the parser's default limit.
+const MAX_COLLECTION_DEPTH: usize = 4;
+
+impl<'a> Arbitrary<'a> for Dimension {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[
+            Dimension::XY,
+            Dimension::XYZ,
+            Dimension::XYM,
+            Dimension::XYZM,
+        ])?)
+    }
+}
+
+fn coord<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+) -> Result<Coord<T>> {
+    let x = u.arbitrary()?;
+    let y = u.arbitrary()?;
+    let z = match dim {
+        Dimension::XYZ | Dimension::XYZM => Some(u.arbitrary()?),
+        Dimension::XY | Dimension::XYM => None,
+    };
+    let m = match dim {
+        Dimension::XYM | Dimension::XYZM => Some(u.arbitrary()?),
+        Dimension::XY | Dimension::XYZ => None,
+    };
+    Ok(Coord { x, y, z, m })
+}
+
+/// At least `min_len` coordinates of dimension `dim`.
+fn coords<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+    min_len: usize,
+) -> Result<Vec<Coord<T>>> {
+    let len = min_len + u.arbitrary_len::<Coord<T>>()?;
+    (0..len).map(|_| coord(u, dim)).collect()
+}
+
+fn point<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+    allow_empty: bool,
+) -> Result<Point<T>> {
+    if allow_empty && u.ratio(1, 8)? {
+        Ok(Point::empty(dim))
+    } else {
+        Ok(Point(Some(coord(u, dim)?), dim))
+    }
+}
+
+fn line_string<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+) -> Result<LineString<T>> {
+    Ok(LineString(coords(u, dim, 2)?, dim))
+}
+
+/// A closed ring of at least four coordinates.
+fn ring<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+) -> Result<LineString<T>> {
+    let mut coords = coords(u, dim, 3)?;
+    coords.push(coords[0].clone());
+    Ok(LineString(coords, dim))
+}
+
+fn polygon<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+) -> Result<Polygon<T>> {
+    let len = 1 + u.arbitrary_len::<Coord<T>>()?;
+    let rings = (0..len).map(|_| ring(u, dim));
+    Ok(Polygon(rings.collect::<Result<_>>()?, dim))
+}
+
+fn multi_point<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+) -> Result<MultiPoint<T>> {
+    let len = u.arbitrary_len::<Coord<T>>()?;
+    let points = (0..len).map(|_| point(u, dim, false));
+    Ok(MultiPoint(points.collect::<Result<_>>()?, dim))
+}
+
+fn multi_line_string<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+) -> Result<MultiLineString<T>> {
+    let len = u.arbitrary_len::<Coord<T>>()?;
+    let line_strings = (0..len).map(|_| line_string(u, dim));
+    Ok(MultiLineString(line_strings.collect::<Result<_>>()?, dim))
+}
+
+fn multi_polygon<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+) -> Result<MultiPolygon<T>> {
+    let len = u.arbitrary_len::<Coord<T>>()?;
+    let polygons = (0..len).map(|_| polygon(u, dim));
+    Ok(MultiPolygon(polygons.collect::<Result<_>>()?, dim))
+}
+
+fn collection<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+    depth: usize,
+) -> Result<GeometryCollection<T>> {
+    let len = u.arbitrary_len::<Coord<T>>()?;
+    let geometries = (0..len).map(|_| geometry(u, dim, depth + 1));
+    Ok(GeometryCollection(geometries.collect::<Result<_>>()?, dim))
+}
+
+/// A geometry of dimension `dim`, inside `depth` collections.
+fn geometry<'a, T: WktNum + Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    dim: Dimension,
+    depth: usize,
+) -> Result<Wkt<T>> {
+    let kinds = if depth < MAX_COLLECTION_DEPTH { 8 } else { 7 };
+    let wkt = match u.choose_index(kinds)? {
+        0 => Wkt::Point(point(u, dim, true)?),
+        1 => Wkt::LineString(line_string(u, dim)?),
+        2 => Wkt::LinearRing(ring(u, dim)?),
+        3 => Wkt::Polygon(polygon(u, dim)?),
+        4 => Wkt::MultiPoint(multi_point(u, dim)?),
+        5 => Wkt::MultiLineString(multi_line_string(u, dim)?),
+        6 => Wkt::MultiPolygon(multi_polygon(u, dim)?),
+        _ => Wkt::GeometryCollection(collection(u, dim, depth)?),
+    };
+    Ok(wkt)
+}
+
+/// Implements `Arbitrary` for `$type` by generating it in an arbitrary dimension with `$generate`.
+macro_rules! impl_arbitrary {
+    ($($type:ident => |$u:ident, $dim:ident| $generate:expr;)*) => {
+        $(
+            impl<'a, T: WktNum + Arbitrary<'a>> Arbitrary<'a> for $type<T> {
+                fn arbitrary($u: &mut Unstructured<'a>) -> Result<Self> {
+                    let $dim = $u.arbitrary()?;
+                    $generate
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary! {
+    Coord => |u, dim| coord(u, dim);
+    Point => |u, dim| point(u, dim, true);
+    LineString => |u, dim| line_string(u, dim);
+    Polygon => |u, dim| polygon(u, dim);
+    MultiPoint => |u, dim| multi_point(u, dim);
+    MultiLineString => |u, dim| multi_line_string(u, dim);
+    MultiPolygon => |u, dim| multi_polygon(u, dim);
+    GeometryCollection => |u, dim| collection(u, dim, 0);
+    Wkt => |u, dim| geometry(u, dim, 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    use crate::types::Polygon;
+    use crate::{ParseLimits, Wkt};
+
+    /// Pseudo-random bytes, so the test doesn't depend on a random number generator.
+    fn bytes(seed: u32, len: usize) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (state >> 16) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn arbitrary_geometries_round_trip() {
+        let limits = ParseLimits {
+            keep_linear_rings: true,
+            ..Default::default()
+        };
+        for seed in 0..200 {
+            let data = bytes(seed, 512);
+            let mut u = Unstructured::new(&data);
+            let wkt = Wkt::<i32>::arbitrary(&mut u).unwrap();
+            let reparsed = Wkt::from_str_with_limits(&wkt.to_string(), &limits);
+            assert_eq!(reparsed, Ok(wkt), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn arbitrary_polygons_have_closed_rings() {
+        for seed in 0..50 {
+            let data = bytes(seed, 256);
+            let polygon = Polygon::<f64>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            assert!(!polygon.0.is_empty());
+            for ring in &polygon.0 {
+                assert!(ring.0.len() >= 4);
+                let (first, last) = (&ring.0[0], &ring.0[ring.0.len() - 1]);
+                assert_eq!(first.x.to_bits(), last.x.to_bits());
+                assert_eq!(first.y.to_bits(), last.y.to_bits());
+            }
+        }
+    }
+}
@@ -14,11 +14,16 @@
 
 use geo_traits::CoordTrait;
 
+use crate::error::ParseWktError;
 use crate::tokenizer::{PeekableTokens, Token};
 use crate::types::Dimension;
 use crate::{FromTokens, WktNum};
 use std::str::FromStr;
 
+/// A coordinate with `x` and `y` and an optional `z` and `m`.
+///
+/// Its [`CoordTrait::dim`] follows which of `z` and `m` are present, and [`CoordTrait::z`] panics
+/// when `z` is missing rather than inventing a zero, so check the dimension or read `z` directly.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Coord<T>
 where
@@ -26,34 +31,100 @@ where
 {
     pub x: T,
     pub y: T,
-    pub z: T,
+    pub z: Option<T>,
+    pub m: Option<T>,
+}
+
+impl<T: WktNum> Coord<T> {
+    fn geo_dim(&self) -> geo_traits::Dimensions {
+        self.dimension().into()
+    }
+
+    /// The dimension given by which of `z` and `m` this coordinate has.
+    pub(crate) fn dimension(&self) -> Dimension {
+        match (self.z.is_some(), self.m.is_some()) {
+            (false, false) => Dimension::XY,
+            (true, false) => Dimension::XYZ,
+            (false, true) => Dimension::XYM,
+            (true, true) => Dimension::XYZM,
+        }
+    }
+
+    /// The ordinate at position `n`, counting only the ordinates this coordinate has, so `2`
+    /// is `m` for a measured coordinate without `z`.
+    fn nth(&self, n: usize) -> Option<T> {
+        match n {
+            0 => Some(self.x),
+            1 => Some(self.y),
+            2 => self.z.or(self.m),
+            3 => self.z.and(self.m),
+            _ => None,
+        }
+    }
+}
+
+/// Read the next ordinate of a coordinate in a geometry of dimension `dim`, failing as
+/// [`next_number`] does unless the coordinate has ended early.
+fn next_ordinate<T>(
+    tokens: &mut PeekableTokens<T>,
+    dim: Dimension,
+    expected: &'static str,
+) -> Result<T, ParseWktError>
+where
+    T: WktNum + FromStr,
+{
+    if let Some(Ok(Token::Comma | Token::ParenClose)) = tokens.peek() {
+        tokens.next();
+        return Err(tokens.inconsistent_dimension_error(dim));
+    }
+    next_number(tokens, expected)
+}
+
+/// Read the next token as a number, failing with `expected` if it's anything else.
+fn next_number<T>(
+    tokens: &mut PeekableTokens<T>,
+    expected: &'static str,
+) -> Result<T, ParseWktError>
+where
+    T: WktNum + FromStr,
+{
+    match tokens.next().transpose()? {
+        Some(Token::Number(n)) => Ok(n),
+        Some(_) => Err(tokens.unexpected_token_error(expected)),
+        None => Err(tokens.unexpected_end_error(expected)),
+    }
 }
 
 impl<T> FromTokens<T> for Coord<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
-        let x = match tokens.next().transpose()? {
-            Some(Token::Number(n)) => n,
-            _ => return Err("Expected a number for the X coordinate"),
-        };
-        let y = match tokens.next().transpose()? {
-            Some(Token::Number(n)) => n,
-            _ => return Err("Expected a number for the Y coordinate"),
-        };
-        let z = match tokens.next().transpose()? {
-            Some(Token::Number(n)) => n,
-            _ => return Err("Expected a number for the Z coordinate"),
-        };
+    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, ParseWktError> {
+        let x = next_number(tokens, "Expected a number for the X coordinate")?;
+        let y = next_ordinate(tokens, dim, "Expected a number for the Y coordinate")?;
 
-        match dim {
-            Dimension::XY => { return Err("x, y, and z fields are expected") },
-            Dimension::XYZ => (),
-            _ => { return Err("x, y, and z fields are expected") }
+        let mut z = None;
+        let mut m = None;
+        if matches!(dim, Dimension::XYZ | Dimension::XYZM) {
+            z = Some(next_ordinate(
+                tokens,
+                dim,
+                "Expected a number for the Z coordinate",
+            )?);
+        }
+        if matches!(dim, Dimension::XYM | Dimension::XYZM) {
+            m = Some(next_ordinate(
+                tokens,
+                dim,
+                "Expected a number for the M coordinate",
+            )?);
+        }
+        if let Some(Ok(Token::Number(_))) = tokens.peek() {
+            tokens.next();
+            return Err(tokens.inconsistent_dimension_error(dim));
         }
 
-        Ok(Coord { x, y, z })
+        Ok(Coord { x, y, z, m })
     }
 }
 
@@ -61,7 +132,7 @@ impl<T: WktNum> CoordTrait for Coord<T> {
     type T = T;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        geo_traits::Dimensions::Xyz
+        self.geo_dim()
     }
 
     fn x(&self) -> Self::T {
@@ -72,17 +143,17 @@ impl<T: WktNum> CoordTrait for Coord<T> {
         self.y
     }
 
+    /// The `z` ordinate.
+    ///
+    /// # Panics
+    ///
+    /// If this coordinate has no `z`, as for `nth_or_panic` out of range.
     fn z(&self) -> Self::T {
-        self.z
+        self.z.expect("coordinate has no z")
     }
 
     fn nth_or_panic(&self, n: usize) -> Self::T {
-        match n {
-            0 => self.x,
-            1 => self.y,
-            2 => self.z,
-            _ => panic!("n out of range"),
-        }
+        self.nth(n).expect("n out of range")
     }
 }
 
@@ -90,7 +161,7 @@ impl<T: WktNum> CoordTrait for &Coord<T> {
     type T = T;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        geo_traits::Dimensions::Xyz
+        self.geo_dim()
     }
 
     fn x(&self) -> Self::T {
@@ -101,16 +172,182 @@ impl<T: WktNum> CoordTrait for &Coord<T> {
         self.y
     }
 
+    /// The `z` ordinate.
+    ///
+    /// # Panics
+    ///
+    /// If this coordinate has no `z`, as for `nth_or_panic` out of range.
     fn z(&self) -> Self::T {
-        self.z
+        self.z.expect("coordinate has no z")
     }
 
     fn nth_or_panic(&self, n: usize) -> Self::T {
-        match n {
-            0 => self.x,
-            1 => self.y,
-            2 => self.z,
-            _ => panic!("n out of range"),
+        Coord::nth(self, n).expect("n out of range")
+    }
+}
+
+/// Serializes as an `[x, y]`, `[x, y, z]` or `[x, y, z, m]` array, with `null` for the `z` of a
+/// measured coordinate without one
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Coord<T>
+where
+    T: WktNum + serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let len = if self.m.is_some() {
+            4
+        } else if self.z.is_some() {
+            3
+        } else {
+            2
+        };
+        // A sequence rather than a tuple, so that formats which aren't self-describing record the
+        // length for reading it back
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        seq.serialize_element(&self.x)?;
+        seq.serialize_element(&self.y)?;
+        // z and m are written as options, which JSON writes as plain numbers, so that they read
+        // back the same way whether or not the format describes itself
+        if len > 2 {
+            seq.serialize_element(&self.z)?;
+        }
+        if len > 3 {
+            seq.serialize_element(&self.m)?;
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes from an `[x, y]`, `[x, y, z]` or `[x, y, z, m]` array, where only the `z` of
+/// `[x, y, null, m]` may be `null`
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Coord<T>
+where
+    T: WktNum + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(CoordVisitor(std::marker::PhantomData))
+    }
+}
+
+#[cfg(feature = "serde")]
+struct CoordVisitor<T>(std::marker::PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::de::Visitor<'de> for CoordVisitor<T>
+where
+    T: WktNum + serde::Deserialize<'de>,
+{
+    type Value = Coord<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "an [x, y], [x, y, z] or [x, y, z, m] coordinate array"
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let x = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let y = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        // `None` for a missing element, and `Some(None)` for a `null`
+        let (z, m) = match seq.next_element::<Option<T>>()? {
+            None => (None, None),
+            Some(z) => match (z, seq.next_element::<Option<T>>()?) {
+                (Some(z), None) => (Some(z), None),
+                (z, Some(Some(m))) => (z, Some(m)),
+                (None, None) | (_, Some(None)) => {
+                    return Err(A::Error::invalid_value(
+                        serde::de::Unexpected::Option,
+                        &"a null z followed by an m",
+                    ))
+                }
+            },
+        };
+        if seq.next_element::<T>()?.is_some() {
+            return Err(A::Error::invalid_length(5, &self));
+        }
+
+        Ok(Coord { x, y, z, m })
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::Coord;
+
+    #[test]
+    fn coord_json_array_round_trip() {
+        let coord = Coord {
+            x: 1.5,
+            y: -2.0,
+            z: Some(3.25),
+            m: None,
+        };
+        let json = serde_json::to_string(&coord).unwrap();
+        assert_eq!(json, "[1.5,-2.0,3.25]");
+
+        let parsed: Coord<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, coord);
+
+        let measured = Coord {
+            x: 1.0,
+            y: 2.0,
+            z: None,
+            m: Some(4.0),
+        };
+        assert_eq!(
+            serde_json::to_string(&measured).unwrap(),
+            "[1.0,2.0,null,4.0]"
+        );
+        assert_eq!(
+            serde_json::from_str::<Coord<f64>>("[1.0,2.0,null,4.0]").unwrap(),
+            measured
+        );
+
+        assert!(serde_json::from_str::<Coord<f64>>("[1.0]").is_err());
+        assert!(serde_json::from_str::<Coord<f64>>("[1.0,2.0,3.0,4.0,5.0]").is_err());
+        for nulls in [
+            "[1.0,2.0,null]",
+            "[1.0,2.0,null,null]",
+            "[1.0,2.0,3.0,null]",
+        ] {
+            assert!(
+                serde_json::from_str::<Coord<f64>>(nulls).is_err(),
+                "{nulls}"
+            );
+        }
+    }
+
+    #[test]
+    fn coord_bincode_round_trip() {
+        // bincode isn't self-describing, so relies on the length being written
+        for (z, m) in [
+            (None, None),
+            (Some(3.), None),
+            (None, Some(4.)),
+            (Some(3.), Some(4.)),
+        ] {
+            let coord = Coord { x: 1., y: 2., z, m };
+            let bytes = bincode::serialize(&coord).unwrap();
+            assert_eq!(bincode::deserialize::<Coord<f64>>(&bytes).unwrap(), coord);
         }
     }
 }
@@ -8,3 +8,26 @@ pub enum Dimension {
     XYM,
     XYZM,
 }
+
+impl Dimension {
+    /// The dimension matching `dims`, taking an unknown dimension to be 2D.
+    pub(crate) fn of(dims: geo_traits::Dimensions) -> Self {
+        match dims {
+            geo_traits::Dimensions::Xyz => Dimension::XYZ,
+            geo_traits::Dimensions::Xym => Dimension::XYM,
+            geo_traits::Dimensions::Xyzm => Dimension::XYZM,
+            geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(_) => Dimension::XY,
+        }
+    }
+}
+
+impl From<Dimension> for geo_traits::Dimensions {
+    fn from(dim: Dimension) -> Self {
+        match dim {
+            Dimension::XY => geo_traits::Dimensions::Xy,
+            Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
+            Dimension::XYZM => geo_traits::Dimensions::Xyzm,
+        }
+    }
+}
@@ -13,16 +13,38 @@
 // limitations under the License.
 
 use geo_traits::{GeometryCollectionTrait, GeometryTrait};
+use geo_types::CoordNum;
 
-use crate::to_wkt::write_geometry_collection;
+use crate::error::ParseWktError;
+use crate::to_wkt::{write_wkt_collection_with_config, WriteConfig};
 use crate::tokenizer::{PeekableTokens, Token};
 use crate::types::Dimension;
 use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
+/// A collection of geometries of any type.
+///
+/// The second field is the dimension the collection was written with, so that e.g.
+/// `GEOMETRYCOLLECTION Z EMPTY` is written back as it was parsed. A non-empty collection reports
+/// the dimension of its members.
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct GeometryCollection<T: WktNum>(pub Vec<Wkt<T>>);
+pub struct GeometryCollection<T: WktNum>(pub Vec<Wkt<T>>, pub Dimension);
+
+impl<T: WktNum> GeometryCollection<T> {
+    /// A collection of `geometries`, with the dimension of the first.
+    pub fn new(geometries: Vec<Wkt<T>>) -> Self {
+        let dim = geometries
+            .first()
+            .map_or(Dimension::XY, |first| Dimension::of(first.dim()));
+        GeometryCollection(geometries, dim)
+    }
+
+    /// An empty collection of dimension `dim`.
+    pub fn empty(dim: Dimension) -> Self {
+        GeometryCollection(Vec::new(), dim)
+    }
+}
 
 impl<T> From<GeometryCollection<T>> for Wkt<T>
 where
@@ -33,12 +55,29 @@ where
     }
 }
 
+/// Collect geometries into a `GeometryCollection`.
+impl<T: WktNum> FromIterator<Wkt<T>> for GeometryCollection<T> {
+    fn from_iter<I: IntoIterator<Item = Wkt<T>>>(iter: I) -> Self {
+        GeometryCollection::new(iter.into_iter().collect())
+    }
+}
+
+impl<T: WktNum> Extend<Wkt<T>> for GeometryCollection<T> {
+    fn extend<I: IntoIterator<Item = Wkt<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> fmt::Display for GeometryCollection<T>
 where
-    T: WktNum + fmt::Display,
+    T: WktNum + CoordNum + fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        Ok(write_geometry_collection(f, self)?)
+        Ok(write_wkt_collection_with_config(
+            f,
+            self,
+            &WriteConfig::default(),
+        )?)
     }
 }
 
@@ -46,15 +85,21 @@ impl<T> FromTokens<T> for GeometryCollection<T>
 where
     T: WktNum + FromStr + Default,
 {
-    // Unsure if the dimension should be used in parsing GeometryCollection; is it
-    // GEOMETRYCOLLECTION ( POINT Z (...) , POINT ZM (...))
-    // or does a geometry collection have a known dimension?
-    fn from_tokens(tokens: &mut PeekableTokens<T>, _dim: Dimension) -> Result<Self, &'static str> {
+    // Members carry their own dimension tags, so `dim` is only kept for the collection itself,
+    // e.g. for `GEOMETRYCOLLECTION Z EMPTY`
+    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, ParseWktError> {
+        // Members are parsed recursively, so limit the nesting to bound the stack used
+        tokens.enter_collection()?;
         let mut items = Vec::new();
 
         let word = match tokens.next().transpose()? {
             Some(Token::Word(w)) => w,
-            _ => return Err("Expected a word in GEOMETRYCOLLECTION"),
+            Some(_) => {
+                return Err(tokens.unexpected_token_error("Expected a word in GEOMETRYCOLLECTION"))
+            }
+            None => {
+                return Err(tokens.unexpected_end_error("Expected a word in GEOMETRYCOLLECTION"))
+            }
         };
 
         let item = Wkt::from_word_and_tokens(&word, tokens)?;
@@ -65,14 +110,26 @@ where
 
             let word = match tokens.next().transpose()? {
                 Some(Token::Word(w)) => w,
-                _ => return Err("Expected a word in GEOMETRYCOLLECTION"),
+                Some(_) => {
+                    return Err(
+                        tokens.unexpected_token_error("Expected a word in GEOMETRYCOLLECTION")
+                    )
+                }
+                None => {
+                    return Err(tokens.unexpected_end_error("Expected a word in GEOMETRYCOLLECTION"))
+                }
             };
 
             let item = Wkt::from_word_and_tokens(&word, tokens)?;
             items.push(item);
         }
 
-        Ok(GeometryCollection(items))
+        tokens.leave_collection();
+        Ok(GeometryCollection(items, dim))
+    }
+
+    fn empty(dim: Dimension) -> Self {
+        GeometryCollection::empty(dim)
     }
 }
 
@@ -84,12 +141,7 @@ impl<T: WktNum> GeometryCollectionTrait for GeometryCollection<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        self.0.first().map_or(self.1.into(), |first| first.dim())
     }
 
     fn num_geometries(&self) -> usize {
@@ -108,13 +160,57 @@ mod tests {
     use crate::Wkt;
     use std::str::FromStr;
 
+    #[test]
+    fn collect_geometries_and_points() {
+        let points: Vec<Point<f64>> = ["POINT(1 2)", "POINT(3 4)"]
+            .into_iter()
+            .map(|s| match Wkt::from_str(s).unwrap() {
+                Wkt::Point(point) => point,
+                _ => unreachable!(),
+            })
+            .collect();
+        let multi_point: MultiPoint<f64> = points.iter().cloned().collect();
+        assert_eq!(multi_point.to_string(), "MULTIPOINT((1 2),(3 4))");
+
+        let collection: GeometryCollection<f64> = points
+            .into_iter()
+            .map(Wkt::from)
+            .chain([Wkt::from(multi_point)])
+            .collect();
+        assert_eq!(
+            collection.to_string(),
+            "GEOMETRYCOLLECTION(POINT(1 2),POINT(3 4),MULTIPOINT((1 2),(3 4)))"
+        );
+    }
+
+    #[test]
+    fn extend_collections() {
+        let mut collection: GeometryCollection<f64> = Wkt::from_str("GEOMETRYCOLLECTION EMPTY")
+            .unwrap()
+            .into_geometry_collection()
+            .unwrap();
+        collection.extend([Wkt::point_2d(1., 2.)]);
+        collection.extend(vec![Wkt::point_2d(3., 4.), Wkt::empty_point()]);
+        assert_eq!(
+            collection.to_string(),
+            "GEOMETRYCOLLECTION(POINT(1 2),POINT(3 4),POINT EMPTY)"
+        );
+
+        let mut multi_polygon: MultiPolygon<f64> = Wkt::from_str("MULTIPOLYGON(((0 0,1 0,0 0)))")
+            .unwrap()
+            .into_multi_polygon()
+            .unwrap();
+        multi_polygon.extend(multi_polygon.0.clone());
+        assert_eq!(multi_polygon.0.len(), 2);
+    }
+
     #[test]
     fn basic_geometrycollection() {
-        let wkt: Wkt<f64> = Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(8 4 9)))")
+        let wkt: Wkt<f64> = Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(8 4 9))")
             .ok()
             .unwrap();
         let items = match wkt {
-            Wkt::GeometryCollection(GeometryCollection(items)) => items,
+            Wkt::GeometryCollection(GeometryCollection(items, _)) => items,
             _ => unreachable!(),
         };
         assert_eq!(1, items.len());
@@ -122,11 +218,12 @@ mod tests {
 
     #[test]
     fn complex_geometrycollection() {
-        let wkt: Wkt<f64> = Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(8 4 -8),LINESTRING Z(4 6 9,7 10 2)))")
-            .ok()
-            .unwrap();
+        let wkt: Wkt<f64> =
+            Wkt::from_str("GEOMETRYCOLLECTION Z(POINT Z(8 4 -8),LINESTRING Z(4 6 9,7 10 2))")
+                .ok()
+                .unwrap();
         let items = match wkt {
-            Wkt::GeometryCollection(GeometryCollection(items)) => items,
+            Wkt::GeometryCollection(GeometryCollection(items, _)) => items,
             _ => unreachable!(),
         };
         assert_eq!(2, items.len());
@@ -134,7 +231,7 @@ mod tests {
 
     #[test]
     fn write_empty_geometry_collection() {
-        let geometry_collection: GeometryCollection<f64> = GeometryCollection(vec![]);
+        let geometry_collection: GeometryCollection<f64> = GeometryCollection::new(vec![]);
 
         assert_eq!(
             "GEOMETRYCOLLECTION EMPTY",
@@ -144,136 +241,157 @@ mod tests {
 
     #[test]
     fn write_geometry_collection() {
-        let point = Wkt::Point(Point(Some(Coord {
+        let point = Wkt::Point(Point::new(Coord {
             x: 10.,
             y: 20.,
-            z: 30.,
-        })));
+            z: Some(30.),
+            m: None,
+        }));
 
-        let multipoint = Wkt::MultiPoint(MultiPoint(vec![
-            Point(Some(Coord {
+        let multipoint = Wkt::MultiPoint(MultiPoint::new(vec![
+            Point::new(Coord {
                 x: 10.1,
                 y: 20.2,
-                z: 30.3,
-            })),
-            Point(Some(Coord {
+                z: Some(30.3),
+                m: None,
+            }),
+            Point::new(Coord {
                 x: 30.3,
                 y: 40.4,
-                z: 50.5,
-            })),
+                z: Some(50.5),
+                m: None,
+            }),
         ]));
 
-        let linestring = Wkt::LineString(LineString(vec![
+        let linestring = Wkt::LineString(LineString::new(vec![
             Coord {
                 x: 10.,
                 y: 20.,
-                z: 30.,
+                z: Some(30.),
+                m: None,
             },
             Coord {
                 x: 30.,
                 y: 40.,
-                z: 50.,
+                z: Some(50.),
+                m: None,
             },
         ]));
 
-        let polygon = Wkt::Polygon(Polygon(vec![LineString(vec![
+        let polygon = Wkt::Polygon(Polygon::new(vec![LineString::new(vec![
             Coord {
                 x: 0.,
                 y: 0.,
-                z: 0.,
+                z: Some(0.),
+                m: None,
             },
             Coord {
                 x: 20.,
                 y: 40.,
-                z: 60.,
+                z: Some(60.),
+                m: None,
             },
             Coord {
                 x: 40.,
                 y: 0.,
-                z: -40.,
+                z: Some(-40.),
+                m: None,
             },
             Coord {
                 x: 0.,
                 y: 0.,
-                z: 0.,
+                z: Some(0.),
+                m: None,
             },
         ])]));
 
-        let multilinestring = Wkt::MultiLineString(MultiLineString(vec![
-            LineString(vec![
+        let multilinestring = Wkt::MultiLineString(MultiLineString::new(vec![
+            LineString::new(vec![
                 Coord {
                     x: 10.1,
                     y: 20.2,
-                    z: 30.3,
+                    z: Some(30.3),
+                    m: None,
                 },
                 Coord {
                     x: 30.3,
                     y: 40.4,
-                    z: 50.5,
+                    z: Some(50.5),
+                    m: None,
                 },
             ]),
-            LineString(vec![
+            LineString::new(vec![
                 Coord {
                     x: 50.5,
                     y: 60.6,
-                    z: 70.7,
+                    z: Some(70.7),
+                    m: None,
                 },
                 Coord {
                     x: 70.7,
                     y: 80.8,
-                    z: 90.9,
+                    z: Some(90.9),
+                    m: None,
                 },
             ]),
         ]));
 
-        let multipolygon = Wkt::MultiPolygon(MultiPolygon(vec![
-            Polygon(vec![LineString(vec![
+        let multipolygon = Wkt::MultiPolygon(MultiPolygon::new(vec![
+            Polygon::new(vec![LineString::new(vec![
                 Coord {
                     x: 0.,
                     y: 0.,
-                    z: 0.,
+                    z: Some(0.),
+                    m: None,
                 },
                 Coord {
                     x: 20.,
                     y: 40.,
-                    z: 60.,
+                    z: Some(60.),
+                    m: None,
                 },
                 Coord {
                     x: 40.,
                     y: 0.,
-                    z: -40.,
+                    z: Some(-40.),
+                    m: None,
                 },
                 Coord {
                     x: 0.,
                     y: 0.,
-                    z: 0.,
+                    z: Some(0.),
+                    m: None,
                 },
             ])]),
-            Polygon(vec![LineString(vec![
+            Polygon::new(vec![LineString::new(vec![
                 Coord {
                     x: 40.,
                     y: 40.,
-                    z: 40.,
+                    z: Some(40.),
+                    m: None,
                 },
                 Coord {
                     x: 20.,
                     y: 45.,
-                    z: -20.,
+                    z: Some(-20.),
+                    m: None,
                 },
                 Coord {
                     x: 45.,
                     y: 30.,
-                    z: -45.,
+                    z: Some(-45.),
+                    m: None,
                 },
                 Coord {
                     x: 40.,
                     y: 40.,
-                    z: 40.,
+                    z: Some(40.),
+                    m: None,
                 },
             ])]),
         ]));
 
-        let geometrycollection = GeometryCollection(vec![
+        let geometrycollection = GeometryCollection::new(vec![
             point,
             multipoint,
             linestring,
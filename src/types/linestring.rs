@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use geo_traits::{CoordTrait, LineStringTrait};
+use num_traits::Float;
 
 use crate::to_wkt::write_linestring;
+use crate::error::ParseWktError;
 use crate::tokenizer::PeekableTokens;
 use crate::types::coord::Coord;
 use crate::types::Dimension;
@@ -22,8 +24,28 @@ use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
+/// A line string of coordinates.
+///
+/// The second field is the dimension the line string was written with, so that e.g.
+/// `LINESTRING Z EMPTY` is written back as it was parsed. A line string with coordinates reports
+/// the dimension of its first coordinate.
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct LineString<T: WktNum>(pub Vec<Coord<T>>);
+pub struct LineString<T: WktNum>(pub Vec<Coord<T>>, pub Dimension);
+
+impl<T: WktNum> LineString<T> {
+    /// A line string through `coords`, with the dimension of the first.
+    pub fn new(coords: Vec<Coord<T>>) -> Self {
+        let dim = coords
+            .first()
+            .map_or(Dimension::XY, |first| Dimension::of(first.dim()));
+        LineString(coords, dim)
+    }
+
+    /// An empty line string of dimension `dim`.
+    pub fn empty(dim: Dimension) -> Self {
+        LineString(Vec::new(), dim)
+    }
+}
 
 impl<T> From<LineString<T>> for Wkt<T>
 where
@@ -34,16 +56,126 @@ where
     }
 }
 
+/// Collect coordinates into a `LineString`, e.g. to build a geometry for writing.
+impl<T: WktNum> FromIterator<Coord<T>> for LineString<T> {
+    fn from_iter<I: IntoIterator<Item = Coord<T>>>(iter: I) -> Self {
+        LineString::new(iter.into_iter().collect())
+    }
+}
+
+impl<T: WktNum> Extend<Coord<T>> for LineString<T> {
+    fn extend<I: IntoIterator<Item = Coord<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> FromTokens<T> for LineString<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, ParseWktError> {
         let result = FromTokens::comma_many(<Coord<T> as FromTokens<T>>::from_tokens, tokens, dim);
-        result.map(LineString)
+        result.map(|coords| LineString(coords, dim))
+    }
+
+    fn empty(dim: Dimension) -> Self {
+        LineString::empty(dim)
+    }
+}
+
+impl<T: WktNum + Float> LineString<T> {
+    /// Resample this line string at a uniform arc length `spacing`.
+    ///
+    /// The output starts at the first coordinate and has a point every `spacing` units of distance
+    /// along the line, interpolating across segment boundaries. The last coordinate is always kept,
+    /// so the final step may be shorter than `spacing`. Line strings with fewer than two
+    /// coordinates, no length or a length which isn't finite, or a non-positive `spacing`, are
+    /// returned unchanged.
+    ///
+    /// The output has about `length / spacing` coordinates, which are all allocated, so a `spacing`
+    /// far smaller than the line is long takes as much time and memory.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::types::LineString;
+    /// use wkt::Wkt;
+    ///
+    /// let Wkt::LineString(ls) = Wkt::<f64>::from_str("LINESTRING Z(0 0 0,0 3 0)").unwrap() else {
+    ///     unreachable!()
+    /// };
+    /// let resampled = ls.resample(1.0);
+    /// assert_eq!(resampled.0.len(), 4);
+    /// assert_eq!(resampled.0[1].y, 1.0);
+    /// ```
+    pub fn resample(&self, spacing: T) -> LineString<T> {
+        let (first, last) = match (self.0.first(), self.0.last()) {
+            (Some(first), Some(last)) if self.0.len() > 1 && spacing > T::zero() => (first, last),
+            _ => return self.clone(),
+        };
+
+        let mut resampled = vec![first.clone()];
+        let mut has_length = false;
+        // Distance along the line since the most recently emitted coordinate
+        let mut carried = T::zero();
+        for segment in self.0.windows(2) {
+            let (start, end) = (&segment[0], &segment[1]);
+            let length = distance(start, end);
+            if length == T::zero() {
+                continue;
+            }
+            has_length = true;
+
+            // Offsets are `first + k * spacing` rather than accumulated, so they can't stall once
+            // `spacing` is too small to change a large offset
+            let first = spacing - carried;
+            if first > length {
+                carried = carried + length;
+                continue;
+            }
+            let Some(count) = ((length - first) / spacing).floor().to_usize() else {
+                return self.clone();
+            };
+            let mut offset = first;
+            for k in 0..=count {
+                offset = first + spacing * T::from(k).unwrap_or_else(T::infinity);
+                resampled.push(interpolate(start, end, (offset / length).min(T::one())));
+            }
+            carried = length - offset;
+        }
+        if !has_length {
+            return self.clone();
+        }
+
+        // Snap to the endpoint when the last sample already (almost) landed on it
+        if carried > spacing * T::epsilon().sqrt() {
+            resampled.push(last.clone());
+        } else if let Some(end) = resampled.last_mut() {
+            *end = last.clone();
+        }
+
+        LineString(resampled, self.1)
     }
 }
 
+fn distance<T: WktNum + Float>(a: &Coord<T>, b: &Coord<T>) -> T {
+    let dz = b.z.unwrap_or_default() - a.z.unwrap_or_default();
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+fn interpolate<T: WktNum + Float>(a: &Coord<T>, b: &Coord<T>, fraction: T) -> Coord<T> {
+    Coord {
+        x: a.x + (b.x - a.x) * fraction,
+        y: a.y + (b.y - a.y) * fraction,
+        z: lerp(a.z, b.z, fraction),
+        m: lerp(a.m, b.m, fraction),
+    }
+}
+
+fn lerp<T: WktNum + Float>(a: Option<T>, b: Option<T>, fraction: T) -> Option<T> {
+    Some(a? + (b? - a?) * fraction)
+}
+
 impl<T> fmt::Display for LineString<T>
 where
     T: WktNum + fmt::Display,
@@ -61,12 +193,7 @@ impl<T: WktNum> LineStringTrait for LineString<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        self.0.first().map_or(self.1.into(), |first| first.dim())
     }
 
     fn num_coords(&self) -> usize {
@@ -86,12 +213,7 @@ impl<T: WktNum> LineStringTrait for &LineString<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        self.0.first().map_or(self.1.into(), |first| first.dim())
     }
 
     fn num_coords(&self) -> usize {
@@ -109,22 +231,35 @@ mod tests {
     use crate::Wkt;
     use std::str::FromStr;
 
+    #[test]
+    fn collect_linestring() {
+        let line_string: LineString<f64> = (0..3)
+            .map(|i| Coord {
+                x: i as f64,
+                y: 2. * i as f64,
+                z: None,
+                m: None,
+            })
+            .collect();
+        assert_eq!(line_string.to_string(), "LINESTRING(0 0,1 2,2 4)");
+    }
+
     #[test]
     fn basic_linestring() {
         let wkt = Wkt::from_str("LINESTRING Z(10 -20 15, -0 -0.5 -1)").ok().unwrap();
         let coords = match wkt {
-            Wkt::LineString(LineString(coords)) => coords,
+            Wkt::LineString(LineString(coords, _)) => coords,
             _ => unreachable!(),
         };
         assert_eq!(2, coords.len());
 
         assert_eq!(10.0, coords[0].x);
         assert_eq!(-20.0, coords[0].y);
-        assert_eq!(15.0, coords[0].z);
+        assert_eq!(Some(15.0), coords[0].z);
 
         assert_eq!(0.0, coords[1].x);
         assert_eq!(-0.5, coords[1].y);
-        assert_eq!(-1.0, coords[1].z);
+        assert_eq!(Some(-1.0), coords[1].z);
     }
 
     #[test]
@@ -133,42 +268,140 @@ mod tests {
             .ok()
             .unwrap();
         let coords = match wkt {
-            Wkt::LineString(LineString(coords)) => coords,
+            Wkt::LineString(LineString(coords, _)) => coords,
             _ => unreachable!(),
         };
         assert_eq!(2, coords.len());
 
         assert_eq!(-117.0, coords[0].x);
         assert_eq!(33.0, coords[0].y);
-        assert_eq!(2.0, coords[0].z);
+        assert_eq!(Some(2.0), coords[0].z);
 
         assert_eq!(-116.0, coords[1].x);
         assert_eq!(34.0, coords[1].y);
-        assert_eq!(4.0, coords[1].z);
+        assert_eq!(Some(4.0), coords[1].z);
+    }
+
+    #[test]
+    fn resample_across_segments() {
+        let linestring = LineString::new(vec![
+            Coord {
+                x: 0.,
+                y: 0.,
+                z: Some(0.),
+                m: None,
+            },
+            Coord {
+                x: 3.,
+                y: 0.,
+                z: Some(0.),
+                m: None,
+            },
+            Coord {
+                x: 3.,
+                y: 4.,
+                z: Some(0.),
+                m: None,
+            },
+        ]);
+
+        let resampled = linestring.resample(1.);
+        assert_eq!(resampled.0.len(), 8);
+        for pair in resampled.0.windows(2) {
+            let (dx, dy): (f64, f64) = (pair[1].x - pair[0].x, pair[1].y - pair[0].y);
+            assert!(((dx * dx + dy * dy).sqrt() - 1.).abs() < 1e-9);
+        }
+        assert_eq!(resampled.0[3], linestring.0[1]);
+        assert_eq!(resampled.0.last(), linestring.0.last());
+
+        let resampled = linestring.resample(2.5);
+        let ys: Vec<f64> = resampled.0.iter().map(|c| c.y).collect();
+        let xs: Vec<f64> = resampled.0.iter().map(|c| c.x).collect();
+        assert_eq!(xs, vec![0., 2.5, 3., 3.]);
+        assert_eq!(ys, vec![0., 0., 2., 4.]);
+    }
+
+    #[test]
+    fn resample_degenerate() {
+        let empty: LineString<f64> = LineString::new(vec![]);
+        assert_eq!(empty.resample(1.), empty);
+
+        let single = LineString::new(vec![Coord {
+            x: 1.,
+            y: 2.,
+            z: Some(3.),
+            m: None,
+        }]);
+        assert_eq!(single.resample(1.), single);
+
+        let point = Coord {
+            x: 1.,
+            y: 2.,
+            z: None,
+            m: None,
+        };
+        let no_length = LineString::new(vec![point.clone(), point.clone(), point]);
+        assert_eq!(no_length.resample(1.), no_length);
+    }
+
+    #[test]
+    fn resample_many_steps() {
+        let coord = |x| Coord {
+            x,
+            y: 0.,
+            z: None,
+            m: None,
+        };
+        let linestring: LineString<f64> = LineString::new(vec![coord(0.), coord(1000.)]);
+        let resampled = linestring.resample(0.1);
+        assert_eq!(resampled.0.len(), 10_001);
+        assert!((resampled.0[5_000].x - 500.).abs() < 1e-9);
+        assert_eq!(resampled.0.last(), linestring.0.last());
     }
 
     #[test]
     fn write_empty_linestring() {
-        let linestring: LineString<f64> = LineString(vec![]);
+        let linestring: LineString<f64> = LineString::new(vec![]);
 
         assert_eq!("LINESTRING EMPTY", format!("{}", linestring));
     }
 
     #[test]
     fn write_linestring() {
-        let linestring = LineString(vec![
+        let linestring = LineString::new(vec![
             Coord {
                 x: 10.1,
                 y: 20.2,
-                z: 30.3,
+                z: Some(30.3),
+                m: None,
             },
             Coord {
                 x: 30.3,
                 y: 40.4,
-                z: 50.5,
+                z: Some(50.5),
+                m: None,
             },
         ]);
 
         assert_eq!("LINESTRING Z(10.1 20.2 30.3,30.3 40.4 50.5)", format!("{}", linestring));
     }
+
+    #[test]
+    fn dimension_round_trips() {
+        use geo_traits::{Dimensions, LineStringTrait};
+
+        for (input, dim) in [
+            ("LINESTRING(1 2,3 4)", Dimensions::Xy),
+            ("LINESTRING Z(1 2 3,4 5 6)", Dimensions::Xyz),
+            ("LINESTRING M(1 2 3,4 5 6)", Dimensions::Xym),
+            ("LINESTRING ZM(1 2 3 4,5 6 7 8)", Dimensions::Xyzm),
+        ] {
+            let linestring = match Wkt::<f64>::from_str(input).unwrap() {
+                Wkt::LineString(linestring) => linestring,
+                _ => unreachable!(),
+            };
+            assert_eq!(linestring.dim(), dim, "{input}");
+            assert_eq!(linestring.to_string(), input);
+        }
+    }
 }
@@ -20,9 +20,13 @@ pub use self::linestring::LineString;
 pub use self::multilinestring::MultiLineString;
 pub use self::multipoint::MultiPoint;
 pub use self::multipolygon::MultiPolygon;
+#[cfg(feature = "ordered")]
+pub use self::ordered::Ordered;
 pub use self::point::Point;
 pub use self::polygon::Polygon;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod coord;
 mod dimension;
 mod geometry_type;
@@ -31,5 +35,7 @@ mod linestring;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
+#[cfg(feature = "ordered")]
+mod ordered;
 mod point;
 mod polygon;
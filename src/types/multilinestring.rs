@@ -15,6 +15,7 @@
 use geo_traits::{LineStringTrait, MultiLineStringTrait};
 
 use crate::to_wkt::write_multi_linestring;
+use crate::error::ParseWktError;
 use crate::tokenizer::PeekableTokens;
 use crate::types::linestring::LineString;
 use crate::types::Dimension;
@@ -22,8 +23,28 @@ use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
+/// A collection of line strings.
+///
+/// The second field is the dimension the multi line string was written with, so that e.g.
+/// `MULTILINESTRING Z EMPTY` is written back as it was parsed. A non-empty multi line string
+/// reports the dimension of its members.
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct MultiLineString<T: WktNum>(pub Vec<LineString<T>>);
+pub struct MultiLineString<T: WktNum>(pub Vec<LineString<T>>, pub Dimension);
+
+impl<T: WktNum> MultiLineString<T> {
+    /// A multi line string of `line_strings`, with the dimension of the first.
+    pub fn new(line_strings: Vec<LineString<T>>) -> Self {
+        let dim = line_strings
+            .first()
+            .map_or(Dimension::XY, |first| Dimension::of(first.dim()));
+        MultiLineString(line_strings, dim)
+    }
+
+    /// An empty multi line string of dimension `dim`.
+    pub fn empty(dim: Dimension) -> Self {
+        MultiLineString(Vec::new(), dim)
+    }
+}
 
 impl<T> From<MultiLineString<T>> for Wkt<T>
 where
@@ -34,6 +55,19 @@ where
     }
 }
 
+/// Collect line strings into a `MultiLineString`.
+impl<T: WktNum> FromIterator<LineString<T>> for MultiLineString<T> {
+    fn from_iter<I: IntoIterator<Item = LineString<T>>>(iter: I) -> Self {
+        MultiLineString::new(iter.into_iter().collect())
+    }
+}
+
+impl<T: WktNum> Extend<LineString<T>> for MultiLineString<T> {
+    fn extend<I: IntoIterator<Item = LineString<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> fmt::Display for MultiLineString<T>
 where
     T: WktNum + fmt::Display,
@@ -47,13 +81,17 @@ impl<T> FromTokens<T> for MultiLineString<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, ParseWktError> {
         let result = FromTokens::comma_many(
             <LineString<T> as FromTokens<T>>::from_tokens_with_parens,
             tokens,
             dim,
         );
-        result.map(MultiLineString)
+        result.map(|line_strings| MultiLineString(line_strings, dim))
+    }
+
+    fn empty(dim: Dimension) -> Self {
+        MultiLineString::empty(dim)
     }
 }
 
@@ -65,12 +103,11 @@ impl<T: WktNum> MultiLineStringTrait for MultiLineString<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        // Empty members don't know their dimension, so go by the first with coordinates
+        self.0
+            .iter()
+            .find(|line_string| !line_string.0.is_empty())
+            .map_or(self.1.into(), |member| member.dim())
     }
 
     fn num_line_strings(&self) -> usize {
@@ -90,12 +127,11 @@ impl<T: WktNum> MultiLineStringTrait for &MultiLineString<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        // Empty members don't know their dimension, so go by the first with coordinates
+        self.0
+            .iter()
+            .find(|line_string| !line_string.0.is_empty())
+            .map_or(self.1.into(), |member| member.dim())
     }
 
     fn num_line_strings(&self) -> usize {
@@ -120,7 +156,7 @@ mod tests {
             .ok()
             .unwrap();
         let lines = match wkt {
-            Wkt::MultiLineString(MultiLineString(lines)) => lines,
+            Wkt::MultiLineString(MultiLineString(lines, _)) => lines,
             _ => unreachable!(),
         };
         assert_eq!(2, lines.len());
@@ -128,36 +164,40 @@ mod tests {
 
     #[test]
     fn write_empty_multilinestring() {
-        let multilinestring: MultiLineString<f64> = MultiLineString(vec![]);
+        let multilinestring: MultiLineString<f64> = MultiLineString::new(vec![]);
 
         assert_eq!("MULTILINESTRING EMPTY", format!("{}", multilinestring));
     }
 
     #[test]
     fn write_multilinestring() {
-        let multilinestring = MultiLineString(vec![
-            LineString(vec![
+        let multilinestring = MultiLineString::new(vec![
+            LineString::new(vec![
                 Coord {
                     x: 10.1,
                     y: 20.2,
-                    z: 30.3,
+                    z: Some(30.3),
+                    m: None,
                 },
                 Coord {
                     x: 30.3,
                     y: 40.4,
-                    z: 50.5,
+                    z: Some(50.5),
+                    m: None,
                 },
             ]),
-            LineString(vec![
+            LineString::new(vec![
                 Coord {
                     x: 50.5,
                     y: 60.6,
-                    z: 70.7
+                    z: Some(70.7),
+                    m: None,
                 },
                 Coord {
                     x: 70.7,
                     y: 80.8,
-                    z: 90.9,
+                    z: Some(90.9),
+                    m: None,
                 },
             ]),
         ]);
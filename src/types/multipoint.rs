@@ -15,6 +15,7 @@
 use geo_traits::{MultiPointTrait, PointTrait};
 
 use crate::to_wkt::write_multi_point;
+use crate::error::ParseWktError;
 use crate::tokenizer::PeekableTokens;
 use crate::types::point::Point;
 use crate::types::Dimension;
@@ -22,8 +23,28 @@ use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
+/// A collection of points.
+///
+/// The second field is the dimension the multi point was written with, so that e.g.
+/// `MULTIPOINT M EMPTY` is written back as it was parsed. A non-empty multi point reports the
+/// dimension of its members.
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct MultiPoint<T: WktNum>(pub Vec<Point<T>>);
+pub struct MultiPoint<T: WktNum>(pub Vec<Point<T>>, pub Dimension);
+
+impl<T: WktNum> MultiPoint<T> {
+    /// A multi point of `points`, with the dimension of the first.
+    pub fn new(points: Vec<Point<T>>) -> Self {
+        let dim = points
+            .first()
+            .map_or(Dimension::XY, |first| Dimension::of(first.dim()));
+        MultiPoint(points, dim)
+    }
+
+    /// An empty multi point of dimension `dim`.
+    pub fn empty(dim: Dimension) -> Self {
+        MultiPoint(Vec::new(), dim)
+    }
+}
 
 impl<T> From<MultiPoint<T>> for Wkt<T>
 where
@@ -34,6 +55,19 @@ where
     }
 }
 
+/// Collect points into a `MultiPoint`.
+impl<T: WktNum> FromIterator<Point<T>> for MultiPoint<T> {
+    fn from_iter<I: IntoIterator<Item = Point<T>>>(iter: I) -> Self {
+        MultiPoint::new(iter.into_iter().collect())
+    }
+}
+
+impl<T: WktNum> Extend<Point<T>> for MultiPoint<T> {
+    fn extend<I: IntoIterator<Item = Point<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> fmt::Display for MultiPoint<T>
 where
     T: WktNum + fmt::Display,
@@ -47,13 +81,17 @@ impl<T> FromTokens<T> for MultiPoint<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, ParseWktError> {
         let result = FromTokens::comma_many(
             <Point<T> as FromTokens<T>>::from_tokens_with_optional_parens,
             tokens,
             dim,
         );
-        result.map(MultiPoint)
+        result.map(|points| MultiPoint(points, dim))
+    }
+
+    fn empty(dim: Dimension) -> Self {
+        MultiPoint::empty(dim)
     }
 }
 
@@ -65,12 +103,7 @@ impl<T: WktNum> MultiPointTrait for MultiPoint<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        self.0.first().map_or(self.1.into(), |first| first.dim())
     }
 
     fn num_points(&self) -> usize {
@@ -90,12 +123,7 @@ impl<T: WktNum> MultiPointTrait for &MultiPoint<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        self.0.first().map_or(self.1.into(), |first| first.dim())
     }
 
     fn num_points(&self) -> usize {
@@ -118,7 +146,7 @@ mod tests {
     fn basic_multipoint() {
         let wkt: Wkt<f64> = Wkt::from_str("MULTIPOINT Z((8 4 6), (4 0 3))").ok().unwrap();
         let points = match wkt {
-            Wkt::MultiPoint(MultiPoint(points)) => points,
+            Wkt::MultiPoint(MultiPoint(points, _)) => points,
             _ => unreachable!(),
         };
         assert_eq!(2, points.len());
@@ -130,18 +158,18 @@ mod tests {
             .ok()
             .unwrap();
         let points = match wkt {
-            Wkt::MultiPoint(MultiPoint(points)) => points,
+            Wkt::MultiPoint(MultiPoint(points, _)) => points,
             _ => unreachable!(),
         };
         assert_eq!(2, points.len());
 
         assert_eq!(0.0, points[0].0.as_ref().unwrap().x);
         assert_eq!(0.0, points[0].0.as_ref().unwrap().y);
-        assert_eq!(4.0, points[0].0.as_ref().unwrap().z);
+        assert_eq!(Some(4.0), points[0].0.as_ref().unwrap().z);
 
         assert_eq!(1.0, points[1].0.as_ref().unwrap().x);
         assert_eq!(2.0, points[1].0.as_ref().unwrap().y);
-        assert_eq!(4.0, points[1].0.as_ref().unwrap().z);
+        assert_eq!(Some(4.0), points[1].0.as_ref().unwrap().z);
     }
 
     #[test]
@@ -150,24 +178,24 @@ mod tests {
             .ok()
             .unwrap();
         let points = match wkt {
-            Wkt::MultiPoint(MultiPoint(points)) => points,
+            Wkt::MultiPoint(MultiPoint(points, _)) => points,
             _ => unreachable!(),
         };
         assert_eq!(2, points.len());
 
         assert_eq!(0.0, points[0].0.as_ref().unwrap().x);
         assert_eq!(0.0, points[0].0.as_ref().unwrap().y);
-        assert_eq!(4.0, points[0].0.as_ref().unwrap().z);
+        assert_eq!(Some(4.0), points[0].0.as_ref().unwrap().z);
 
         assert_eq!(1.0, points[1].0.as_ref().unwrap().x);
         assert_eq!(2.0, points[1].0.as_ref().unwrap().y);
-        assert_eq!(4.0, points[1].0.as_ref().unwrap().z);
+        assert_eq!(Some(4.0), points[1].0.as_ref().unwrap().z);
     }
     #[test]
     fn postgis_style_multipoint() {
         let wkt: Wkt<f64> = Wkt::from_str("MULTIPOINT Z(8 4 7, 4 0 9)").unwrap();
         let points = match wkt {
-            Wkt::MultiPoint(MultiPoint(points)) => points,
+            Wkt::MultiPoint(MultiPoint(points, _)) => points,
             _ => unreachable!(),
         };
         assert_eq!(2, points.len());
@@ -177,17 +205,49 @@ mod tests {
     fn mixed_parens_multipoint() {
         let wkt: Wkt<f64> = Wkt::from_str("MULTIPOINT Z(8 4 2, (4 0 1))").unwrap();
         let points = match wkt {
-            Wkt::MultiPoint(MultiPoint(points)) => points,
+            Wkt::MultiPoint(MultiPoint(points, _)) => points,
             _ => unreachable!(),
         };
         assert_eq!(2, points.len());
     }
 
+    #[test]
+    fn unparenthesized_2d_multipoint() {
+        let flat: Wkt<f64> = Wkt::from_str("MULTIPOINT (10 40, 40 30, 20 20)").unwrap();
+        let nested: Wkt<f64> = Wkt::from_str("MULTIPOINT ((10 40), (40 30), (20 20))").unwrap();
+        assert_eq!(flat, nested);
+
+        let mixed: Wkt<f64> = Wkt::from_str("MULTIPOINT (10 40, (40 30))").unwrap();
+        let points = match mixed {
+            Wkt::MultiPoint(MultiPoint(points, _)) => points,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            points,
+            vec![
+                Point::new(Coord {
+                    x: 10.0,
+                    y: 40.0,
+                    z: None,
+                    m: None,
+                }),
+                Point::new(Coord {
+                    x: 40.0,
+                    y: 30.0,
+                    z: None,
+                    m: None,
+                }),
+            ]
+        );
+
+        assert!(<Wkt<f64>>::from_str("MULTIPOINT ((10 40), 40)").is_err());
+    }
+
     #[test]
     fn empty_multipoint() {
         let wkt: Wkt<f64> = Wkt::from_str("MULTIPOINT EMPTY").unwrap();
         let points = match wkt {
-            Wkt::MultiPoint(MultiPoint(points)) => points,
+            Wkt::MultiPoint(MultiPoint(points, _)) => points,
             _ => unreachable!(),
         };
         assert_eq!(0, points.len());
@@ -195,24 +255,26 @@ mod tests {
 
     #[test]
     fn write_empty_multipoint() {
-        let multipoint: MultiPoint<f64> = MultiPoint(vec![]);
+        let multipoint: MultiPoint<f64> = MultiPoint::new(vec![]);
 
         assert_eq!("MULTIPOINT EMPTY", format!("{}", multipoint));
     }
 
     #[test]
     fn write_multipoint() {
-        let multipoint = MultiPoint(vec![
-            Point(Some(Coord {
+        let multipoint = MultiPoint::new(vec![
+            Point::new(Coord {
                 x: 10.1,
                 y: 20.2,
-                z: 30.3,
-            })),
-            Point(Some(Coord {
+                z: Some(30.3),
+                m: None,
+            }),
+            Point::new(Coord {
                 x: 40.4,
                 y: 50.5,
-                z: 60.6,
-            })),
+                z: Some(60.6),
+                m: None,
+            }),
         ]);
 
         assert_eq!(
@@ -220,4 +282,41 @@ mod tests {
             format!("{}", multipoint)
         );
     }
+
+    #[test]
+    fn round_trip_random_multipoints() {
+        // A small xorshift generator, so the test is deterministic without extra dependencies.
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for len in 0..50 {
+            let multipoint = MultiPoint::new(
+                (0..len % 7)
+                    .map(|_| {
+                        let mut ordinate = || match next() % 4 {
+                            // Integers are written without a decimal point
+                            0 => (next() % 2001) as f64 - 1000.,
+                            1 => -(next() as f64 / u64::MAX as f64),
+                            _ => (next() as f64 / u64::MAX as f64 - 0.5) * 1e6,
+                        };
+                        Point::new(Coord {
+                            x: ordinate(),
+                            y: ordinate(),
+                            z: Some(ordinate()),
+                            m: None,
+                        })
+                    })
+                    .collect(),
+            );
+
+            let written = multipoint.to_string();
+            let parsed: Wkt<f64> = Wkt::from_str(&written).unwrap();
+            assert_eq!(parsed, Wkt::MultiPoint(multipoint), "{written}");
+        }
+    }
 }
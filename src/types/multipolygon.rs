@@ -15,6 +15,7 @@
 use geo_traits::{MultiPolygonTrait, PolygonTrait};
 
 use crate::to_wkt::write_multi_polygon;
+use crate::error::ParseWktError;
 use crate::tokenizer::PeekableTokens;
 use crate::types::polygon::Polygon;
 use crate::types::Dimension;
@@ -22,8 +23,28 @@ use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
+/// A collection of polygons.
+///
+/// The second field is the dimension the multi polygon was written with, so that e.g.
+/// `MULTIPOLYGON ZM EMPTY` is written back as it was parsed. A non-empty multi polygon reports the
+/// dimension of its members.
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct MultiPolygon<T: WktNum>(pub Vec<Polygon<T>>);
+pub struct MultiPolygon<T: WktNum>(pub Vec<Polygon<T>>, pub Dimension);
+
+impl<T: WktNum> MultiPolygon<T> {
+    /// A multi polygon of `polygons`, with the dimension of the first.
+    pub fn new(polygons: Vec<Polygon<T>>) -> Self {
+        let dim = polygons
+            .first()
+            .map_or(Dimension::XY, |first| Dimension::of(first.dim()));
+        MultiPolygon(polygons, dim)
+    }
+
+    /// An empty multi polygon of dimension `dim`.
+    pub fn empty(dim: Dimension) -> Self {
+        MultiPolygon(Vec::new(), dim)
+    }
+}
 
 impl<T> From<MultiPolygon<T>> for Wkt<T>
 where
@@ -34,6 +55,44 @@ where
     }
 }
 
+/// Collect polygons into a `MultiPolygon`.
+impl<T: WktNum> FromIterator<Polygon<T>> for MultiPolygon<T> {
+    fn from_iter<I: IntoIterator<Item = Polygon<T>>>(iter: I) -> Self {
+        MultiPolygon::new(iter.into_iter().collect())
+    }
+}
+
+impl<T: WktNum> Extend<Polygon<T>> for MultiPolygon<T> {
+    fn extend<I: IntoIterator<Item = Polygon<T>>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> From<Polygon<T>> for MultiPolygon<T>
+where
+    T: WktNum,
+{
+    fn from(value: Polygon<T>) -> Self {
+        let dim = value.1;
+        MultiPolygon(vec![value], dim)
+    }
+}
+
+/// Unwraps a multipolygon with exactly one part, or gives the multipolygon back unchanged.
+impl<T> TryFrom<MultiPolygon<T>> for Polygon<T>
+where
+    T: WktNum,
+{
+    type Error = MultiPolygon<T>;
+
+    fn try_from(mut value: MultiPolygon<T>) -> Result<Self, Self::Error> {
+        match value.0.len() {
+            1 => Ok(value.0.remove(0)),
+            _ => Err(value),
+        }
+    }
+}
+
 impl<T> fmt::Display for MultiPolygon<T>
 where
     T: WktNum + fmt::Display,
@@ -47,13 +106,17 @@ impl<T> FromTokens<T> for MultiPolygon<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, ParseWktError> {
         let result = FromTokens::comma_many(
             <Polygon<T> as FromTokens<T>>::from_tokens_with_parens,
             tokens,
             dim,
         );
-        result.map(MultiPolygon)
+        result.map(|polygons| MultiPolygon(polygons, dim))
+    }
+
+    fn empty(dim: Dimension) -> Self {
+        MultiPolygon::empty(dim)
     }
 }
 
@@ -65,12 +128,11 @@ impl<T: WktNum> MultiPolygonTrait for MultiPolygon<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        // Empty members don't know their dimension, so go by the first with coordinates
+        self.0
+            .iter()
+            .find(|polygon| polygon.0.first().is_some_and(|ring| !ring.0.is_empty()))
+            .map_or(self.1.into(), |member| member.dim())
     }
 
     fn num_polygons(&self) -> usize {
@@ -90,12 +152,11 @@ impl<T: WktNum> MultiPolygonTrait for &MultiPolygon<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        // Empty members don't know their dimension, so go by the first with coordinates
+        self.0
+            .iter()
+            .find(|polygon| polygon.0.first().is_some_and(|ring| !ring.0.is_empty()))
+            .map_or(self.1.into(), |member| member.dim())
     }
 
     fn num_polygons(&self) -> usize {
@@ -114,13 +175,33 @@ mod tests {
     use crate::Wkt;
     use std::str::FromStr;
 
+    #[test]
+    fn polygon_multipolygon_conversions() {
+        let Wkt::Polygon(polygon) =
+            Wkt::<f64>::from_str("POLYGON Z((0 0 0,1 0 0,1 1 0,0 0 0))").unwrap()
+        else {
+            unreachable!()
+        };
+
+        let multi_polygon = MultiPolygon::from(polygon.clone());
+        assert_eq!(multi_polygon, MultiPolygon::new(vec![polygon.clone()]));
+        assert_eq!(Polygon::try_from(multi_polygon), Ok(polygon.clone()));
+
+        let two_parts = MultiPolygon::new(vec![polygon.clone(), polygon]);
+        assert_eq!(Polygon::try_from(two_parts.clone()), Err(two_parts));
+        assert_eq!(
+            Polygon::try_from(MultiPolygon::<f64>::new(vec![])),
+            Err(MultiPolygon::new(vec![]))
+        );
+    }
+
     #[test]
     fn basic_multipolygon() {
         let wkt: Wkt<f64> = Wkt::from_str("MULTIPOLYGON Z(((8 4 6)), ((4 0 9)))")
             .ok()
             .unwrap();
         let polygons = match wkt {
-            Wkt::MultiPolygon(MultiPolygon(polygons)) => polygons,
+            Wkt::MultiPolygon(MultiPolygon(polygons, _)) => polygons,
             _ => unreachable!(),
         };
         assert_eq!(2, polygons.len());
@@ -128,80 +209,111 @@ mod tests {
 
     #[test]
     fn write_empty_multipolygon() {
-        let multipolygon: MultiPolygon<f64> = MultiPolygon(vec![]);
+        let multipolygon: MultiPolygon<f64> = MultiPolygon::new(vec![]);
 
         assert_eq!("MULTIPOLYGON EMPTY", format!("{}", multipolygon));
     }
 
+    #[test]
+    fn empty_members_round_trip() {
+        for input in [
+            "MULTIPOLYGON(EMPTY,((0 0,1 0,1 1,0 0)))",
+            "MULTIPOLYGON Z(EMPTY,((0 0 1,1 0 1,1 1 1,0 0 1)),EMPTY)",
+            "MULTILINESTRING M(EMPTY,(1 2 3,4 5 6))",
+            "MULTIPOINT(EMPTY,(1 2))",
+        ] {
+            let wkt: Wkt<f64> = Wkt::from_str(input).unwrap();
+            assert_eq!(wkt.to_string(), input);
+        }
+
+        let wkt: Wkt<f64> = Wkt::from_str("MULTIPOLYGON (EMPTY, ((0 0,1 0,1 1,0 0)))").unwrap();
+        let Wkt::MultiPolygon(MultiPolygon(polygons, _)) = wkt else {
+            unreachable!()
+        };
+        assert_eq!(polygons[0], Polygon::new(vec![]));
+    }
+
     #[test]
     fn write_multipolygon() {
-        let multipolygon = MultiPolygon(vec![
-            Polygon(vec![
-                LineString(vec![
+        let multipolygon = MultiPolygon::new(vec![
+            Polygon::new(vec![
+                LineString::new(vec![
                     Coord {
                         x: 0.,
                         y: 0.,
-                        z: 0.,
+                        z: Some(0.),
+                        m: None,
                     },
                     Coord {
                         x: 20.,
                         y: 40.,
-                        z: 60.,
+                        z: Some(60.),
+                        m: None,
                     },
                     Coord {
                         x: 40.,
                         y: 0.,
-                        z: -40.,
+                        z: Some(-40.),
+                        m: None,
                     },
                     Coord {
                         x: 0.,
                         y: 0.,
-                        z: 0.,
+                        z: Some(0.),
+                        m: None,
                     },
                 ]),
-                LineString(vec![
+                LineString::new(vec![
                     Coord {
                         x: 5.,
                         y: 5.,
-                        z: 5.,
+                        z: Some(5.),
+                        m: None,
                     },
                     Coord {
                         x: 20.,
                         y: 30.,
-                        z: 40.,
+                        z: Some(40.),
+                        m: None,
                     },
                     Coord {
                         x: 30.,
                         y: 5.,
-                        z: -30.,
+                        z: Some(-30.),
+                        m: None,
                     },
                     Coord {
                         x: 5.,
                         y: 5.,
-                        z: 5.,
+                        z: Some(5.),
+                        m: None,
                     },
                 ]),
             ]),
-            Polygon(vec![LineString(vec![
+            Polygon::new(vec![LineString::new(vec![
                 Coord {
                     x: 40.,
                     y: 40.,
-                    z: 40.,
+                    z: Some(40.),
+                    m: None,
                 },
                 Coord {
                     x: 20.,
                     y: 45.,
-                    z: -20.,
+                    z: Some(-20.),
+                    m: None,
                 },
                 Coord {
                     x: 45.,
                     y: 30.,
-                    z: -45.,
+                    z: Some(-45.),
+                    m: None,
                 },
                 Coord {
                     x: 40.,
                     y: 40.,
-                    z: 40.,
+                    z: Some(40.),
+                    m: None,
                 },
             ])]),
         ]);
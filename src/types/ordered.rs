@@ -0,0 +1,309 @@
+//! A total order on geometries of `f32` and `f64`, so that they can be sorted, used as `HashMap`
+//! keys or deduplicated with a `HashSet`. Enabled by the `ordered` feature.
+//!
+//! The geometries' own `PartialEq` follows IEEE 754, where NaN is never equal to itself, so can't
+//! back `Eq`. [`Ordered`] instead compares ordinates with `total_cmp` and hashes their bits, so a
+//! NaN ordinate equals itself while `-0.0` and `0.0` differ.
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::types::{
+    Coord, Dimension, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon,
+};
+use crate::{Wkt, WktNum};
+
+/// A geometry or [`Coord`] of `f32` or `f64`, compared, ordered and hashed by the bits of its
+/// ordinates.
+///
+/// Geometries of different types are ordered as the variants of [`Wkt`] are declared, and a
+/// missing z or m before any present one.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use std::str::FromStr;
+/// use wkt::types::Ordered;
+/// use wkt::Wkt;
+///
+/// let geometries: HashSet<Ordered<Wkt<f64>>> = ["POINT(NaN 1)", "POINT(NaN 1)", "POINT(-0 1)"]
+///     .into_iter()
+///     .map(|wkt| Ordered(Wkt::from_str_allow_nonfinite(wkt).unwrap()))
+///     .collect();
+/// assert_eq!(geometries.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ordered<G>(pub G);
+
+/// A total order and a hash agreeing with it.
+trait TotalOrd {
+    fn total_cmp(&self, other: &Self) -> Ordering;
+    fn total_hash<H: Hasher>(&self, state: &mut H);
+}
+
+macro_rules! impl_float {
+    ($float:ty) => {
+        impl TotalOrd for $float {
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                <$float>::total_cmp(self, other)
+            }
+
+            fn total_hash<H: Hasher>(&self, state: &mut H) {
+                self.to_bits().hash(state);
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+impl<T: TotalOrd> TotalOrd for Option<T> {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Some(a), Some(b)) => a.total_cmp(b),
+            _ => self.is_some().cmp(&other.is_some()),
+        }
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.is_some().hash(state);
+        if let Some(value) = self {
+            value.total_hash(state);
+        }
+    }
+}
+
+impl<T: TotalOrd> TotalOrd for Vec<T> {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.iter()
+            .zip(other)
+            .map(|(a, b)| a.total_cmp(b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| self.len().cmp(&other.len()))
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for item in self {
+            item.total_hash(state);
+        }
+    }
+}
+
+impl TotalOrd for Dimension {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        (*self as u8).cmp(&(*other as u8))
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.hash(state);
+    }
+}
+
+impl<T: WktNum + TotalOrd> TotalOrd for Coord<T> {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.x
+            .total_cmp(&other.x)
+            .then_with(|| self.y.total_cmp(&other.y))
+            .then_with(|| self.z.total_cmp(&other.z))
+            .then_with(|| self.m.total_cmp(&other.m))
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.x.total_hash(state);
+        self.y.total_hash(state);
+        self.z.total_hash(state);
+        self.m.total_hash(state);
+    }
+}
+
+impl<T: WktNum + TotalOrd> TotalOrd for Point<T> {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .total_cmp(&other.0)
+            .then_with(|| self.1.total_cmp(&other.1))
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.total_hash(state);
+        self.1.total_hash(state);
+    }
+}
+
+macro_rules! impl_wrapper {
+    ($geometry_type:ident) => {
+        impl<T: WktNum + TotalOrd> TotalOrd for $geometry_type<T> {
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                self.0
+                    .total_cmp(&other.0)
+                    .then_with(|| self.1.total_cmp(&other.1))
+            }
+
+            fn total_hash<H: Hasher>(&self, state: &mut H) {
+                self.0.total_hash(state);
+                self.1.total_hash(state);
+            }
+        }
+    };
+}
+
+impl_wrapper!(LineString);
+impl_wrapper!(Polygon);
+impl_wrapper!(MultiPoint);
+impl_wrapper!(MultiLineString);
+impl_wrapper!(MultiPolygon);
+impl_wrapper!(GeometryCollection);
+
+/// The position of `wkt`'s variant in the declaration of [`Wkt`].
+fn variant_index<T: WktNum>(wkt: &Wkt<T>) -> u8 {
+    match wkt {
+        Wkt::Point(_) => 0,
+        Wkt::LineString(_) => 1,
+        Wkt::LinearRing(_) => 2,
+        Wkt::Polygon(_) => 3,
+        Wkt::MultiPoint(_) => 4,
+        Wkt::MultiLineString(_) => 5,
+        Wkt::MultiPolygon(_) => 6,
+        Wkt::GeometryCollection(_) => 7,
+    }
+}
+
+impl<T: WktNum + TotalOrd> TotalOrd for Wkt<T> {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Wkt::Point(a), Wkt::Point(b)) => a.total_cmp(b),
+            (Wkt::LineString(a), Wkt::LineString(b)) | (Wkt::LinearRing(a), Wkt::LinearRing(b)) => {
+                a.total_cmp(b)
+            }
+            (Wkt::Polygon(a), Wkt::Polygon(b)) => a.total_cmp(b),
+            (Wkt::MultiPoint(a), Wkt::MultiPoint(b)) => a.total_cmp(b),
+            (Wkt::MultiLineString(a), Wkt::MultiLineString(b)) => a.total_cmp(b),
+            (Wkt::MultiPolygon(a), Wkt::MultiPolygon(b)) => a.total_cmp(b),
+            (Wkt::GeometryCollection(a), Wkt::GeometryCollection(b)) => a.total_cmp(b),
+            _ => variant_index(self).cmp(&variant_index(other)),
+        }
+    }
+
+    fn total_hash<H: Hasher>(&self, state: &mut H) {
+        variant_index(self).hash(state);
+        match self {
+            Wkt::Point(g) => g.total_hash(state),
+            Wkt::LineString(g) | Wkt::LinearRing(g) => g.total_hash(state),
+            Wkt::Polygon(g) => g.total_hash(state),
+            Wkt::MultiPoint(g) => g.total_hash(state),
+            Wkt::MultiLineString(g) => g.total_hash(state),
+            Wkt::MultiPolygon(g) => g.total_hash(state),
+            Wkt::GeometryCollection(g) => g.total_hash(state),
+        }
+    }
+}
+
+macro_rules! impl_ordered {
+    ($($geometry_type:ident),*) => {
+        $(
+            impl_ordered!(@impl $geometry_type<f32>);
+            impl_ordered!(@impl $geometry_type<f64>);
+        )*
+    };
+    (@impl $geometry:ty) => {
+        impl PartialEq for Ordered<$geometry> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other).is_eq()
+            }
+        }
+
+        impl Eq for Ordered<$geometry> {}
+
+        impl PartialOrd for Ordered<$geometry> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Ordered<$geometry> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for Ordered<$geometry> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.total_hash(state);
+            }
+        }
+    };
+}
+
+impl_ordered!(
+    Coord,
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Wkt
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Ordered;
+    use crate::types::{Coord, Point};
+    use crate::{ParseLimits, Wkt};
+    use std::collections::{BTreeSet, HashSet};
+
+    fn point(z: Option<f64>, m: Option<f64>) -> Ordered<Point<f64>> {
+        Ordered(Point::new(Coord { x: 1., y: 2., z, m }))
+    }
+
+    #[test]
+    fn dedupe_points() {
+        let points: HashSet<Ordered<Point<f64>>> = [
+            point(Some(f64::NAN), None),
+            point(Some(f64::NAN), None),
+            point(Some(0.), None),
+            point(Some(-0.), None),
+            point(None, Some(0.)),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(points.len(), 4);
+    }
+
+    #[test]
+    fn sort_points() {
+        let points: BTreeSet<Ordered<Point<f64>>> = [
+            point(Some(f64::NAN), None),
+            point(Some(1.), None),
+            point(None, None),
+            point(Some(-0.), None),
+        ]
+        .into_iter()
+        .collect();
+        let zs: Vec<_> = points.iter().map(|p| p.0 .0.as_ref().unwrap().z).collect();
+        assert_eq!(zs.len(), 4);
+        assert_eq!(zs[..3], [None, Some(-0.), Some(1.)]);
+        assert!(zs[3].unwrap().is_nan());
+    }
+
+    #[test]
+    fn geometries_as_keys() {
+        let keep_linear_rings = ParseLimits {
+            keep_linear_rings: true,
+            ..Default::default()
+        };
+        let geometries: HashSet<Ordered<Wkt<f32>>> = [
+            "LINESTRING Z(1 2 3,4 5 6)",
+            "LINEARRING Z(1 2 3,4 5 6)",
+            "LINESTRING Z(1 2 3, 4 5 6)",
+            "GEOMETRYCOLLECTION(POINT Z EMPTY,POINT M EMPTY)",
+            "GEOMETRYCOLLECTION(POINT Z EMPTY, POINT M EMPTY)",
+        ]
+        .into_iter()
+        .map(|wkt| Ordered(Wkt::from_str_with_limits(wkt, &keep_linear_rings).unwrap()))
+        .collect();
+        assert_eq!(geometries.len(), 3);
+    }
+}
@@ -15,6 +15,7 @@
 use geo_traits::{CoordTrait, PointTrait};
 
 use crate::to_wkt::write_point;
+use crate::error::ParseWktError;
 use crate::tokenizer::PeekableTokens;
 use crate::types::coord::Coord;
 use crate::types::Dimension;
@@ -22,8 +23,26 @@ use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
+/// A point, or an empty point if it has no coordinate.
+///
+/// The second field is the dimension the point was written with, so that e.g. `POINT M EMPTY`
+/// is written back as it was parsed. A point with a coordinate reports the dimension of its
+/// coordinate, so the field should match it.
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct Point<T: WktNum>(pub Option<Coord<T>>);
+pub struct Point<T: WktNum>(pub Option<Coord<T>>, pub Dimension);
+
+impl<T: WktNum> Point<T> {
+    /// A point at `coord`, with the dimension of its ordinates.
+    pub fn new(coord: Coord<T>) -> Self {
+        let dim = coord.dimension();
+        Point(Some(coord), dim)
+    }
+
+    /// An empty point of dimension `dim`.
+    pub fn empty(dim: Dimension) -> Self {
+        Point(None, dim)
+    }
+}
 
 impl<T> From<Point<T>> for Wkt<T>
 where
@@ -34,6 +53,41 @@ where
     }
 }
 
+impl<T: WktNum> Wkt<T> {
+    /// A 2D point at `x`, `y`.
+    ///
+    /// ```
+    /// use wkt::Wkt;
+    ///
+    /// assert_eq!(Wkt::point_2d(1., 2.).to_string(), "POINT(1 2)");
+    /// assert_eq!(Wkt::point_3d(1., 2., 3.).to_string(), "POINT Z(1 2 3)");
+    /// assert_eq!(Wkt::<f64>::empty_point().to_string(), "POINT EMPTY");
+    /// ```
+    pub fn point_2d(x: T, y: T) -> Self {
+        Wkt::Point(Point::new(Coord {
+            x,
+            y,
+            z: None,
+            m: None,
+        }))
+    }
+
+    /// A 3D point at `x`, `y`, `z`.
+    pub fn point_3d(x: T, y: T, z: T) -> Self {
+        Wkt::Point(Point::new(Coord {
+            x,
+            y,
+            z: Some(z),
+            m: None,
+        }))
+    }
+
+    /// An empty 2D point, written as `POINT EMPTY`.
+    pub fn empty_point() -> Self {
+        Wkt::Point(Point::empty(Dimension::XY))
+    }
+}
+
 impl<T> fmt::Display for Point<T>
 where
     T: WktNum + fmt::Display,
@@ -47,9 +101,13 @@ impl<T> FromTokens<T> for Point<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, ParseWktError> {
         let result = <Coord<T> as FromTokens<T>>::from_tokens(tokens, dim);
-        result.map(|coord| Point(Some(coord)))
+        result.map(|coord| Point(Some(coord), dim))
+    }
+
+    fn empty(dim: Dimension) -> Self {
+        Point::empty(dim)
     }
 }
 
@@ -64,8 +122,7 @@ impl<T: WktNum> PointTrait for Point<T> {
         if let Some(coord) = &self.0 {
             coord.dim()
         } else {
-            // TODO: infer dimension from empty WKT
-            geo_traits::Dimensions::Xyz
+            self.1.into()
         }
     }
 
@@ -85,8 +142,7 @@ impl<T: WktNum> PointTrait for &Point<T> {
         if let Some(coord) = &self.0 {
             coord.dim()
         } else {
-            // TODO: infer dimension from empty WKT
-            geo_traits::Dimensions::Xyz
+            self.1.into()
         }
     }
 
@@ -97,43 +153,100 @@ impl<T: WktNum> PointTrait for &Point<T> {
 #[cfg(test)]
 mod tests {
     use super::{Coord, Point};
+    use crate::types::Dimension;
     use crate::Wkt;
     use std::str::FromStr;
 
+    #[test]
+    fn point_constructors() {
+        assert_eq!(Wkt::point_2d(1., 2.), Wkt::from_str("POINT(1 2)").unwrap());
+        assert_eq!(
+            Wkt::point_3d(1, 2, 3),
+            Wkt::from_str("POINT Z(1 2 3)").unwrap()
+        );
+        assert_eq!(
+            Wkt::<f64>::empty_point(),
+            Wkt::Point(Point::empty(Dimension::XY))
+        );
+    }
+
     #[test]
     fn basic_point() {
         let wkt = Wkt::from_str("POINT Z(10 -20 30)").ok().unwrap();
         let coord = match wkt {
-            Wkt::Point(Point(Some(coord))) => coord,
+            Wkt::Point(Point(Some(coord), _)) => coord,
             _ => unreachable!(),
         };
         assert_eq!(10.0, coord.x);
         assert_eq!(-20.0, coord.y);
-        assert_eq!(30.0, coord.z);
+        assert_eq!(Some(30.0), coord.z);
     }
 
     #[test]
     fn basic_point_z() {
         let wkt = Wkt::from_str("POINT Z(-117 33 10)").ok().unwrap();
         let coord = match wkt {
-            Wkt::Point(Point(Some(coord))) => coord,
+            Wkt::Point(Point(Some(coord), _)) => coord,
             _ => unreachable!(),
         };
         assert_eq!(-117.0, coord.x);
         assert_eq!(33.0, coord.y);
-        assert_eq!(10.0, coord.z);
+        assert_eq!(Some(10.0), coord.z);
     }
 
     #[test]
     fn basic_point_z_one_word() {
         let wkt = Wkt::from_str("POINTZ(-117 33 10)").ok().unwrap();
         let coord = match wkt {
-            Wkt::Point(Point(Some(coord))) => coord,
+            Wkt::Point(Point(Some(coord), _)) => coord,
             _ => unreachable!(),
         };
         assert_eq!(-117.0, coord.x);
         assert_eq!(33.0, coord.y);
-        assert_eq!(10.0, coord.z);
+        assert_eq!(Some(10.0), coord.z);
+    }
+
+    #[test]
+    fn basic_point_m() {
+        let wkt = Wkt::from_str("POINT M(1 2 3)").ok().unwrap();
+        let coord = match wkt {
+            Wkt::Point(Point(Some(coord), _)) => coord,
+            _ => unreachable!(),
+        };
+        assert_eq!(1.0, coord.x);
+        assert_eq!(2.0, coord.y);
+        assert_eq!(None, coord.z);
+        assert_eq!(Some(3.0), coord.m);
+    }
+
+    #[test]
+    fn basic_point_zm() {
+        let wkt = Wkt::from_str("POINTZM(1 2 3 4)").ok().unwrap();
+        let coord = match wkt {
+            Wkt::Point(Point(Some(coord), _)) => coord,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            geo_traits::CoordTrait::dim(&coord),
+            geo_traits::Dimensions::Xyzm
+        );
+        assert_eq!(Some(3.0), coord.z);
+        assert_eq!(Some(4.0), coord.m);
+    }
+
+    #[test]
+    #[should_panic(expected = "coordinate has no z")]
+    fn measured_point_has_no_trait_z() {
+        let wkt: Wkt<f64> = Wkt::from_str("POINT M(1 2 3)").ok().unwrap();
+        let coord = match wkt {
+            Wkt::Point(Point(Some(coord), _)) => coord,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            geo_traits::CoordTrait::dim(&coord),
+            geo_traits::Dimensions::Xym
+        );
+        geo_traits::CoordTrait::z(&coord);
     }
 
     #[test]
@@ -143,12 +256,12 @@ mod tests {
             
             .unwrap();
         let coord = match wkt {
-            Wkt::Point(Point(Some(coord))) => coord,
+            Wkt::Point(Point(Some(coord), _)) => coord,
             _ => unreachable!(),
         };
         assert_eq!(10.0, coord.x);
         assert_eq!(-20.0, coord.y);
-        assert_eq!(30.0, coord.z);
+        assert_eq!(Some(30.0), coord.z);
     }
 
     #[test]
@@ -156,34 +269,72 @@ mod tests {
         <Wkt<f64>>::from_str("POINT ()").err().unwrap();
         <Wkt<f64>>::from_str("POINT (10)").err().unwrap();
         <Wkt<f64>>::from_str("POINT 10").err().unwrap();
+        <Wkt<f64>>::from_str("POINT ZM(1 2 3)").err().unwrap();
     }
 
     #[test]
     fn write_empty_point() {
-        let point: Point<f64> = Point(None);
+        let point: Point<f64> = Point::empty(Dimension::XY);
+        assert_eq!("POINT EMPTY", format!("{}", point));
 
+        let point: Point<f64> = Point::empty(Dimension::XYZ);
         assert_eq!("POINT Z EMPTY", format!("{}", point));
     }
 
+    #[test]
+    fn empty_point_keeps_dimension() {
+        for input in [
+            "POINT EMPTY",
+            "POINT Z EMPTY",
+            "POINT M EMPTY",
+            "POINT ZM EMPTY",
+        ] {
+            let point: Wkt<f64> = Wkt::from_str(input).unwrap();
+            assert_eq!(point.to_string(), input);
+        }
+        let point: Wkt<f64> = Wkt::from_str("POINTM EMPTY").unwrap();
+        assert_eq!(point, Wkt::Point(Point::empty(Dimension::XYM)));
+    }
+
     #[test]
     fn write_3d_point() {
-        let point = Point(Some(Coord {
+        let point = Point::new(Coord {
             x: 10.12345,
             y: 20.67891,
-            z: 30.63831,
-        }));
+            z: Some(30.63831),
+            m: None,
+        });
 
         assert_eq!("POINT Z(10.12345 20.67891 30.63831)", format!("{}", point));
     }
 
     #[test]
     fn write_point_with_z_coord() {
-        let point = Point(Some(Coord {
+        let point = Point::new(Coord {
             x: 10.12345,
             y: 20.67891,
-            z: -32.56455,
-        }));
+            z: Some(-32.56455),
+            m: None,
+        });
 
         assert_eq!("POINT Z(10.12345 20.67891 -32.56455)", format!("{}", point));
     }
+
+    #[test]
+    fn write_point_with_m_coord() {
+        let point: Wkt<f64> = Wkt::from_str("POINT M(1 2 3)").unwrap();
+        assert_eq!("POINT M(1 2 3)", point.to_string());
+    }
+
+    #[test]
+    fn write_point_with_zm_coord() {
+        let point = Point::new(Coord {
+            x: 1.0,
+            y: 2.0,
+            z: Some(3.0),
+            m: Some(4.0),
+        });
+
+        assert_eq!("POINT ZM(1 2 3 4)", format!("{}", point));
+    }
 }
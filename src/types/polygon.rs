@@ -15,6 +15,7 @@
 use geo_traits::{LineStringTrait, PolygonTrait};
 
 use crate::to_wkt::write_polygon;
+use crate::error::ParseWktError;
 use crate::tokenizer::PeekableTokens;
 use crate::types::linestring::LineString;
 use crate::types::Dimension;
@@ -22,8 +23,28 @@ use crate::{FromTokens, Wkt, WktNum};
 use std::fmt;
 use std::str::FromStr;
 
+/// A polygon: its exterior ring followed by any interior rings.
+///
+/// The second field is the dimension the polygon was written with, so that e.g. `POLYGON Z EMPTY`
+/// is written back as it was parsed. A polygon with rings reports the dimension of its exterior
+/// ring.
 #[derive(Clone, Debug, Default, PartialEq)]
-pub struct Polygon<T: WktNum>(pub Vec<LineString<T>>);
+pub struct Polygon<T: WktNum>(pub Vec<LineString<T>>, pub Dimension);
+
+impl<T: WktNum> Polygon<T> {
+    /// A polygon of `rings`, with the dimension of the exterior ring.
+    pub fn new(rings: Vec<LineString<T>>) -> Self {
+        let dim = rings
+            .first()
+            .map_or(Dimension::XY, |first| Dimension::of(first.dim()));
+        Polygon(rings, dim)
+    }
+
+    /// An empty polygon of dimension `dim`.
+    pub fn empty(dim: Dimension) -> Self {
+        Polygon(Vec::new(), dim)
+    }
+}
 
 impl<T> From<Polygon<T>> for Wkt<T>
 where
@@ -47,13 +68,17 @@ impl<T> FromTokens<T> for Polygon<T>
 where
     T: WktNum + FromStr + Default,
 {
-    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, &'static str> {
+    fn from_tokens(tokens: &mut PeekableTokens<T>, dim: Dimension) -> Result<Self, ParseWktError> {
         let result = FromTokens::comma_many(
             <LineString<T> as FromTokens<T>>::from_tokens_with_parens,
             tokens,
             dim,
         );
-        result.map(Polygon)
+        result.map(|rings| Polygon(rings, dim))
+    }
+
+    fn empty(dim: Dimension) -> Self {
+        Polygon::empty(dim)
     }
 }
 
@@ -65,12 +90,7 @@ impl<T: WktNum> PolygonTrait for Polygon<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        self.0.first().map_or(self.1.into(), |first| first.dim())
     }
 
     fn exterior(&self) -> Option<Self::RingType<'_>> {
@@ -94,12 +114,7 @@ impl<T: WktNum> PolygonTrait for &Polygon<T> {
         Self: 'a;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        // TODO: infer dimension from empty WKT
-        if self.0.is_empty() {
-            geo_traits::Dimensions::Xy
-        } else {
-            self.0[0].dim()
-        }
+        self.0.first().map_or(self.1.into(), |first| first.dim())
     }
 
     fn exterior(&self) -> Option<Self::RingType<'_>> {
@@ -128,7 +143,7 @@ mod tests {
             .ok()
             .unwrap();
         let lines = match wkt {
-            Wkt::Polygon(Polygon(lines)) => lines,
+            Wkt::Polygon(Polygon(lines, _)) => lines,
             _ => unreachable!(),
         };
         assert_eq!(2, lines.len());
@@ -136,56 +151,64 @@ mod tests {
 
     #[test]
     fn write_empty_polygon() {
-        let polygon: Polygon<f64> = Polygon(vec![]);
+        let polygon: Polygon<f64> = Polygon::new(vec![]);
 
         assert_eq!("POLYGON EMPTY", format!("{}", polygon));
     }
 
     #[test]
     fn write_polygon() {
-        let polygon = Polygon(vec![
-            LineString(vec![
+        let polygon = Polygon::new(vec![
+            LineString::new(vec![
                 Coord {
                     x: 0.,
                     y: 0.,
-                    z: 0.,
+                    z: Some(0.),
+                    m: None,
                 },
                 Coord {
                     x: 20.,
                     y: 40.,
-                    z: 60.,
+                    z: Some(60.),
+                    m: None,
                 },
                 Coord {
                     x: 40.,
                     y: 0.,
-                    z: -40.,
+                    z: Some(-40.),
+                    m: None,
                 },
                 Coord {
                     x: 0.,
                     y: 0.,
-                    z: 0.,
+                    z: Some(0.),
+                    m: None,
                 },
             ]),
-            LineString(vec![
+            LineString::new(vec![
                 Coord {
                     x: 5.,
                     y: 5.,
-                    z: 5.,
+                    z: Some(5.),
+                    m: None,
                 },
                 Coord {
                     x: 20.,
                     y: 30.,
-                    z: 40.,
+                    z: Some(40.),
+                    m: None,
                 },
                 Coord {
                     x: 30.,
                     y: 5.,
-                    z: -30.,
+                    z: Some(-30.),
+                    m: None,
                 },
                 Coord {
                     x: 5.,
                     y: 5.,
-                    z: 5.,
+                    z: Some(5.),
+                    m: None,
                 },
             ]),
         ]);
@@ -195,4 +218,26 @@ mod tests {
             format!("{}", polygon)
         );
     }
+
+    #[test]
+    fn dimension_round_trips() {
+        use geo_traits::{Dimensions, PolygonTrait};
+
+        for (input, dim) in [
+            ("POLYGON((0 0,1 0,0 1,0 0))", Dimensions::Xy),
+            ("POLYGON Z((0 0 1,1 0 2,0 1 3,0 0 1))", Dimensions::Xyz),
+            ("POLYGON M((0 0 1,1 0 2,0 1 3,0 0 1))", Dimensions::Xym),
+            (
+                "POLYGON ZM((0 0 1 5,1 0 2 6,0 1 3 7,0 0 1 5))",
+                Dimensions::Xyzm,
+            ),
+        ] {
+            let polygon = match Wkt::<f64>::from_str(input).unwrap() {
+                Wkt::Polygon(polygon) => polygon,
+                _ => unreachable!(),
+            };
+            assert_eq!(polygon.dim(), dim, "{input}");
+            assert_eq!(polygon.to_string(), input);
+        }
+    }
 }
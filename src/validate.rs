@@ -0,0 +1,246 @@
+//! Checks for geometries which parse, but which strict consumers may reject.
+
+use std::str::FromStr;
+
+use num_traits::Float;
+
+use crate::error::ValidatedParseError;
+use crate::types::{Coord, LineString, Polygon};
+use crate::{Wkt, WktNum};
+
+/// A problem found by [`Wkt::validate`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationIssue {
+    /// A `LINESTRING` has a single coordinate.
+    TooFewLineStringCoordinates { found: usize },
+    /// The first and last coordinates of a polygon ring or `LINEARRING` differ.
+    UnclosedRing,
+    /// A non-empty polygon ring or `LINEARRING` has fewer than the four coordinates a closed ring
+    /// needs.
+    TooFewRingCoordinates { found: usize },
+    /// A coordinate has a NaN or infinite ordinate.
+    NonFiniteCoordinate,
+}
+
+fn validate_coords<T: WktNum + Float>(coords: &[Coord<T>], issues: &mut Vec<ValidationIssue>) {
+    if coords.iter().any(|c| {
+        [c.x, c.y]
+            .into_iter()
+            .chain(c.z)
+            .chain(c.m)
+            .any(|ordinate| !ordinate.is_finite())
+    }) {
+        issues.push(ValidationIssue::NonFiniteCoordinate);
+    }
+}
+
+fn validate_line_string<T: WktNum + Float>(
+    line_string: &LineString<T>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if line_string.0.len() == 1 {
+        issues.push(ValidationIssue::TooFewLineStringCoordinates { found: 1 });
+    }
+    validate_coords(&line_string.0, issues);
+}
+
+fn validate_ring<T: WktNum + Float>(ring: &LineString<T>, issues: &mut Vec<ValidationIssue>) {
+    if ring.0.is_empty() {
+        return;
+    }
+    if ring.0.first() != ring.0.last() {
+        issues.push(ValidationIssue::UnclosedRing);
+    }
+    if ring.0.len() < 4 {
+        issues.push(ValidationIssue::TooFewRingCoordinates {
+            found: ring.0.len(),
+        });
+    }
+    validate_coords(&ring.0, issues);
+}
+
+fn validate_polygon<T: WktNum + Float>(polygon: &Polygon<T>, issues: &mut Vec<ValidationIssue>) {
+    for ring in &polygon.0 {
+        validate_ring(ring, issues);
+    }
+}
+
+impl<T: WktNum + Float> Wkt<T> {
+    /// Check this geometry for problems which the parser accepts, but which strict geometry
+    /// engines may reject, such as unclosed polygon rings.
+    ///
+    /// Returns an empty `Vec` if no problems were found.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::{ValidationIssue, Wkt};
+    ///
+    /// let wkt: Wkt<f64> = Wkt::from_str("POLYGON Z((0 0 0,1 0 0,1 1 0,0 1 0))").unwrap();
+    /// assert_eq!(wkt.validate(), vec![ValidationIssue::UnclosedRing]);
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        self.validate_into(&mut issues);
+        issues
+    }
+
+    fn validate_into(&self, issues: &mut Vec<ValidationIssue>) {
+        match self {
+            Wkt::Point(point) => validate_coords(point.0.as_slice(), issues),
+            Wkt::LineString(line_string) => validate_line_string(line_string, issues),
+            Wkt::LinearRing(ring) => validate_ring(ring, issues),
+            Wkt::Polygon(polygon) => validate_polygon(polygon, issues),
+            Wkt::MultiPoint(multi_point) => {
+                for point in &multi_point.0 {
+                    validate_coords(point.0.as_slice(), issues);
+                }
+            }
+            Wkt::MultiLineString(multi_line_string) => {
+                for line_string in &multi_line_string.0 {
+                    validate_line_string(line_string, issues);
+                }
+            }
+            Wkt::MultiPolygon(multi_polygon) => {
+                for polygon in &multi_polygon.0 {
+                    validate_polygon(polygon, issues);
+                }
+            }
+            Wkt::GeometryCollection(collection) => {
+                for geometry in &collection.0 {
+                    geometry.validate_into(issues);
+                }
+            }
+        }
+    }
+}
+
+impl<T: WktNum + Float + FromStr + Default> Wkt<T> {
+    /// Parse a WKT string, then [validate](Wkt::validate) it, failing if any issues were found.
+    ///
+    /// [`Wkt::from_str`](FromStr::from_str) stays permissive, for reading data from other
+    /// systems. Use this to reject e.g. unclosed polygon rings up front when loading data for a
+    /// strict geometry engine.
+    ///
+    /// ```
+    /// use wkt::error::ValidatedParseError;
+    /// use wkt::{ValidationIssue, Wkt};
+    ///
+    /// assert!(Wkt::<f64>::from_str_validated("POLYGON((0 0,1 0,1 1,0 0))").is_ok());
+    /// assert_eq!(
+    ///     Wkt::<f64>::from_str_validated("POLYGON((0 0,1 0,1 1,0 1))"),
+    ///     Err(ValidatedParseError::Invalid(vec![ValidationIssue::UnclosedRing]))
+    /// );
+    /// ```
+    pub fn from_str_validated(wkt_str: &str) -> Result<Self, ValidatedParseError> {
+        let wkt = Wkt::from_str(wkt_str)?;
+        let issues = wkt.validate();
+        if issues.is_empty() {
+            Ok(wkt)
+        } else {
+            Err(ValidatedParseError::Invalid(issues))
+        }
+    }
+}
+
+/// [Validate](Wkt::validate) each of `geoms`, returning the index and issues of those which have
+/// problems.
+///
+/// ```
+/// use std::str::FromStr;
+/// use wkt::{validate_all, ValidationIssue, Wkt};
+///
+/// let geoms: Vec<Wkt<f64>> = vec![
+///     Wkt::from_str("POINT Z(1 2 3)").unwrap(),
+///     Wkt::from_str("LINESTRING Z(1 2 3)").unwrap(),
+/// ];
+/// assert_eq!(
+///     validate_all(&geoms),
+///     vec![(1, vec![ValidationIssue::TooFewLineStringCoordinates { found: 1 }])]
+/// );
+/// ```
+pub fn validate_all<T: WktNum + Float>(geoms: &[Wkt<T>]) -> Vec<(usize, Vec<ValidationIssue>)> {
+    geoms
+        .iter()
+        .enumerate()
+        .filter_map(|(i, geom)| {
+            let issues = geom.validate();
+            (!issues.is_empty()).then_some((i, issues))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate_all, ValidationIssue};
+    use crate::error::ValidatedParseError;
+    use crate::{ParseLimits, Wkt};
+    use std::str::FromStr;
+
+    #[test]
+    fn validate_all_reports_open_ring() {
+        let geoms: Vec<Wkt<f64>> = [
+            "POLYGON Z((0 0 0,1 0 0,1 1 0,0 0 0))",
+            "MULTIPOLYGON Z(((0 0 0,1 0 0,1 1 0,0 0 0)),((5 5 5,6 5 5,6 6 5,5 6 5)))",
+            "GEOMETRYCOLLECTION Z(POINT Z(1 2 3),LINESTRING Z(1 2 3,4 5 6))",
+        ]
+        .into_iter()
+        .map(|s| Wkt::from_str(s).unwrap())
+        .collect();
+
+        assert_eq!(
+            validate_all(&geoms),
+            vec![(1, vec![ValidationIssue::UnclosedRing])]
+        );
+    }
+
+    #[test]
+    fn validate_short_ring() {
+        let wkt: Wkt<f64> = Wkt::from_str("POLYGON Z((0 0 0,1 0 0,0 0 0))").unwrap();
+        assert_eq!(
+            wkt.validate(),
+            vec![ValidationIssue::TooFewRingCoordinates { found: 3 }]
+        );
+    }
+
+    #[test]
+    fn from_str_validated() {
+        assert!(Wkt::<f64>::from_str_validated(
+            "MULTIPOLYGON Z(((0 0 0,1 0 0,1 1 0,0 0 0)),((5 5 5,6 5 5,6 6 5,5 5 5)))"
+        )
+        .is_ok());
+        assert_eq!(
+            Wkt::<f64>::from_str_validated("POLYGON Z((0 0 0,1 0 0,0 0 0),(0 0 0,1 0 0,1 1 0))"),
+            Err(ValidatedParseError::Invalid(vec![
+                ValidationIssue::TooFewRingCoordinates { found: 3 },
+                ValidationIssue::UnclosedRing,
+                ValidationIssue::TooFewRingCoordinates { found: 3 },
+            ]))
+        );
+        assert!(matches!(
+            Wkt::<f64>::from_str_validated("POLYGON((0 0,1 0"),
+            Err(ValidatedParseError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn validate_linear_ring() {
+        let limits = ParseLimits {
+            keep_linear_rings: true,
+            ..Default::default()
+        };
+        let wkt: Wkt<f64> = Wkt::from_str_with_limits("LINEARRING(0 0,1 0,1 1)", &limits).unwrap();
+        assert_eq!(
+            wkt.validate(),
+            vec![
+                ValidationIssue::UnclosedRing,
+                ValidationIssue::TooFewRingCoordinates { found: 3 }
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_empty_is_valid() {
+        let wkt: Wkt<f64> = Wkt::from_str("POLYGON EMPTY").unwrap();
+        assert!(wkt.validate().is_empty());
+    }
+}
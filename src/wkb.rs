@@ -0,0 +1,583 @@
+//! Reading geometries from well-known binary (WKB).
+//!
+//! Both the ISO encoding, where the dimension is part of the type code (`1001` is a `POINT Z`),
+//! and the extended WKB written by PostGIS, which flags z, m and an SRID in the high bits of the
+//! type code, are supported in either byte order. Geometries are written as ISO WKB by
+//! [`write_wkb`](crate::to_wkt::write_wkb).
+
+use num_traits::NumCast;
+
+use crate::error::WkbError;
+use crate::types::{
+    Coord, Dimension, GeometryCollection, GeometryType, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+use crate::{Wkt, WktNum, WktWithSrid};
+
+/// The byte order of WKB data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// Most significant byte first, marked with a `0` byte (XDR).
+    BigEndian,
+    /// Least significant byte first, marked with a `1` byte (NDR).
+    #[default]
+    LittleEndian,
+}
+
+/// Extended WKB flags, set in the high bits of the type code.
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_M_FLAG: u32 = 0x4000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// The deepest nesting of geometry collections that will be read, so that malicious input
+/// can't overflow the stack.
+const MAX_NESTING_DEPTH: usize = 128;
+
+/// The smallest possible encoding of a geometry: a byte order marker, a type code and a count.
+const MIN_GEOMETRY_SIZE: usize = 9;
+
+/// The byte order, type and dimension which start every WKB geometry.
+struct Header {
+    endianness: Endianness,
+    geometry_type: GeometryType,
+    dim: Dimension,
+    srid: Option<u32>,
+}
+
+impl Header {
+    fn coord_size(&self) -> usize {
+        8 * match self.dim {
+            Dimension::XY => 2,
+            Dimension::XYZ | Dimension::XYM => 3,
+            Dimension::XYZM => 4,
+        }
+    }
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl WkbReader<'_> {
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N], WkbError> {
+        let bytes = self
+            .bytes
+            .get(self.offset..self.offset + N)
+            .ok_or(WkbError::UnexpectedEnd(self.bytes.len()))?;
+        self.offset += N;
+        Ok(bytes.try_into().expect("slice has N bytes"))
+    }
+
+    fn read_u32(&mut self, endianness: Endianness) -> Result<u32, WkbError> {
+        let bytes = self.read_bytes()?;
+        Ok(match endianness {
+            Endianness::BigEndian => u32::from_be_bytes(bytes),
+            Endianness::LittleEndian => u32::from_le_bytes(bytes),
+        })
+    }
+
+    fn read_f64(&mut self, endianness: Endianness) -> Result<f64, WkbError> {
+        let bytes = self.read_bytes()?;
+        Ok(match endianness {
+            Endianness::BigEndian => f64::from_be_bytes(bytes),
+            Endianness::LittleEndian => f64::from_le_bytes(bytes),
+        })
+    }
+
+    /// Read the number of items which follow, checking that there is room left in the input for
+    /// that many items of at least `min_size` bytes, so a corrupt count can't cause a huge
+    /// allocation.
+    fn read_count(&mut self, endianness: Endianness, min_size: usize) -> Result<usize, WkbError> {
+        let count = self.read_u32(endianness)? as usize;
+        if count.saturating_mul(min_size) > self.bytes.len() - self.offset {
+            return Err(WkbError::UnexpectedEnd(self.bytes.len()));
+        }
+        Ok(count)
+    }
+
+    fn read_header(&mut self) -> Result<Header, WkbError> {
+        let offset = self.offset;
+        let endianness = match self.read_bytes::<1>()? {
+            [0] => Endianness::BigEndian,
+            [1] => Endianness::LittleEndian,
+            [marker] => return Err(WkbError::InvalidByteOrder { marker, offset }),
+        };
+
+        let offset = self.offset;
+        let code = self.read_u32(endianness)?;
+        let unknown = || WkbError::UnknownGeometryType { code, offset };
+        let iso_code = code & !(EWKB_Z_FLAG | EWKB_M_FLAG | EWKB_SRID_FLAG);
+        let geometry_type = match iso_code % 1000 {
+            1 => GeometryType::Point,
+            2 => GeometryType::LineString,
+            3 => GeometryType::Polygon,
+            4 => GeometryType::MultiPoint,
+            5 => GeometryType::MultiLineString,
+            6 => GeometryType::MultiPolygon,
+            7 => GeometryType::GeometryCollection,
+            _ => return Err(unknown()),
+        };
+        let (iso_z, iso_m) = match iso_code / 1000 {
+            0 => (false, false),
+            1 => (true, false),
+            2 => (false, true),
+            3 => (true, true),
+            _ => return Err(unknown()),
+        };
+        let dim = match (
+            iso_z || code & EWKB_Z_FLAG != 0,
+            iso_m || code & EWKB_M_FLAG != 0,
+        ) {
+            (false, false) => Dimension::XY,
+            (true, false) => Dimension::XYZ,
+            (false, true) => Dimension::XYM,
+            (true, true) => Dimension::XYZM,
+        };
+
+        let srid = if code & EWKB_SRID_FLAG != 0 {
+            Some(self.read_u32(endianness)?)
+        } else {
+            None
+        };
+
+        Ok(Header {
+            endianness,
+            geometry_type,
+            dim,
+            srid,
+        })
+    }
+
+    /// Read the header of a member of a multi-geometry, which must be of type `expected`.
+    fn read_member_header(&mut self, expected: GeometryType) -> Result<Header, WkbError> {
+        let offset = self.offset;
+        let header = self.read_header()?;
+        if header.geometry_type != expected {
+            return Err(WkbError::UnexpectedMemberType {
+                expected,
+                found: header.geometry_type,
+                offset,
+            });
+        }
+        Ok(header)
+    }
+
+    fn read_ordinate<T: NumCast>(&mut self, endianness: Endianness) -> Result<T, WkbError> {
+        let offset = self.offset;
+        let value = self.read_f64(endianness)?;
+        T::from(value).ok_or(WkbError::InvalidNumber(offset))
+    }
+
+    fn read_coord<T: WktNum + NumCast>(&mut self, header: &Header) -> Result<Coord<T>, WkbError> {
+        let endianness = header.endianness;
+        let x = self.read_ordinate(endianness)?;
+        let y = self.read_ordinate(endianness)?;
+        let mut z = None;
+        let mut m = None;
+        if matches!(header.dim, Dimension::XYZ | Dimension::XYZM) {
+            z = Some(self.read_ordinate(endianness)?);
+        }
+        if matches!(header.dim, Dimension::XYM | Dimension::XYZM) {
+            m = Some(self.read_ordinate(endianness)?);
+        }
+        Ok(Coord { x, y, z, m })
+    }
+
+    /// Read the body of a point, which is empty if its x and y are both NaN.
+    fn read_point<T: WktNum + NumCast>(&mut self, header: &Header) -> Result<Point<T>, WkbError> {
+        let start = self.offset;
+        let x = self.read_f64(header.endianness)?;
+        let y = self.read_f64(header.endianness)?;
+        if x.is_nan() && y.is_nan() {
+            let end = start + header.coord_size();
+            if end > self.bytes.len() {
+                return Err(WkbError::UnexpectedEnd(self.bytes.len()));
+            }
+            self.offset = end;
+            return Ok(Point::empty(header.dim));
+        }
+        self.offset = start;
+        Ok(Point::new(self.read_coord(header)?))
+    }
+
+    fn read_line_string<T: WktNum + NumCast>(
+        &mut self,
+        header: &Header,
+    ) -> Result<LineString<T>, WkbError> {
+        let count = self.read_count(header.endianness, header.coord_size())?;
+        let coords = (0..count)
+            .map(|_| self.read_coord(header))
+            .collect::<Result<_, _>>()?;
+        Ok(LineString(coords, header.dim))
+    }
+
+    fn read_polygon<T: WktNum + NumCast>(
+        &mut self,
+        header: &Header,
+    ) -> Result<Polygon<T>, WkbError> {
+        let count = self.read_count(header.endianness, 4)?;
+        let rings = (0..count)
+            .map(|_| self.read_line_string(header))
+            .collect::<Result<_, _>>()?;
+        Ok(Polygon(rings, header.dim))
+    }
+
+    fn read_geometry<T: WktNum + NumCast>(
+        &mut self,
+        header: &Header,
+        depth: usize,
+    ) -> Result<Wkt<T>, WkbError> {
+        let endianness = header.endianness;
+        Ok(match header.geometry_type {
+            GeometryType::Point => Wkt::Point(self.read_point(header)?),
+            GeometryType::LineString => Wkt::LineString(self.read_line_string(header)?),
+            GeometryType::Polygon => Wkt::Polygon(self.read_polygon(header)?),
+            GeometryType::MultiPoint => {
+                let count = self.read_count(endianness, MIN_GEOMETRY_SIZE)?;
+                let points = (0..count)
+                    .map(|_| {
+                        let header = self.read_member_header(GeometryType::Point)?;
+                        self.read_point(&header)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Wkt::MultiPoint(MultiPoint(points, header.dim))
+            }
+            GeometryType::MultiLineString => {
+                let count = self.read_count(endianness, MIN_GEOMETRY_SIZE)?;
+                let line_strings = (0..count)
+                    .map(|_| {
+                        let header = self.read_member_header(GeometryType::LineString)?;
+                        self.read_line_string(&header)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Wkt::MultiLineString(MultiLineString(line_strings, header.dim))
+            }
+            GeometryType::MultiPolygon => {
+                let count = self.read_count(endianness, MIN_GEOMETRY_SIZE)?;
+                let polygons = (0..count)
+                    .map(|_| {
+                        let header = self.read_member_header(GeometryType::Polygon)?;
+                        self.read_polygon(&header)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Wkt::MultiPolygon(MultiPolygon(polygons, header.dim))
+            }
+            GeometryType::GeometryCollection => {
+                if depth >= MAX_NESTING_DEPTH {
+                    return Err(WkbError::NestingTooDeep(MAX_NESTING_DEPTH));
+                }
+                let count = self.read_count(endianness, MIN_GEOMETRY_SIZE)?;
+                let geometries = (0..count)
+                    .map(|_| {
+                        let header = self.read_header()?;
+                        self.read_geometry(&header, depth + 1)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Wkt::GeometryCollection(GeometryCollection(geometries, header.dim))
+            }
+        })
+    }
+}
+
+impl<T: WktNum + NumCast> WktWithSrid<T> {
+    /// Read a geometry from well-known binary, along with the SRID of extended WKB.
+    ///
+    /// ```
+    /// use wkt::WktWithSrid;
+    ///
+    /// // A little-endian extended WKB `POINT` with SRID 4326
+    /// let mut wkb = vec![1, 0x01, 0x00, 0x00, 0x20, 0xe6, 0x10, 0x00, 0x00];
+    /// wkb.extend(1.5f64.to_le_bytes());
+    /// wkb.extend(2.5f64.to_le_bytes());
+    ///
+    /// let tagged = WktWithSrid::<f64>::from_wkb(&wkb).unwrap();
+    /// assert_eq!(tagged.srid(), Some(4326));
+    /// assert_eq!(tagged.to_string(), "POINT(1.5 2.5)");
+    /// ```
+    pub fn from_wkb(bytes: &[u8]) -> Result<Self, WkbError> {
+        let mut reader = WkbReader { bytes, offset: 0 };
+        let header = reader.read_header()?;
+        let geometry = reader.read_geometry(&header, 0)?;
+        if reader.offset < bytes.len() {
+            return Err(WkbError::TrailingBytes(bytes.len() - reader.offset));
+        }
+        Ok(WktWithSrid {
+            geometry,
+            srid: header.srid,
+        })
+    }
+}
+
+impl<T: WktNum + NumCast> Wkt<T> {
+    /// Read a geometry from well-known binary, e.g. a `bytea` column from PostGIS.
+    ///
+    /// The result is the same as parsing the equivalent WKT text. Any SRID is discarded; use
+    /// [`WktWithSrid::from_wkb`] to keep it.
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use wkt::Wkt;
+    ///
+    /// // A big-endian ISO WKB `POINT Z`
+    /// let mut wkb = vec![0, 0x00, 0x00, 0x03, 0xe9];
+    /// for ordinate in [1f64, 2., 3.] {
+    ///     wkb.extend(ordinate.to_be_bytes());
+    /// }
+    ///
+    /// let wkt = Wkt::<f64>::from_wkb(&wkb).unwrap();
+    /// assert_eq!(wkt, Wkt::from_str("POINT Z(1 2 3)").unwrap());
+    /// ```
+    pub fn from_wkb(bytes: &[u8]) -> Result<Self, WkbError> {
+        WktWithSrid::from_wkb(bytes).map(WktWithSrid::into_geometry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Endianness;
+    use crate::error::WkbError;
+    use crate::types::GeometryType;
+    use crate::{Wkt, WktWithSrid};
+    use std::str::FromStr;
+
+    /// Builds WKB by hand, so the reader is tested against the format rather than the writer.
+    struct Builder {
+        bytes: Vec<u8>,
+        endianness: Endianness,
+    }
+
+    impl Builder {
+        fn new(endianness: Endianness) -> Self {
+            Builder {
+                bytes: vec![],
+                endianness,
+            }
+        }
+
+        fn header(mut self, code: u32) -> Self {
+            self.bytes.push(match self.endianness {
+                Endianness::BigEndian => 0,
+                Endianness::LittleEndian => 1,
+            });
+            self.u32(code)
+        }
+
+        fn u32(mut self, n: u32) -> Self {
+            self.bytes.extend(match self.endianness {
+                Endianness::BigEndian => n.to_be_bytes(),
+                Endianness::LittleEndian => n.to_le_bytes(),
+            });
+            self
+        }
+
+        fn f64s(mut self, values: &[f64]) -> Self {
+            for value in values {
+                self.bytes.extend(match self.endianness {
+                    Endianness::BigEndian => value.to_be_bytes(),
+                    Endianness::LittleEndian => value.to_le_bytes(),
+                });
+            }
+            self
+        }
+
+        fn bytes(mut self, bytes: &[u8]) -> Self {
+            self.bytes.extend(bytes);
+            self
+        }
+    }
+
+    fn wkt(wkt: &str) -> Wkt<f64> {
+        Wkt::from_str(wkt).unwrap()
+    }
+
+    #[test]
+    fn iso_line_string_z_in_both_byte_orders() {
+        for endianness in [Endianness::LittleEndian, Endianness::BigEndian] {
+            let wkb = Builder::new(endianness)
+                .header(1002)
+                .u32(2)
+                .f64s(&[1., 2., 3., 4., 5., 6.])
+                .bytes;
+            assert_eq!(Wkt::from_wkb(&wkb), Ok(wkt("LINESTRING Z(1 2 3,4 5 6)")));
+        }
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let wkb = Builder::new(Endianness::BigEndian)
+            .header(3)
+            .u32(2)
+            .u32(4)
+            .f64s(&[0., 0., 10., 0., 10., 10., 0., 0.])
+            .u32(4)
+            .f64s(&[1., 1., 2., 1., 2., 2., 1., 1.])
+            .bytes;
+        assert_eq!(
+            Wkt::from_wkb(&wkb),
+            Ok(wkt("POLYGON((0 0,10 0,10 10,0 0),(1 1,2 1,2 2,1 1))"))
+        );
+    }
+
+    #[test]
+    fn extended_wkb_flags_and_srid() {
+        let wkb = Builder::new(Endianness::LittleEndian)
+            .header(0x8000_0001 | 0x2000_0000)
+            .u32(27700)
+            .f64s(&[1., 2., 3.])
+            .bytes;
+        assert_eq!(
+            WktWithSrid::from_wkb(&wkb),
+            Ok(wkt("POINT Z(1 2 3)").with_srid(27700))
+        );
+        assert_eq!(Wkt::from_wkb(&wkb), Ok(wkt("POINT Z(1 2 3)")));
+
+        let wkb = Builder::new(Endianness::LittleEndian)
+            .header(0x4000_0002)
+            .u32(1)
+            .f64s(&[1., 2., 3.])
+            .bytes;
+        assert_eq!(Wkt::from_wkb(&wkb), Ok(wkt("LINESTRING M(1 2 3)")));
+    }
+
+    #[test]
+    fn multi_geometries_and_nested_collection() {
+        let wkb = Builder::new(Endianness::LittleEndian)
+            .header(3004)
+            .u32(2)
+            .header(3001)
+            .f64s(&[1., 2., 3., 4.])
+            .header(3001)
+            .f64s(&[5., 6., 7., 8.])
+            .bytes;
+        assert_eq!(
+            Wkt::from_wkb(&wkb),
+            Ok(wkt("MULTIPOINT ZM((1 2 3 4),(5 6 7 8))"))
+        );
+
+        // Each member has its own byte order
+        let big_point = Builder::new(Endianness::BigEndian)
+            .header(1)
+            .f64s(&[1., 2.])
+            .bytes;
+        let wkb = Builder::new(Endianness::LittleEndian)
+            .header(7)
+            .u32(2)
+            .bytes(&big_point)
+            .header(7)
+            .u32(1)
+            .header(5)
+            .u32(1)
+            .header(2)
+            .u32(2)
+            .f64s(&[3., 4., 5., 6.])
+            .bytes;
+        assert_eq!(
+            Wkt::from_wkb(&wkb),
+            Ok(wkt(
+                "GEOMETRYCOLLECTION(POINT(1 2),GEOMETRYCOLLECTION(MULTILINESTRING((3 4,5 6))))"
+            ))
+        );
+    }
+
+    #[test]
+    fn empty_geometries() {
+        let wkb = Builder::new(Endianness::LittleEndian)
+            .header(1)
+            .f64s(&[f64::NAN, f64::NAN])
+            .bytes;
+        assert_eq!(Wkt::from_wkb(&wkb), Ok(wkt("POINT EMPTY")));
+
+        let wkb = Builder::new(Endianness::LittleEndian)
+            .header(6)
+            .u32(0)
+            .bytes;
+        assert_eq!(Wkt::from_wkb(&wkb), Ok(wkt("MULTIPOLYGON EMPTY")));
+    }
+
+    #[test]
+    fn invalid_wkb() {
+        let point = Builder::new(Endianness::LittleEndian)
+            .header(1)
+            .f64s(&[1., 2.])
+            .bytes;
+        assert_eq!(
+            Wkt::<f64>::from_wkb(&point[..12]),
+            Err(WkbError::UnexpectedEnd(12))
+        );
+        assert_eq!(
+            Wkt::<f64>::from_wkb(&[2, 1, 0, 0, 0]),
+            Err(WkbError::InvalidByteOrder {
+                marker: 2,
+                offset: 0
+            })
+        );
+        assert_eq!(
+            Wkt::<f64>::from_wkb(&Builder::new(Endianness::LittleEndian).header(17).bytes),
+            Err(WkbError::UnknownGeometryType {
+                code: 17,
+                offset: 1
+            })
+        );
+
+        let mut trailing = point.clone();
+        trailing.push(0);
+        assert_eq!(
+            Wkt::<f64>::from_wkb(&trailing),
+            Err(WkbError::TrailingBytes(1))
+        );
+
+        let wrong_member = Builder::new(Endianness::LittleEndian)
+            .header(4)
+            .u32(1)
+            .bytes(
+                &Builder::new(Endianness::LittleEndian)
+                    .header(2)
+                    .u32(0)
+                    .bytes,
+            )
+            .bytes;
+        assert_eq!(
+            Wkt::<f64>::from_wkb(&wrong_member),
+            Err(WkbError::UnexpectedMemberType {
+                expected: GeometryType::Point,
+                found: GeometryType::LineString,
+                offset: 9
+            })
+        );
+
+        // A count far larger than the input fails without trying to allocate for it
+        let huge = Builder::new(Endianness::LittleEndian)
+            .header(2)
+            .u32(u32::MAX)
+            .bytes;
+        assert_eq!(Wkt::<f64>::from_wkb(&huge), Err(WkbError::UnexpectedEnd(9)));
+
+        let mut nested = Builder::new(Endianness::LittleEndian);
+        for _ in 0..200 {
+            nested = nested.header(7).u32(1);
+        }
+        assert_eq!(
+            Wkt::<f64>::from_wkb(&nested.header(7).u32(0).bytes),
+            Err(WkbError::NestingTooDeep(128))
+        );
+    }
+
+    #[test]
+    fn integer_ordinates() {
+        let wkb = Builder::new(Endianness::LittleEndian)
+            .header(1)
+            .f64s(&[3., 4.5])
+            .bytes;
+        assert_eq!(
+            Wkt::<i32>::from_wkb(&wkb[..]).unwrap().to_string(),
+            "POINT(3 4)"
+        );
+
+        let wkb = Builder::new(Endianness::LittleEndian)
+            .header(1)
+            .f64s(&[1e20, 0.])
+            .bytes;
+        assert_eq!(Wkt::<i32>::from_wkb(&wkb), Err(WkbError::InvalidNumber(5)));
+    }
+}